@@ -10,9 +10,8 @@ use nvisy_sdk::{NvisyClient, Result};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Create a client with an API key from environment
-    let api_key = std::env::var("NVISY_API_KEY").expect("NVISY_API_KEY must be set");
-    let client = NvisyClient::with_api_key(&api_key)?;
+    // Create a client configured from NVISY_* environment variables
+    let client = NvisyClient::from_env()?;
 
     // List all workspaces
     println!("Listing workspaces...");
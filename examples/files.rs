@@ -13,8 +13,7 @@ use nvisy_sdk::{NvisyClient, Result};
 #[tokio::main]
 async fn main() -> Result<()> {
     // Create a client
-    let api_key = std::env::var("NVISY_API_KEY").expect("NVISY_API_KEY must be set");
-    let client = NvisyClient::with_api_key(&api_key)?;
+    let client = NvisyClient::from_env()?;
 
     // Create a workspace for our files
     println!("Creating workspace for file uploads...");
@@ -0,0 +1,306 @@
+//! Authentication of requests made by the client.
+//!
+//! Every request carries an `Authorization` header produced by an
+//! [`AuthProvider`]. A static API key is used unless another provider is
+//! configured with
+//! [`NvisyConfigBuilder::with_auth`](crate::NvisyConfigBuilder::with_auth).
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use super::runtime::Instant;
+use crate::error::{Error, Result};
+
+/// Default time before expiry at which OAuth2 tokens are refreshed.
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Future resolving to an `Authorization` header value.
+#[cfg(not(target_arch = "wasm32"))]
+pub type AuthFuture<'a> = Pin<Box<dyn Future<Output = Result<String>> + Send + 'a>>;
+
+/// Future resolving to an `Authorization` header value.
+#[cfg(target_arch = "wasm32")]
+pub type AuthFuture<'a> = Pin<Box<dyn Future<Output = Result<String>> + 'a>>;
+
+/// Source of the credentials sent with each request.
+pub trait AuthProvider: fmt::Debug + Send + Sync {
+    /// Returns the `Authorization` header value for the next request.
+    ///
+    /// Called before every request, so implementations should cache
+    /// credentials that are expensive to obtain. `client` is the HTTP client
+    /// of the SDK, without authentication, for fetching tokens.
+    fn authorization<'a>(&'a self, client: &'a Client) -> AuthFuture<'a>;
+}
+
+/// Authenticates with a static API key.
+#[derive(Clone)]
+pub struct ApiKey(String);
+
+impl ApiKey {
+    /// Creates a provider sending the given API key.
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self(api_key.into())
+    }
+}
+
+impl AuthProvider for ApiKey {
+    fn authorization<'a>(&'a self, _client: &'a Client) -> AuthFuture<'a> {
+        Box::pin(async move { Ok(format!("Bearer {}", self.0)) })
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ApiKey").field(&"[REDACTED]").finish()
+    }
+}
+
+/// Authenticates with an API key fetched from a callback before each
+/// request.
+///
+/// Created by
+/// [`NvisyConfigBuilder::with_api_key_provider`](crate::NvisyConfigBuilder::with_api_key_provider).
+pub(crate) struct ApiKeyFn<F>(pub(crate) F);
+
+impl<F, Fut> AuthProvider for ApiKeyFn<F>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: Future<Output = String> + Send,
+{
+    fn authorization<'a>(&'a self, _client: &'a Client) -> AuthFuture<'a> {
+        Box::pin(async move { Ok(format!("Bearer {}", (self.0)().await)) })
+    }
+}
+
+impl<F> fmt::Debug for ApiKeyFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ApiKeyFn").finish_non_exhaustive()
+    }
+}
+
+/// Authenticates with a bearer token obtained elsewhere, such as from a
+/// user's OAuth2 login.
+#[derive(Clone)]
+pub struct BearerToken(String);
+
+impl BearerToken {
+    /// Creates a provider sending the given token.
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+}
+
+impl AuthProvider for BearerToken {
+    fn authorization<'a>(&'a self, _client: &'a Client) -> AuthFuture<'a> {
+        Box::pin(async move { Ok(format!("Bearer {}", self.0)) })
+    }
+}
+
+impl fmt::Debug for BearerToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BearerToken").field(&"[REDACTED]").finish()
+    }
+}
+
+/// Authenticates with tokens from the OAuth2 client credentials grant.
+///
+/// A token is requested on first use and cached until shortly before it
+/// expires, after which the next request fetches a new one.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nvisy_sdk::{NvisyConfig, OAuth2ClientCredentials};
+///
+/// let auth = OAuth2ClientCredentials::new(
+///     "https://auth.nvisy.com/oauth/token",
+///     "client-id",
+///     "client-secret",
+/// )
+/// .with_scope("files:read");
+/// let client = NvisyConfig::builder().with_auth(auth).build_client().unwrap();
+/// ```
+pub struct OAuth2ClientCredentials {
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    scopes: Vec<String>,
+    refresh_margin: Duration,
+    token: Mutex<Option<CachedToken>>,
+}
+
+/// Access token and the time it should be replaced.
+struct CachedToken {
+    value: String,
+    refresh_at: Option<Instant>,
+}
+
+/// Successful response of a token endpoint.
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+impl OAuth2ClientCredentials {
+    /// Creates a provider requesting tokens from `token_url`.
+    pub fn new(
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            token_url: token_url.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            scopes: Vec::new(),
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            token: Mutex::new(None),
+        }
+    }
+
+    /// Adds a scope to request.
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scopes.push(scope.into());
+        self
+    }
+
+    /// Sets how long before expiry a token is refreshed.
+    pub fn with_refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// Requests a new token from the token endpoint.
+    async fn fetch(&self, client: &Client) -> Result<CachedToken> {
+        let mut form = vec![
+            ("grant_type", "client_credentials".to_string()),
+            ("client_id", self.client_id.clone()),
+            ("client_secret", self.client_secret.clone()),
+        ];
+        if !self.scopes.is_empty() {
+            form.push(("scope", self.scopes.join(" ")));
+        }
+
+        let response = client.post(&self.token_url).form(&form).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Error::Authentication(format!(
+                "token endpoint responded with {status}"
+            )));
+        }
+        let token: TokenResponse = serde_json::from_slice(&response.bytes().await?)?;
+
+        let refresh_at = token.expires_in.map(|secs| {
+            Instant::now() + Duration::from_secs(secs).saturating_sub(self.refresh_margin)
+        });
+        Ok(CachedToken {
+            value: token.access_token,
+            refresh_at,
+        })
+    }
+}
+
+impl AuthProvider for OAuth2ClientCredentials {
+    fn authorization<'a>(&'a self, client: &'a Client) -> AuthFuture<'a> {
+        Box::pin(async move {
+            // Holding the lock while fetching makes concurrent requests
+            // share one refresh.
+            let mut token = self.token.lock().await;
+            let expired = token.as_ref().is_none_or(|token| {
+                token
+                    .refresh_at
+                    .is_some_and(|refresh_at| Instant::now() >= refresh_at)
+            });
+            if expired {
+                *token = Some(self.fetch(client).await?);
+            }
+            let value = token.as_ref().map_or("", |token| token.value.as_str());
+            Ok(format!("Bearer {value}"))
+        })
+    }
+}
+
+impl fmt::Debug for OAuth2ClientCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OAuth2ClientCredentials")
+            .field("token_url", &self.token_url)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"[REDACTED]")
+            .field("scopes", &self.scopes)
+            .field("refresh_margin", &self.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Method;
+
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+
+    #[tokio::test]
+    async fn test_client_credentials_token_is_cached() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], r#"{"access_token":"tok","expires_in":3600}"#),
+            mock::response(200, &[], ""),
+            mock::response(200, &[], ""),
+        ]);
+
+        let auth = OAuth2ClientCredentials::new(format!("{base_url}/oauth/token"), "id", "secret")
+            .with_scope("files:read");
+        let client = NvisyConfig::builder()
+            .with_base_url(base_url)
+            .with_auth(auth)
+            .build_client()?;
+        client.send(Method::GET, "/health/").await?;
+        client.send(Method::GET, "/health/").await?;
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].request_line().starts_with("POST /oauth/token"));
+        let form = String::from_utf8_lossy(&requests[0].body);
+        assert!(form.contains("grant_type=client_credentials"));
+        assert!(form.contains("scope=files%3Aread"));
+        for request in &requests[1..] {
+            assert_eq!(request.header("authorization"), Some("Bearer tok"));
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_api_key_provider_is_called_per_request() -> Result<()> {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], ""),
+            mock::response(200, &[], ""),
+        ]);
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let client = NvisyConfig::builder()
+            .with_base_url(base_url)
+            .with_api_key_provider(move || {
+                let call = counter.fetch_add(1, Ordering::SeqCst);
+                async move { format!("key-{call}") }
+            })
+            .build_client()?;
+        client.send(Method::GET, "/health/").await?;
+        client.send(Method::GET, "/health/").await?;
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].header("authorization"), Some("Bearer key-0"));
+        assert_eq!(requests[1].header("authorization"), Some("Bearer key-1"));
+        Ok(())
+    }
+}
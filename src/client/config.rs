@@ -4,20 +4,40 @@
 //! and customizing [`NvisyClient`] instances.
 
 use std::fmt;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use derive_builder::Builder;
 use reqwest::Client;
+use uuid::Uuid;
 
+use super::auth::{ApiKey, ApiKeyFn, AuthProvider};
+use super::dns::DnsSettings;
+use super::download_cache::DownloadCache;
+use super::hooks::{ErrorInfo, Hooks, RequestInfo, ResponseInfo, RetryInfo};
+use super::interceptor::RequestInterceptor;
 use super::nvisy::NvisyClient;
+use super::rate_budget::RateBudgets;
+use super::redirect::RedirectPolicy;
+use super::retry::RetryPolicy;
+use super::schema::SchemaMigrations;
+use super::telemetry::Telemetry;
 use crate::error::Result;
 
 /// Default base URL for the Nvisy API.
 pub const DEFAULT_BASE_URL: &str = "https://api.nvisy.com";
 
+/// Default base URL of the Nvisy web console.
+pub const DEFAULT_CONSOLE_URL: &str = "https://app.nvisy.com";
+
 /// Default request timeout.
 pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 
+/// Default upper bound accepted for the request timeout.
+pub const DEFAULT_MAX_TIMEOUT: Duration = Duration::from_secs(300);
+
 /// Configuration for the Nvisy API client.
 ///
 /// This struct holds all the necessary configuration parameters for creating and using
@@ -57,26 +77,193 @@ pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
 )]
 pub struct NvisyConfig {
     /// API key for authentication with the Nvisy API.
+    ///
+    /// Required unless another provider is set with
+    /// [`with_auth`](NvisyConfigBuilder::with_auth).
+    #[builder(default)]
     api_key: String,
 
+    /// Provider of the credentials sent with each request.
+    ///
+    /// Set with [`with_auth`](NvisyConfigBuilder::with_auth) on the builder.
+    /// Defaults to sending the API key.
+    #[builder(default, setter(custom))]
+    auth: Option<Arc<dyn AuthProvider>>,
+
     /// Base URL for the Nvisy API.
     ///
     /// Defaults to the official Nvisy API endpoint.
     #[builder(default = "Self::default_base_url()")]
     base_url: String,
 
+    /// Base URL of the Nvisy web console.
+    ///
+    /// Used to build links to resources with [`NvisyClient::urls`].
+    /// Defaults to the official Nvisy console.
+    #[builder(default = "Self::default_console_url()")]
+    console_url: String,
+
     /// Timeout for HTTP requests.
     ///
     /// Controls how long the client will wait for API responses before timing out.
     #[builder(default = "Self::default_timeout()")]
     timeout: Duration,
 
+    /// Upper bound accepted for the request timeout.
+    ///
+    /// Guards against accidentally configured multi-hour timeouts. Raise it
+    /// when calls such as archive downloads of large workspaces legitimately
+    /// take longer. Defaults to 5 minutes.
+    #[builder(default = "Self::default_max_timeout()")]
+    max_timeout: Duration,
+
+    /// Timeout for establishing a connection.
+    ///
+    /// Ignored in browsers and when a custom client is provided. Defaults
+    /// to no separate limit.
+    #[builder(default)]
+    connect_timeout: Option<Duration>,
+
+    /// Timeout for each read of the response.
+    ///
+    /// Unlike the overall timeout, it restarts whenever data arrives, so
+    /// long downloads keep going as long as they make progress. Ignored in
+    /// browsers and when a custom client is provided. Defaults to no
+    /// separate limit.
+    #[builder(default)]
+    read_timeout: Option<Duration>,
+
     /// Optional custom reqwest client.
     ///
     /// If provided, this client will be used instead of creating a new one.
-    /// This allows for custom configuration of the HTTP client.
+    /// This allows for custom configuration of the HTTP client. Build it with
+    /// `reqwest::redirect::Policy::none()` so that the SDK's redirect policy
+    /// is the only one applied.
     #[builder(default = "None")]
     client: Option<Client>,
+
+    /// Host name overrides and custom resolver for the client's connections.
+    ///
+    /// Set with [`with_resolve`](NvisyConfigBuilder::with_resolve) and
+    /// [`with_dns_resolver`](NvisyConfigBuilder::with_dns_resolver) on the
+    /// builder. Ignored when a custom client is provided. Defaults to the
+    /// system resolver.
+    #[builder(default, setter(custom))]
+    dns: DnsSettings,
+
+    /// URL of a proxy that all requests are sent through.
+    ///
+    /// Ignored in browsers and when a custom client is provided. Defaults
+    /// to the proxy named by the `HTTPS_PROXY` and `HTTP_PROXY` environment
+    /// variables, if any.
+    #[builder(default)]
+    proxy: Option<String>,
+
+    /// Policy controlling which redirects are followed.
+    ///
+    /// Defaults to following at most 10 redirects.
+    #[builder(default)]
+    redirect_policy: RedirectPolicy,
+
+    /// Whether to remove the `Authorization` header when a redirect leaves
+    /// the origin of the configured base URL.
+    ///
+    /// Defaults to `true`, which prevents API keys from leaking to
+    /// third-party hosts such as presigned storage URLs.
+    #[builder(default = "true")]
+    strip_auth_on_redirect: bool,
+
+    /// Whether the client refuses to send requests that could modify data.
+    ///
+    /// When enabled, requests other than `GET`, `HEAD`, and `OPTIONS` fail
+    /// with [`Error::ReadOnlyMode`] before anything is sent, including
+    /// requests for access tokens. Health checks with options are still
+    /// allowed, since they only read. Defaults to `false`.
+    ///
+    /// [`Error::ReadOnlyMode`]: crate::Error::ReadOnlyMode
+    #[builder(default)]
+    read_only: bool,
+
+    /// Policy controlling how failed operations are retried.
+    ///
+    /// Defaults to retrying downloads that fail checksum verification twice.
+    #[builder(default)]
+    retry_policy: RetryPolicy,
+
+    /// How long results of [`HealthService::health`] are cached.
+    ///
+    /// Only calls made without options are cached. Defaults to no caching.
+    ///
+    /// [`HealthService::health`]: crate::service::HealthService::health
+    #[builder(default)]
+    health_cache_ttl: Option<Duration>,
+
+    /// Maximum size in bytes of a response body read into memory.
+    ///
+    /// Larger responses fail with [`Error::ResponseTooLarge`] instead of
+    /// being buffered. Streaming downloads into a writer are not limited.
+    /// Defaults to no limit.
+    ///
+    /// [`Error::ResponseTooLarge`]: crate::Error::ResponseTooLarge
+    #[builder(default)]
+    max_response_size: Option<u64>,
+
+    /// Maximum size in bytes of a single uploaded file.
+    ///
+    /// Larger uploads fail with [`Error::UploadTooLarge`] before any data is
+    /// sent. The server-advertised limit from
+    /// [`CapabilitiesService::get_capabilities`] is also enforced once it has
+    /// been fetched. Defaults to no client-side limit.
+    ///
+    /// [`Error::UploadTooLarge`]: crate::Error::UploadTooLarge
+    /// [`CapabilitiesService::get_capabilities`]: crate::service::CapabilitiesService::get_capabilities
+    #[builder(default)]
+    max_upload_size: Option<u64>,
+
+    /// Callbacks invoked as requests are made.
+    ///
+    /// Set with [`on_request`], [`on_response`], [`on_retry`], and
+    /// [`on_error`] on the builder. Defaults to no callbacks.
+    ///
+    /// [`on_request`]: NvisyConfigBuilder::on_request
+    /// [`on_response`]: NvisyConfigBuilder::on_response
+    /// [`on_retry`]: NvisyConfigBuilder::on_retry
+    /// [`on_error`]: NvisyConfigBuilder::on_error
+    #[builder(default, setter(custom))]
+    hooks: Hooks,
+
+    /// Middleware invoked around every request, in registration order.
+    ///
+    /// Added with [`with_interceptor`](NvisyConfigBuilder::with_interceptor)
+    /// on the builder. Defaults to none.
+    #[builder(default, setter(custom))]
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+
+    /// Opt-in anonymous usage telemetry.
+    ///
+    /// Reports go only to the configured sink. Defaults to disabled.
+    #[builder(default)]
+    telemetry: Option<Telemetry>,
+
+    /// Local disk cache for downloaded file content.
+    ///
+    /// Defaults to no caching.
+    #[builder(default)]
+    download_cache: Option<DownloadCache>,
+
+    /// Shims rewriting responses of other schema versions before they are
+    /// deserialized.
+    ///
+    /// Defaults to no shims.
+    #[builder(default)]
+    schema_migrations: SchemaMigrations,
+
+    /// Client-side request rate limits for individual workspaces.
+    ///
+    /// Set with [`with_rate_budget`](NvisyConfigBuilder::with_rate_budget)
+    /// on the builder. Defaults to no limits.
+    #[builder(default, setter(custom))]
+    rate_budgets: RateBudgets,
 }
 
 impl NvisyConfigBuilder {
@@ -85,11 +272,21 @@ impl NvisyConfigBuilder {
         DEFAULT_BASE_URL.to_string()
     }
 
+    /// Returns the default base URL of the web console.
+    fn default_console_url() -> String {
+        DEFAULT_CONSOLE_URL.to_string()
+    }
+
     /// Returns the default timeout.
     fn default_timeout() -> Duration {
         DEFAULT_TIMEOUT
     }
 
+    /// Returns the default upper bound for the timeout.
+    fn default_max_timeout() -> Duration {
+        DEFAULT_MAX_TIMEOUT
+    }
+
     /// Validates the configuration before building.
     fn validate_config(&self) -> std::result::Result<(), String> {
         // Validate API key is not empty
@@ -98,6 +295,9 @@ impl NvisyConfigBuilder {
         {
             return Err("API key cannot be empty".to_string());
         }
+        if self.api_key.is_none() && self.auth.as_ref().is_none_or(Option::is_none) {
+            return Err("An API key or auth provider is required".to_string());
+        }
 
         // Validate base URL
         if let Some(ref base_url) = self.base_url
@@ -107,15 +307,40 @@ impl NvisyConfigBuilder {
             return Err("Base URL must start with http:// or https://".to_string());
         }
 
+        // Validate console URL
+        if let Some(ref console_url) = self.console_url
+            && !console_url.starts_with("http://")
+            && !console_url.starts_with("https://")
+        {
+            return Err("Console URL must start with http:// or https://".to_string());
+        }
+
+        // Validate proxy URL
+        if let Some(Some(ref proxy)) = self.proxy
+            && url::Url::parse(proxy).is_err()
+        {
+            return Err("Proxy must be a valid URL".to_string());
+        }
+
         // Validate timeout is reasonable
+        let max_timeout = self.max_timeout.unwrap_or(DEFAULT_MAX_TIMEOUT);
         if let Some(timeout) = self.timeout {
             if timeout.is_zero() {
                 return Err("Timeout must be greater than 0".to_string());
             }
-            if timeout > Duration::from_secs(300) {
-                return Err("Timeout cannot exceed 300 seconds (5 minutes)".to_string());
+            if timeout > max_timeout {
+                return Err(format!(
+                    "Timeout cannot exceed {} seconds",
+                    max_timeout.as_secs()
+                ));
             }
         }
+        if self.connect_timeout.flatten().is_some_and(|t| t.is_zero()) {
+            return Err("Connect timeout must be greater than 0".to_string());
+        }
+        if self.read_timeout.flatten().is_some_and(|t| t.is_zero()) {
+            return Err("Read timeout must be greater than 0".to_string());
+        }
 
         Ok(())
     }
@@ -125,6 +350,191 @@ impl NvisyConfigBuilder {
         self.with_timeout(Duration::from_secs(secs))
     }
 
+    /// Sets a callback invoked before each request is sent, including each
+    /// redirect hop.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use nvisy_sdk::NvisyConfig;
+    /// let config = NvisyConfig::builder()
+    ///     .with_api_key("your-api-key")
+    ///     .on_request(|info| println!("--> {} {}", info.method(), info.url()))
+    ///     .on_response(|info| println!("<-- {} in {:?}", info.status(), info.elapsed()))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn on_request<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&RequestInfo<'_>) + Send + Sync + 'static,
+    {
+        self.hooks
+            .get_or_insert_with(Hooks::default)
+            .set_on_request(Arc::new(hook));
+        self
+    }
+
+    /// Sets a callback invoked when a response is received, whatever its
+    /// status.
+    pub fn on_response<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&ResponseInfo<'_>) + Send + Sync + 'static,
+    {
+        self.hooks
+            .get_or_insert_with(Hooks::default)
+            .set_on_response(Arc::new(hook));
+        self
+    }
+
+    /// Sets a callback invoked before a failed operation is retried.
+    pub fn on_retry<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&RetryInfo<'_>) + Send + Sync + 'static,
+    {
+        self.hooks
+            .get_or_insert_with(Hooks::default)
+            .set_on_retry(Arc::new(hook));
+        self
+    }
+
+    /// Sets a callback invoked when a request fails without a response,
+    /// such as on a connection error or timeout.
+    pub fn on_error<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&ErrorInfo<'_>) + Send + Sync + 'static,
+    {
+        self.hooks
+            .get_or_insert_with(Hooks::default)
+            .set_on_error(Arc::new(hook));
+        self
+    }
+
+    /// Authenticates requests with the given provider instead of an API key.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use nvisy_sdk::{BearerToken, NvisyConfig};
+    /// let config = NvisyConfig::builder()
+    ///     .with_auth(BearerToken::new("user-access-token"))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_auth<P: AuthProvider + 'static>(mut self, provider: P) -> Self {
+        self.auth = Some(Some(Arc::new(provider)));
+        self
+    }
+
+    /// Fetches the API key from `provider` before each request.
+    ///
+    /// Lets keys kept in a secrets manager be loaded lazily and rotated
+    /// without rebuilding the client. The provider is called for every
+    /// request, including retries, so it should return a cached key and
+    /// only reach out to the secrets store when the key is due to change.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::sync::{Arc, RwLock};
+    /// # use nvisy_sdk::NvisyConfig;
+    /// let current_key = Arc::new(RwLock::new(String::from("initial-key")));
+    /// let key = current_key.clone();
+    /// let config = NvisyConfig::builder()
+    ///     .with_api_key_provider(move || {
+    ///         let key = key.read().unwrap().clone();
+    ///         async move { key }
+    ///     })
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Later, after the secret was rotated:
+    /// *current_key.write().unwrap() = String::from("rotated-key");
+    /// ```
+    pub fn with_api_key_provider<F, Fut>(self, provider: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = String> + Send + 'static,
+    {
+        self.with_auth(ApiKeyFn(provider))
+    }
+
+    /// Resolves `host` to `addr` instead of querying DNS.
+    ///
+    /// Can be called several times for the same host to provide multiple
+    /// addresses. A port of `0` uses the default port of the URL scheme.
+    /// Useful for split-horizon DNS setups, or for pointing tests at a local
+    /// server without editing `/etc/hosts`. Has no effect on `wasm32`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use nvisy_sdk::NvisyConfig;
+    /// let config = NvisyConfig::builder()
+    ///     .with_api_key("your-api-key")
+    ///     .with_resolve("api.nvisy.com", "127.0.0.1:8443".parse().unwrap())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_resolve(mut self, host: impl Into<String>, addr: SocketAddr) -> Self {
+        self.dns
+            .get_or_insert_with(DnsSettings::default)
+            .add_override(host.into().to_ascii_lowercase(), addr);
+        self
+    }
+
+    /// Resolves host names with a custom resolver instead of the system one.
+    ///
+    /// Overrides set with [`with_resolve`](Self::with_resolve) still take
+    /// precedence.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_dns_resolver<R>(mut self, resolver: R) -> Self
+    where
+        R: reqwest::dns::Resolve + 'static,
+    {
+        self.dns
+            .get_or_insert_with(DnsSettings::default)
+            .set_resolver(Arc::new(resolver));
+        self
+    }
+
+    /// Limits requests addressed to a workspace to `limit` per second.
+    ///
+    /// Requests over the budget wait for a free slot instead of failing.
+    /// Requests whose path names the workspace (`/workspaces/{id}/...`)
+    /// count against it, as do requests made with
+    /// [`RequestOptions::with_workspace`](crate::RequestOptions::with_workspace),
+    /// such as downloads of the workspace's files. A limit of zero is
+    /// treated as one.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use nvisy_sdk::NvisyConfig;
+    /// # let migration_workspace = uuid::Uuid::nil();
+    /// let config = NvisyConfig::builder()
+    ///     .with_api_key("your-api-key")
+    ///     .with_rate_budget(migration_workspace, 5)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_rate_budget(mut self, workspace_id: Uuid, limit: u32) -> Self {
+        self.rate_budgets
+            .get_or_insert_with(RateBudgets::default)
+            .set(workspace_id, limit);
+        self
+    }
+
+    /// Adds an interceptor invoked around every request.
+    ///
+    /// Interceptors run in the order they are added. See
+    /// [`RequestInterceptor`] for an example.
+    pub fn with_interceptor<I: RequestInterceptor + 'static>(mut self, interceptor: I) -> Self {
+        self.interceptors
+            .get_or_insert_with(Vec::new)
+            .push(Arc::new(interceptor));
+        self
+    }
+
     /// Creates a Nvisy API client directly from the builder.
     ///
     /// This is a convenience method that builds the configuration and
@@ -181,6 +591,9 @@ impl NvisyConfig {
     }
 
     /// Returns the API key.
+    ///
+    /// Empty if requests are authenticated by another
+    /// [`AuthProvider`].
     pub fn api_key(&self) -> &str {
         &self.api_key
     }
@@ -202,11 +615,119 @@ impl NvisyConfig {
         &self.base_url
     }
 
+    /// Returns the base URL of the web console.
+    pub fn console_url(&self) -> &str {
+        &self.console_url
+    }
+
     /// Returns the timeout duration.
     pub fn timeout(&self) -> Duration {
         self.timeout
     }
 
+    /// Returns the proxy URL, if set.
+    pub fn proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
+    /// Returns the upper bound accepted for the timeout.
+    pub fn max_timeout(&self) -> Duration {
+        self.max_timeout
+    }
+
+    /// Returns the connect timeout, if set.
+    pub fn connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Returns the read timeout, if set.
+    pub fn read_timeout(&self) -> Option<Duration> {
+        self.read_timeout
+    }
+
+    /// Returns the redirect policy.
+    pub fn redirect_policy(&self) -> &RedirectPolicy {
+        &self.redirect_policy
+    }
+
+    /// Returns the retry policy.
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Returns whether the `Authorization` header is removed on redirects
+    /// that leave the base URL's origin.
+    pub fn strip_auth_on_redirect(&self) -> bool {
+        self.strip_auth_on_redirect
+    }
+
+    /// Returns whether the client refuses requests that could modify data.
+    pub fn read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns how long health check results are cached, if at all.
+    pub fn health_cache_ttl(&self) -> Option<Duration> {
+        self.health_cache_ttl
+    }
+
+    /// Returns the maximum response body size, if limited.
+    pub fn max_response_size(&self) -> Option<u64> {
+        self.max_response_size
+    }
+
+    /// Returns the maximum upload size, if limited.
+    pub fn max_upload_size(&self) -> Option<u64> {
+        self.max_upload_size
+    }
+
+    /// Returns the telemetry configuration, if enabled.
+    pub fn telemetry(&self) -> Option<&Telemetry> {
+        self.telemetry.as_ref()
+    }
+
+    /// Returns the download cache, if enabled.
+    pub fn download_cache(&self) -> Option<&DownloadCache> {
+        self.download_cache.as_ref()
+    }
+
+    /// Returns the response schema migrations.
+    pub fn schema_migrations(&self) -> &SchemaMigrations {
+        &self.schema_migrations
+    }
+
+    /// Returns the per-second request limit for a workspace, if one is set.
+    pub fn rate_budget(&self, workspace_id: Uuid) -> Option<u32> {
+        self.rate_budgets.limit(workspace_id)
+    }
+
+    /// Returns the workspace rate budgets.
+    pub(crate) fn rate_budgets(&self) -> &RateBudgets {
+        &self.rate_budgets
+    }
+
+    /// Returns the provider of request credentials.
+    pub(crate) fn auth_provider(&self) -> Arc<dyn AuthProvider> {
+        self.auth
+            .clone()
+            .unwrap_or_else(|| Arc::new(ApiKey::new(self.api_key.clone())))
+    }
+
+    /// Returns the DNS settings.
+    pub(crate) fn dns(&self) -> &DnsSettings {
+        &self.dns
+    }
+
+    /// Returns the registered callbacks.
+    pub(crate) fn hooks(&self) -> &Hooks {
+        &self.hooks
+    }
+
+    /// Returns the registered interceptors.
+    pub(crate) fn interceptors(&self) -> &[Arc<dyn RequestInterceptor>] {
+        &self.interceptors
+    }
+
     /// Returns a clone of the custom reqwest client, if one was provided.
     pub(crate) fn client(&self) -> Option<Client> {
         self.client.clone()
@@ -217,8 +738,28 @@ impl fmt::Debug for NvisyConfig {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("NvisyConfig")
             .field("api_key", &self.masked_api_key())
+            .field("auth", &self.auth)
             .field("base_url", &self.base_url)
+            .field("console_url", &self.console_url)
             .field("timeout", &self.timeout)
+            .field("max_timeout", &self.max_timeout)
+            .field("connect_timeout", &self.connect_timeout)
+            .field("read_timeout", &self.read_timeout)
+            .field("dns", &self.dns)
+            .field("proxy", &self.proxy)
+            .field("redirect_policy", &self.redirect_policy)
+            .field("strip_auth_on_redirect", &self.strip_auth_on_redirect)
+            .field("read_only", &self.read_only)
+            .field("retry_policy", &self.retry_policy)
+            .field("health_cache_ttl", &self.health_cache_ttl)
+            .field("max_response_size", &self.max_response_size)
+            .field("max_upload_size", &self.max_upload_size)
+            .field("hooks", &self.hooks)
+            .field("interceptors", &self.interceptors.len())
+            .field("telemetry", &self.telemetry)
+            .field("download_cache", &self.download_cache)
+            .field("schema_migrations", &self.schema_migrations)
+            .field("rate_budgets", &self.rate_budgets)
             .finish()
     }
 }
@@ -298,6 +839,58 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_config_builder_max_timeout() -> Result<()> {
+        let config = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_max_timeout(Duration::from_secs(3600))
+            .with_timeout(Duration::from_secs(1800))
+            .with_connect_timeout(Duration::from_secs(5))
+            .with_read_timeout(Duration::from_secs(60))
+            .build()?;
+        assert_eq!(config.timeout(), Duration::from_secs(1800));
+        assert_eq!(config.connect_timeout(), Some(Duration::from_secs(5)));
+        assert_eq!(config.read_timeout(), Some(Duration::from_secs(60)));
+
+        let result = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_connect_timeout(Duration::ZERO)
+            .build();
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_builder_redirect_policy() -> Result<()> {
+        let config = NvisyConfig::builder().with_api_key("test_key").build()?;
+        assert!(matches!(
+            config.redirect_policy(),
+            RedirectPolicy::Limited(10)
+        ));
+
+        let config = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_redirect_policy(RedirectPolicy::none())
+            .build()?;
+        assert!(matches!(config.redirect_policy(), RedirectPolicy::None));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_builder_strip_auth_on_redirect() -> Result<()> {
+        let config = NvisyConfig::builder().with_api_key("test_key").build()?;
+        assert!(config.strip_auth_on_redirect());
+
+        let config = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_strip_auth_on_redirect(false)
+            .build()?;
+        assert!(!config.strip_auth_on_redirect());
+
+        Ok(())
+    }
+
     #[test]
     fn test_masked_api_key() -> Result<()> {
         let config = NvisyConfig::builder()
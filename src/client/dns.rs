@@ -0,0 +1,71 @@
+//! DNS overrides for the connections made by the client.
+//!
+//! Browsers resolve host names themselves, so these settings have no effect
+//! on `wasm32` targets.
+
+use std::fmt;
+use std::net::SocketAddr;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::ClientBuilder;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::dns::Resolve;
+
+/// Static host overrides and an optional custom resolver.
+#[derive(Clone, Default)]
+pub(crate) struct DnsSettings {
+    overrides: Vec<(String, SocketAddr)>,
+    #[cfg(not(target_arch = "wasm32"))]
+    resolver: Option<Arc<dyn Resolve>>,
+}
+
+impl DnsSettings {
+    /// Resolves `host` to `addr` instead of querying DNS.
+    pub(crate) fn add_override(&mut self, host: String, addr: SocketAddr) {
+        self.overrides.push((host, addr));
+    }
+
+    /// Resolves host names without an override with `resolver`.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn set_resolver(&mut self, resolver: Arc<dyn Resolve>) {
+        self.resolver = Some(resolver);
+    }
+
+    /// Applies the settings to a client builder.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn apply(&self, mut builder: ClientBuilder) -> ClientBuilder {
+        // reqwest keeps one entry per host, so group repeated overrides.
+        let mut hosts: Vec<&str> = self
+            .overrides
+            .iter()
+            .map(|(host, _)| host.as_str())
+            .collect();
+        hosts.sort_unstable();
+        hosts.dedup();
+        for host in hosts {
+            let addrs: Vec<SocketAddr> = self
+                .overrides
+                .iter()
+                .filter(|(other, _)| other == host)
+                .map(|(_, addr)| *addr)
+                .collect();
+            builder = builder.resolve_to_addrs(host, &addrs);
+        }
+        if let Some(resolver) = &self.resolver {
+            builder = builder.dns_resolver2(resolver.clone());
+        }
+        builder
+    }
+}
+
+impl fmt::Debug for DnsSettings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("DnsSettings");
+        debug.field("overrides", &self.overrides);
+        #[cfg(not(target_arch = "wasm32"))]
+        debug.field("custom_resolver", &self.resolver.is_some());
+        debug.finish()
+    }
+}
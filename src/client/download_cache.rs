@@ -0,0 +1,234 @@
+//! Local disk cache for downloaded file content.
+//!
+//! Entries are keyed by the SHA-256 checksum the API reports for each file,
+//! so identical content is stored once no matter how many files share it,
+//! and a changed file never returns stale content.
+//!
+//! Browsers have no file system, so on `wasm32` targets the cache is never
+//! consulted.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::SystemTime;
+
+#[cfg(not(target_arch = "wasm32"))]
+use sha2::{Digest, Sha256};
+use tokio::sync::Mutex;
+
+/// Extension of cache entries.
+#[cfg(not(target_arch = "wasm32"))]
+const ENTRY_EXTENSION: &str = "blob";
+
+/// Disk cache consulted by the `download_file*` methods of
+/// [`FilesService`](crate::service::FilesService).
+///
+/// When the cache holds more than `max_size` bytes, the least recently used
+/// entries are evicted. Files without a checksum are never cached. Cache
+/// failures are not reported; the content is downloaded instead.
+///
+/// [`download_file_stream`] streams content straight from the API and
+/// bypasses the cache, so that large files are never buffered in memory.
+///
+/// [`download_file_stream`]: crate::service::FilesService::download_file_stream
+///
+/// # Examples
+///
+/// ```no_run
+/// use nvisy_sdk::{DownloadCache, NvisyConfig};
+///
+/// let config = NvisyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_download_cache(DownloadCache::new("/var/cache/nvisy", 10 << 30))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct DownloadCache {
+    dir: PathBuf,
+    max_size: u64,
+    /// Serializes writes and evictions.
+    #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+    lock: Arc<Mutex<()>>,
+}
+
+impl DownloadCache {
+    /// Creates a cache storing entries in `dir`, holding at most `max_size`
+    /// bytes.
+    ///
+    /// The directory is created on first use.
+    pub fn new(dir: impl Into<PathBuf>, max_size: u64) -> Self {
+        Self {
+            dir: dir.into(),
+            max_size,
+            lock: Arc::new(Mutex::new(())),
+        }
+    }
+
+    /// Returns the cache directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the maximum total size of cached content in bytes.
+    pub fn max_size(&self) -> u64 {
+        self.max_size
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl DownloadCache {
+    /// Returns `None`, since nothing is cached without a file system.
+    pub(crate) async fn get(&self, _checksum: &str) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Does nothing, since nothing is cached without a file system.
+    pub(crate) async fn put(&self, _checksum: &str, _content: &[u8]) {}
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl DownloadCache {
+    /// Returns the cached content with the given checksum, if present.
+    ///
+    /// Entries whose content no longer matches the checksum are removed.
+    pub(crate) async fn get(&self, checksum: &str) -> Option<Vec<u8>> {
+        let path = self.entry_path(checksum)?;
+        let content = tokio::fs::read(&path).await.ok()?;
+
+        if !format!("{:x}", Sha256::digest(&content)).eq_ignore_ascii_case(checksum) {
+            let _ = tokio::fs::remove_file(&path).await;
+            return None;
+        }
+
+        // Mark the entry as recently used.
+        let _ = tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::options().append(true).open(&path)?;
+            file.set_modified(SystemTime::now())
+        })
+        .await;
+        Some(content)
+    }
+
+    /// Stores content under its checksum, evicting old entries as needed.
+    pub(crate) async fn put(&self, checksum: &str, content: &[u8]) {
+        let Some(path) = self.entry_path(checksum) else {
+            return;
+        };
+        if content.len() as u64 > self.max_size {
+            return;
+        }
+
+        let _guard = self.lock.lock().await;
+        if tokio::fs::create_dir_all(&self.dir).await.is_err() {
+            return;
+        }
+
+        // Write to a temporary file first so readers never see partial
+        // content.
+        let partial = path.with_extension("partial");
+        if tokio::fs::write(&partial, content).await.is_err()
+            || tokio::fs::rename(&partial, &path).await.is_err()
+        {
+            let _ = tokio::fs::remove_file(&partial).await;
+            return;
+        }
+
+        self.evict().await;
+    }
+
+    /// Removes the least recently used entries until the cache fits.
+    async fn evict(&self) {
+        let Ok(mut dir) = tokio::fs::read_dir(&self.dir).await else {
+            return;
+        };
+
+        let mut entries = Vec::new();
+        let mut total = 0;
+        while let Ok(Some(entry)) = dir.next_entry().await {
+            let path = entry.path();
+            if path.extension().is_none_or(|ext| ext != ENTRY_EXTENSION) {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+            let used = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            total += metadata.len();
+            entries.push((used, metadata.len(), path));
+        }
+
+        entries.sort_by_key(|(used, _, _)| *used);
+        for (_, len, path) in entries {
+            if total <= self.max_size {
+                break;
+            }
+            if tokio::fs::remove_file(&path).await.is_ok() {
+                total -= len;
+            }
+        }
+    }
+
+    /// Returns the path of the entry for a checksum, or `None` if the
+    /// checksum is not a hex-encoded SHA-256 digest.
+    fn entry_path(&self, checksum: &str) -> Option<PathBuf> {
+        if checksum.len() != 64 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let name = format!("{}.{ENTRY_EXTENSION}", checksum.to_ascii_lowercase());
+        Some(self.dir.join(name))
+    }
+}
+
+impl fmt::Debug for DownloadCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DownloadCache")
+            .field("dir", &self.dir)
+            .field("max_size", &self.max_size)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn checksum(content: &[u8]) -> String {
+        format!("{:x}", Sha256::digest(content))
+    }
+
+    /// Sets the last use of a cache entry to `secs` after the Unix epoch.
+    fn set_last_used(cache: &DownloadCache, content: &[u8], secs: u64) {
+        let path = cache.entry_path(&checksum(content)).unwrap();
+        let file = std::fs::File::options().append(true).open(path).unwrap();
+        file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cache_evicts_least_recently_used() {
+        let dir = std::env::temp_dir().join(format!("nvisy-cache-{}", Uuid::new_v4()));
+        let cache = DownloadCache::new(&dir, 10);
+
+        cache.put(&checksum(b"aaaa"), b"aaaa").await;
+        cache.put(&checksum(b"bbbb"), b"bbbb").await;
+        set_last_used(&cache, b"aaaa", 1);
+        set_last_used(&cache, b"bbbb", 2);
+        assert!(cache.get(&checksum(b"aaaa")).await.is_some());
+        cache.put(&checksum(b"cccc"), b"cccc").await;
+
+        assert_eq!(
+            cache.get(&checksum(b"aaaa")).await.as_deref(),
+            Some(&b"aaaa"[..])
+        );
+        assert!(cache.get(&checksum(b"bbbb")).await.is_none());
+        assert!(cache.get(&checksum(b"cccc")).await.is_some());
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
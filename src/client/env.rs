@@ -0,0 +1,161 @@
+//! Configuration from environment variables.
+
+use std::env::{self, VarError};
+use std::time::Duration;
+
+use super::config::{NvisyConfig, NvisyConfigBuilder, NvisyConfigBuilderError};
+use crate::error::{Error, Result};
+
+/// Variable holding the API key.
+const API_KEY_VAR: &str = "NVISY_API_KEY";
+
+/// Variable holding the API base URL.
+const BASE_URL_VAR: &str = "NVISY_BASE_URL";
+
+/// Variable holding the request timeout in whole seconds.
+const TIMEOUT_SECS_VAR: &str = "NVISY_TIMEOUT_SECS";
+
+/// Variable holding the URL of a proxy for all requests.
+const PROXY_VAR: &str = "NVISY_PROXY";
+
+impl NvisyConfigBuilder {
+    /// Applies settings from environment variables.
+    ///
+    /// | Variable             | Setting                                       |
+    /// |----------------------|-----------------------------------------------|
+    /// | `NVISY_API_KEY`      | [`with_api_key`](Self::with_api_key)          |
+    /// | `NVISY_BASE_URL`     | [`with_base_url`](Self::with_base_url)        |
+    /// | `NVISY_TIMEOUT_SECS` | [`with_timeout_secs`](Self::with_timeout_secs) |
+    /// | `NVISY_PROXY`        | [`with_proxy`](Self::with_proxy)              |
+    ///
+    /// Unset or empty variables leave the setting unchanged. Settings made
+    /// after this call take precedence. The standard `HTTPS_PROXY`,
+    /// `HTTP_PROXY`, and `NO_PROXY` variables are honored by the HTTP
+    /// client when `NVISY_PROXY` is not set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] naming the variable if a value is not
+    /// valid Unicode or, for `NVISY_TIMEOUT_SECS`, not a number.
+    pub fn with_env(self) -> Result<Self> {
+        self.with_env_from(|name| env::var(name))
+    }
+
+    /// Applies settings from variables looked up with `lookup`.
+    fn with_env_from<F>(mut self, lookup: F) -> Result<Self>
+    where
+        F: Fn(&str) -> std::result::Result<String, VarError>,
+    {
+        let var = |name: &str| match lookup(name) {
+            Ok(value) if value.trim().is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(VarError::NotPresent) => Ok(None),
+            Err(VarError::NotUnicode(_)) => Err(invalid(name, "is not valid Unicode")),
+        };
+
+        if let Some(api_key) = var(API_KEY_VAR)? {
+            self = self.with_api_key(api_key);
+        }
+        if let Some(base_url) = var(BASE_URL_VAR)? {
+            self = self.with_base_url(base_url);
+        }
+        if let Some(timeout) = var(TIMEOUT_SECS_VAR)? {
+            let secs = timeout
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| invalid(TIMEOUT_SECS_VAR, "must be a whole number of seconds"))?;
+            self = self.with_timeout(Duration::from_secs(secs));
+        }
+        if let Some(proxy) = var(PROXY_VAR)? {
+            self = self.with_proxy(proxy);
+        }
+        Ok(self)
+    }
+}
+
+impl NvisyConfig {
+    /// Creates a configuration from environment variables.
+    ///
+    /// `NVISY_API_KEY` is required; see
+    /// [`NvisyConfigBuilder::with_env`] for the other variables.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use nvisy_sdk::{NvisyConfig, Result};
+    ///
+    /// # fn example() -> Result<()> {
+    /// let client = NvisyConfig::from_env()?.build_client()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        from_env_with(|name| env::var(name))
+    }
+}
+
+/// Builds a configuration from variables looked up with `lookup`.
+fn from_env_with<F>(lookup: F) -> Result<NvisyConfig>
+where
+    F: Fn(&str) -> std::result::Result<String, VarError>,
+{
+    match lookup(API_KEY_VAR) {
+        Ok(api_key) if !api_key.trim().is_empty() => {}
+        // Reported by `with_env_from`.
+        Err(VarError::NotUnicode(_)) => {}
+        _ => return Err(invalid(API_KEY_VAR, "is not set")),
+    }
+    Ok(NvisyConfig::builder().with_env_from(lookup)?.build()?)
+}
+
+/// Returns a configuration error for an environment variable.
+fn invalid(name: &str, reason: &str) -> Error {
+    Error::Config(NvisyConfigBuilderError::ValidationError(format!(
+        "Environment variable {name} {reason}"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn lookup(
+        vars: &[(&str, &str)],
+    ) -> impl Fn(&str) -> std::result::Result<String, VarError> + use<> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned().ok_or(VarError::NotPresent)
+    }
+
+    #[test]
+    fn test_from_env_reads_settings() -> Result<()> {
+        let config = from_env_with(lookup(&[
+            ("NVISY_API_KEY", "env-key"),
+            ("NVISY_BASE_URL", "https://eu.api.nvisy.com"),
+            ("NVISY_TIMEOUT_SECS", "90"),
+            ("NVISY_PROXY", "http://proxy.internal:3128"),
+        ]))?;
+
+        assert_eq!(config.base_url(), "https://eu.api.nvisy.com");
+        assert_eq!(config.timeout(), Duration::from_secs(90));
+        assert_eq!(config.proxy(), Some("http://proxy.internal:3128"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_env_names_the_offending_variable() {
+        let err = from_env_with(lookup(&[])).unwrap_err();
+        assert!(err.to_string().contains("NVISY_API_KEY is not set"));
+
+        let err = from_env_with(lookup(&[
+            ("NVISY_API_KEY", "env-key"),
+            ("NVISY_TIMEOUT_SECS", "soon"),
+        ]))
+        .unwrap_err();
+        assert!(err.to_string().contains("NVISY_TIMEOUT_SECS"));
+    }
+}
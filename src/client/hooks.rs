@@ -0,0 +1,245 @@
+//! Callback hooks for observing requests made by the Nvisy API client.
+//!
+//! Hooks are registered on the [`NvisyConfigBuilder`] and invoked
+//! synchronously on the task making the request, so they should return
+//! quickly. They receive lightweight metadata describing the event rather
+//! than the request or response itself.
+//!
+//! [`NvisyConfigBuilder`]: crate::NvisyConfigBuilder
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+use url::Url;
+
+use crate::error::Error;
+
+/// Callback invoked before a request is sent.
+pub type RequestHook = dyn Fn(&RequestInfo<'_>) + Send + Sync;
+
+/// Callback invoked when a response is received.
+pub type ResponseHook = dyn Fn(&ResponseInfo<'_>) + Send + Sync;
+
+/// Callback invoked before a failed operation is retried.
+pub type RetryHook = dyn Fn(&RetryInfo<'_>) + Send + Sync;
+
+/// Callback invoked when a request fails without a response.
+pub type ErrorHook = dyn Fn(&ErrorInfo<'_>) + Send + Sync;
+
+/// Set of callbacks invoked by the client.
+#[derive(Clone, Default)]
+pub(crate) struct Hooks {
+    on_request: Option<Arc<RequestHook>>,
+    on_response: Option<Arc<ResponseHook>>,
+    on_retry: Option<Arc<RetryHook>>,
+    on_error: Option<Arc<ErrorHook>>,
+}
+
+impl Hooks {
+    /// Sets the callback invoked before a request is sent.
+    pub(crate) fn set_on_request(&mut self, hook: Arc<RequestHook>) {
+        self.on_request = Some(hook);
+    }
+
+    /// Sets the callback invoked when a response is received.
+    pub(crate) fn set_on_response(&mut self, hook: Arc<ResponseHook>) {
+        self.on_response = Some(hook);
+    }
+
+    /// Sets the callback invoked before a failed operation is retried.
+    pub(crate) fn set_on_retry(&mut self, hook: Arc<RetryHook>) {
+        self.on_retry = Some(hook);
+    }
+
+    /// Sets the callback invoked when a request fails without a response.
+    pub(crate) fn set_on_error(&mut self, hook: Arc<ErrorHook>) {
+        self.on_error = Some(hook);
+    }
+
+    /// Invokes the request hook, if set.
+    pub(crate) fn request(&self, info: &RequestInfo<'_>) {
+        if let Some(hook) = &self.on_request {
+            hook(info);
+        }
+    }
+
+    /// Invokes the response hook, if set.
+    pub(crate) fn response(&self, info: &ResponseInfo<'_>) {
+        if let Some(hook) = &self.on_response {
+            hook(info);
+        }
+    }
+
+    /// Invokes the retry hook, if set.
+    pub(crate) fn retry(&self, info: &RetryInfo<'_>) {
+        if let Some(hook) = &self.on_retry {
+            hook(info);
+        }
+    }
+
+    /// Invokes the error hook, if set.
+    pub(crate) fn error(&self, info: &ErrorInfo<'_>) {
+        if let Some(hook) = &self.on_error {
+            hook(info);
+        }
+    }
+}
+
+impl fmt::Debug for Hooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hooks")
+            .field("on_request", &self.on_request.is_some())
+            .field("on_response", &self.on_response.is_some())
+            .field("on_retry", &self.on_retry.is_some())
+            .field("on_error", &self.on_error.is_some())
+            .finish()
+    }
+}
+
+/// Request about to be sent, passed to the `on_request` hook.
+///
+/// Each redirect hop is reported as a separate request.
+#[derive(Debug)]
+pub struct RequestInfo<'a> {
+    method: &'a Method,
+    url: &'a Url,
+}
+
+impl<'a> RequestInfo<'a> {
+    /// Creates new request metadata.
+    pub(crate) fn new(method: &'a Method, url: &'a Url) -> Self {
+        Self { method, url }
+    }
+
+    /// Returns the request method.
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    /// Returns the request URL.
+    pub fn url(&self) -> &Url {
+        self.url
+    }
+}
+
+/// Response received for a request, passed to the `on_response` hook.
+///
+/// Error statuses are reported here as well, since the request itself
+/// completed.
+#[derive(Debug)]
+pub struct ResponseInfo<'a> {
+    method: &'a Method,
+    url: &'a Url,
+    status: StatusCode,
+    elapsed: Duration,
+}
+
+impl<'a> ResponseInfo<'a> {
+    /// Creates new response metadata.
+    pub(crate) fn new(
+        method: &'a Method,
+        url: &'a Url,
+        status: StatusCode,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            method,
+            url,
+            status,
+            elapsed,
+        }
+    }
+
+    /// Returns the request method.
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    /// Returns the request URL.
+    pub fn url(&self) -> &Url {
+        self.url
+    }
+
+    /// Returns the response status code.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the time between sending the request and receiving the
+    /// response headers.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Operation about to be retried, passed to the `on_retry` hook.
+#[derive(Debug)]
+pub struct RetryInfo<'a> {
+    attempt: u32,
+    error: &'a Error,
+}
+
+impl<'a> RetryInfo<'a> {
+    /// Creates new retry metadata.
+    pub(crate) fn new(attempt: u32, error: &'a Error) -> Self {
+        Self { attempt, error }
+    }
+
+    /// Returns the number of the retry about to be made, starting at 1.
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Returns the error that caused the retry.
+    pub fn error(&self) -> &Error {
+        self.error
+    }
+}
+
+/// Request that failed without a response, passed to the `on_error` hook.
+#[derive(Debug)]
+pub struct ErrorInfo<'a> {
+    method: &'a Method,
+    url: &'a Url,
+    error: &'a Error,
+    elapsed: Duration,
+}
+
+impl<'a> ErrorInfo<'a> {
+    /// Creates new error metadata.
+    pub(crate) fn new(
+        method: &'a Method,
+        url: &'a Url,
+        error: &'a Error,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            method,
+            url,
+            error,
+            elapsed,
+        }
+    }
+
+    /// Returns the request method.
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    /// Returns the request URL.
+    pub fn url(&self) -> &Url {
+        self.url
+    }
+
+    /// Returns the error the request failed with.
+    pub fn error(&self) -> &Error {
+        self.error
+    }
+
+    /// Returns the time between sending the request and the failure.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
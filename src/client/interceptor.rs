@@ -0,0 +1,106 @@
+//! Interceptors that inspect and modify requests made by the client.
+//!
+//! Unlike the observation hooks, interceptors receive the request itself
+//! and may change it before it is sent, which makes them suitable for
+//! request signing, custom headers, audit logging, and metrics.
+
+use reqwest::{Request, Response};
+
+use crate::error::Result;
+
+/// Middleware invoked around every request sent by the client.
+///
+/// Interceptors run in the order they were registered with
+/// [`NvisyConfigBuilder::with_interceptor`](crate::NvisyConfigBuilder::with_interceptor),
+/// once per attempt: retries and redirect hops are intercepted separately,
+/// after credentials have been added.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nvisy_sdk::{NvisyConfig, RequestInterceptor, Result};
+/// use reqwest::header::HeaderValue;
+///
+/// struct Tenant(&'static str);
+///
+/// impl RequestInterceptor for Tenant {
+///     fn on_request(&self, request: &mut reqwest::Request) -> Result<()> {
+///         request
+///             .headers_mut()
+///             .insert("X-Tenant", HeaderValue::from_static(self.0));
+///         Ok(())
+///     }
+/// }
+///
+/// let config = NvisyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_interceptor(Tenant("acme"))
+///     .build()
+///     .unwrap();
+/// ```
+pub trait RequestInterceptor: Send + Sync {
+    /// Inspects or modifies a request before it is sent.
+    ///
+    /// Returning an error aborts the request with that error.
+    fn on_request(&self, request: &mut Request) -> Result<()> {
+        let _ = request;
+        Ok(())
+    }
+
+    /// Observes a response, whatever its status.
+    fn on_response(&self, response: &Response) {
+        let _ = response;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU16, Ordering};
+
+    use reqwest::Method;
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+
+    #[derive(Default)]
+    struct Signer {
+        last_status: Arc<AtomicU16>,
+    }
+
+    impl RequestInterceptor for Signer {
+        fn on_request(&self, request: &mut Request) -> Result<()> {
+            let signature = format!("{} {}", request.method(), request.url().path());
+            request
+                .headers_mut()
+                .insert("X-Signature", HeaderValue::from_str(&signature).unwrap());
+            Ok(())
+        }
+
+        fn on_response(&self, response: &Response) {
+            self.last_status
+                .store(response.status().as_u16(), Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_modifies_requests() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![mock::response(204, &[], "")]);
+
+        let signer = Signer::default();
+        let last_status = signer.last_status.clone();
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .with_interceptor(signer)
+            .build_client()?;
+        client.send(Method::GET, "/health/").await?;
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].header("x-signature"), Some("GET /health/"));
+        assert_eq!(last_status.load(Ordering::SeqCst), 204);
+        Ok(())
+    }
+}
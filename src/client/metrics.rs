@@ -0,0 +1,105 @@
+//! Request metrics recorded through the [`metrics`] crate facade.
+//!
+//! Every request attempt, including retries and redirect hops, updates:
+//!
+//! - `nvisy_sdk_requests_total` - counter labelled with `method`,
+//!   `endpoint`, and `status`
+//! - `nvisy_sdk_request_errors_total` - counter labelled with `method`,
+//!   `endpoint`, and `kind`, for error statuses and transport failures
+//! - `nvisy_sdk_request_duration_seconds` - histogram labelled with
+//!   `method` and `endpoint`
+//!
+//! Identifiers in the path are replaced with `{id}`, so
+//! `/files/3f2c.../content` is recorded as the `/files/{id}/content`
+//! endpoint. Install a recorder, such as a Prometheus exporter, to collect
+//! the metrics.
+
+use std::time::Duration;
+
+use reqwest::{Method, StatusCode};
+use url::Url;
+use uuid::Uuid;
+
+use crate::error::ErrorKind;
+
+const REQUESTS_TOTAL: &str = "nvisy_sdk_requests_total";
+const REQUEST_ERRORS_TOTAL: &str = "nvisy_sdk_request_errors_total";
+const REQUEST_DURATION_SECONDS: &str = "nvisy_sdk_request_duration_seconds";
+
+/// Records a request attempt that received a response.
+pub(crate) fn record_response(method: &Method, url: &Url, status: StatusCode, elapsed: Duration) {
+    let method = method.to_string();
+    let endpoint = endpoint(url);
+    metrics::counter!(
+        REQUESTS_TOTAL,
+        "method" => method.clone(),
+        "endpoint" => endpoint.clone(),
+        "status" => status.as_u16().to_string(),
+    )
+    .increment(1);
+    if status.is_client_error() || status.is_server_error() {
+        record_error(&method, &endpoint, ErrorKind::from_status(status));
+    }
+    metrics::histogram!(REQUEST_DURATION_SECONDS, "method" => method, "endpoint" => endpoint)
+        .record(elapsed.as_secs_f64());
+}
+
+/// Records a request attempt that failed without a response.
+pub(crate) fn record_failure(method: &Method, url: &Url, kind: ErrorKind, elapsed: Duration) {
+    let method = method.to_string();
+    let endpoint = endpoint(url);
+    metrics::counter!(
+        REQUESTS_TOTAL,
+        "method" => method.clone(),
+        "endpoint" => endpoint.clone(),
+        "status" => "none",
+    )
+    .increment(1);
+    record_error(&method, &endpoint, kind);
+    metrics::histogram!(REQUEST_DURATION_SECONDS, "method" => method, "endpoint" => endpoint)
+        .record(elapsed.as_secs_f64());
+}
+
+fn record_error(method: &str, endpoint: &str, kind: ErrorKind) {
+    metrics::counter!(
+        REQUEST_ERRORS_TOTAL,
+        "method" => method.to_string(),
+        "endpoint" => endpoint.to_string(),
+        "kind" => kind.as_str(),
+    )
+    .increment(1);
+}
+
+/// Returns the path of a URL with identifiers replaced by `{id}`.
+fn endpoint(url: &Url) -> String {
+    let Some(segments) = url.path_segments() else {
+        return url.path().to_string();
+    };
+    let segments: Vec<&str> = segments
+        .map(|segment| {
+            let is_id = segment.parse::<Uuid>().is_ok()
+                || (!segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()));
+            if is_id { "{id}" } else { segment }
+        })
+        .collect();
+    format!("/{}", segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_replaces_identifiers() {
+        let url =
+            Url::parse("https://api.nvisy.com/files/00000000-0000-0000-0000-000000000001/content")
+                .unwrap();
+        assert_eq!(endpoint(&url), "/files/{id}/content");
+
+        let url = Url::parse("https://api.nvisy.com/uploads/x/parts/3").unwrap();
+        assert_eq!(endpoint(&url), "/uploads/x/parts/{id}");
+
+        let url = Url::parse("https://api.nvisy.com/workspaces/").unwrap();
+        assert_eq!(endpoint(&url), "/workspaces/");
+    }
+}
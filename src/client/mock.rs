@@ -0,0 +1,99 @@
+//! Minimal HTTP server for exercising the client in unit tests.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+/// Request captured by the mock server.
+#[derive(Debug)]
+pub(crate) struct Captured {
+    /// Request line and headers.
+    pub(crate) head: String,
+    /// Request body.
+    pub(crate) body: Vec<u8>,
+}
+
+impl Captured {
+    /// Returns the value of the given header, if present.
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.head.lines().skip(1).find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    }
+
+    /// Returns the request line (e.g. `GET /path HTTP/1.1`).
+    pub(crate) fn request_line(&self) -> &str {
+        self.head.lines().next().unwrap_or_default()
+    }
+}
+
+/// Builds a raw HTTP response with the given status, headers, and body.
+pub(crate) fn response(status: u16, headers: &[(&str, &str)], body: &str) -> String {
+    let mut raw = format!("HTTP/1.1 {status} Mock\r\n");
+    for (name, value) in headers {
+        raw.push_str(&format!("{name}: {value}\r\n"));
+    }
+    raw.push_str(&format!(
+        "Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    ));
+    raw
+}
+
+/// Serves the given raw responses in order, one per connection.
+///
+/// Returns the base URL and a handle yielding the captured requests.
+pub(crate) fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<Captured>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let mut captured = Vec::new();
+        for raw in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            captured.push(read_request(&mut stream));
+            stream.write_all(raw.as_bytes()).unwrap();
+        }
+        captured
+    });
+
+    (base_url, handle)
+}
+
+fn read_request(stream: &mut impl Read) -> Captured {
+    let mut reader = BufReader::new(stream);
+    let mut head = String::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if line == "\r\n" || line.is_empty() {
+            break;
+        }
+        head.push_str(&line);
+    }
+
+    let captured = Captured {
+        head,
+        body: Vec::new(),
+    };
+    let mut body = Vec::new();
+    if let Some(len) = captured.header("content-length") {
+        body.resize(len.parse().unwrap(), 0);
+        reader.read_exact(&mut body).unwrap();
+    } else if captured.header("transfer-encoding") == Some("chunked") {
+        loop {
+            let mut size = String::new();
+            reader.read_line(&mut size).unwrap();
+            let size = usize::from_str_radix(size.trim(), 16).unwrap();
+            let mut chunk = vec![0; size + 2];
+            reader.read_exact(&mut chunk).unwrap();
+            if size == 0 {
+                break;
+            }
+            body.extend_from_slice(&chunk[..size]);
+        }
+    }
+
+    Captured { body, ..captured }
+}
@@ -1,8 +1,56 @@
 //! HTTP client for the Nvisy API.
 
+mod auth;
 mod config;
+mod dns;
+mod download_cache;
+mod env;
+mod hooks;
+mod interceptor;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(test)]
+pub(crate) mod mock;
 mod nvisy;
+#[cfg(feature = "profiles")]
+mod profile;
+pub(crate) mod progress;
+mod rate_budget;
+mod rate_limit;
+mod redirect;
+mod retry;
+pub(crate) mod runtime;
+mod schema;
+mod scoped;
+mod self_test;
+mod telemetry;
+mod urls;
 
+pub use auth::{
+    ApiKey, AuthFuture, AuthProvider, BearerToken, DEFAULT_REFRESH_MARGIN, OAuth2ClientCredentials,
+};
 pub(crate) use config::NvisyConfigBuilderError;
-pub use config::{DEFAULT_BASE_URL, DEFAULT_TIMEOUT, NvisyConfig, NvisyConfigBuilder};
+pub use config::{
+    DEFAULT_BASE_URL, DEFAULT_CONSOLE_URL, DEFAULT_MAX_TIMEOUT, DEFAULT_TIMEOUT, NvisyConfig,
+    NvisyConfigBuilder,
+};
+pub use download_cache::DownloadCache;
+pub use hooks::{
+    ErrorHook, ErrorInfo, RequestHook, RequestInfo, ResponseHook, ResponseInfo, RetryHook,
+    RetryInfo,
+};
+pub use interceptor::RequestInterceptor;
 pub use nvisy::NvisyClient;
+pub use progress::{ProgressHook, TransferProgress};
+pub use redirect::{DEFAULT_MAX_REDIRECTS, RedirectAttempt, RedirectFn, RedirectPolicy};
+pub use retry::{
+    DEFAULT_BASE_BACKOFF, DEFAULT_CHECKSUM_RETRIES, DEFAULT_MAX_ATTEMPTS, DEFAULT_MAX_BACKOFF,
+    RetryPolicy,
+};
+pub use schema::{MigrationFn, SCHEMA_VERSION, SCHEMA_VERSION_HEADER, SchemaMigrations};
+pub use scoped::{RequestOptions, ScopedClient};
+pub use self_test::{SelfTestCheck, SelfTestReport, SelfTestStep};
+pub use telemetry::{
+    DEFAULT_TELEMETRY_INTERVAL, SDK_VERSION, Telemetry, TelemetryReport, TelemetrySink,
+};
+pub use urls::ConsoleUrls;
@@ -4,15 +4,36 @@
 //! providing the core HTTP client functionality for interacting with the Nvisy API.
 
 use std::fmt;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
+use bytes::{Bytes, BytesMut};
+use futures_util::{StreamExt, future};
+use reqwest::header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, LOCATION};
 use reqwest::multipart::Form;
-use reqwest::{Client, Method, RequestBuilder, Response};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::redirect::Policy;
+use reqwest::{Client, Method, Request, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
 
+use super::auth::AuthProvider;
 use super::config::NvisyConfig;
+use super::hooks::{ErrorInfo, RequestInfo, ResponseInfo, RetryInfo};
+#[cfg(feature = "metrics")]
+use super::metrics;
+use super::progress::TransferProgress;
+use super::rate_limit;
+use super::redirect::{self, RedirectAttempt};
+use super::retry::RetryPolicy;
+use super::runtime::{self, Instant};
+use super::schema;
+use super::scoped::{IDEMPOTENCY_KEY_HEADER, IMPERSONATE_HEADER, RequestOptions, ScopedClient};
+use super::telemetry::{TelemetryReport, TelemetryState};
 #[cfg(feature = "tracing")]
 use crate::TRACING_TARGET_CLIENT;
-use crate::error::Result;
+use crate::error::{ApiErrorBody, Error, ErrorKind, Result, TimeoutPhase};
+use crate::model::{Capabilities, MonitorStatus};
 
 /// Main Nvisy API client for interacting with all Nvisy services.
 ///
@@ -83,6 +104,10 @@ use crate::error::Result;
 #[derive(Clone)]
 pub struct NvisyClient {
     pub(crate) inner: Arc<NvisyClientInner>,
+    /// Default options applied to every call made by this handle.
+    pub(crate) options: Arc<RequestOptions>,
+    /// Whether the idempotency key from the options has been sent.
+    idempotency_key_used: Arc<AtomicBool>,
 }
 
 /// Inner client state that is shared via Arc for cheap cloning.
@@ -90,6 +115,26 @@ pub struct NvisyClient {
 pub(crate) struct NvisyClientInner {
     pub(crate) config: NvisyConfig,
     pub(crate) client: Client,
+    /// Provider of the credentials sent with each request.
+    pub(crate) auth: Arc<dyn AuthProvider>,
+    /// Parsed base URL of the web console.
+    pub(crate) console_url: url::Url,
+    /// Most recent health status and the time it was fetched.
+    pub(crate) health_cache: Mutex<Option<(Instant, MonitorStatus)>>,
+    /// Server capabilities, once fetched.
+    pub(crate) capabilities: Mutex<Option<Capabilities>>,
+    /// Usage counters reported through the telemetry sink.
+    pub(crate) telemetry: TelemetryState,
+}
+
+impl Drop for NvisyClientInner {
+    fn drop(&mut self) {
+        if let Some(telemetry) = self.config.telemetry()
+            && let Some(report) = self.telemetry.take()
+        {
+            telemetry.emit(&report);
+        }
+    }
 }
 
 impl NvisyClient {
@@ -102,7 +147,34 @@ impl NvisyClient {
         let client = if let Some(custom_client) = config.client() {
             custom_client
         } else {
-            Client::builder().timeout(config.timeout()).build()?
+            // Redirects are followed by the client itself so that the
+            // configured redirect policy applies uniformly.
+            #[cfg(not(target_arch = "wasm32"))]
+            let builder = Client::builder()
+                .timeout(config.timeout())
+                .redirect(Policy::none());
+            #[cfg(not(target_arch = "wasm32"))]
+            let builder = config.dns().apply(builder);
+            #[cfg(not(target_arch = "wasm32"))]
+            let builder = match config.proxy() {
+                Some(proxy) => builder.proxy(reqwest::Proxy::all(proxy)?),
+                None => builder,
+            };
+            #[cfg(not(target_arch = "wasm32"))]
+            let builder = match config.connect_timeout() {
+                Some(timeout) => builder.connect_timeout(timeout),
+                None => builder,
+            };
+            #[cfg(not(target_arch = "wasm32"))]
+            let builder = match config.read_timeout() {
+                Some(timeout) => builder.read_timeout(timeout),
+                None => builder,
+            };
+            // Browsers follow redirects themselves, and the timeout is
+            // applied to each request instead.
+            #[cfg(target_arch = "wasm32")]
+            let builder = Client::builder();
+            builder.build()?
         };
 
         #[cfg(feature = "tracing")]
@@ -115,8 +187,22 @@ impl NvisyClient {
             "Nvisy client created successfully"
         );
 
-        let inner = Arc::new(NvisyClientInner { config, client });
-        Ok(Self { inner })
+        let console_url = url::Url::parse(config.console_url())?;
+        let auth = config.auth_provider();
+        let inner = Arc::new(NvisyClientInner {
+            config,
+            client,
+            auth,
+            console_url,
+            health_cache: Mutex::new(None),
+            capabilities: Mutex::new(None),
+            telemetry: TelemetryState::new(),
+        });
+        Ok(Self {
+            inner,
+            options: Arc::default(),
+            idempotency_key_used: Arc::default(),
+        })
     }
 
     /// Creates a new client with just an API key using default settings.
@@ -135,6 +221,23 @@ impl NvisyClient {
         Self::new(config)
     }
 
+    /// Creates a new client configured from environment variables.
+    ///
+    /// See [`NvisyConfig::from_env`] for the variables read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use nvisy_sdk::{NvisyClient, Result};
+    /// # fn example() -> Result<()> {
+    /// let client = NvisyClient::from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<Self> {
+        Self::new(NvisyConfig::from_env()?)
+    }
+
     /// Creates a new configuration builder for constructing a Nvisy client.
     ///
     /// This is a convenience method that returns a `NvisyConfigBuilder` for building
@@ -162,6 +265,90 @@ impl NvisyClient {
         &self.inner.config
     }
 
+    /// Creates a client applying the given options to every call.
+    ///
+    /// The returned client shares connections and caches with this one.
+    /// Options already applied to this client are kept unless overridden.
+    pub fn with_options(&self, options: RequestOptions) -> ScopedClient {
+        // A new key starts a new operation; otherwise the inherited key
+        // stays bound to the operation it was already sent with.
+        let idempotency_key_used = match options.idempotency_key() {
+            Some(_) => Arc::default(),
+            None => self.idempotency_key_used.clone(),
+        };
+        ScopedClient::new(Self {
+            inner: self.inner.clone(),
+            options: Arc::new(self.options.merge(options)),
+            idempotency_key_used,
+        })
+    }
+
+    /// Returns the default options applied to every call.
+    pub(crate) fn options(&self) -> &RequestOptions {
+        &self.options
+    }
+
+    /// Returns the request timeout, taking default options into account.
+    pub(crate) fn timeout(&self) -> Duration {
+        self.options
+            .timeout()
+            .unwrap_or_else(|| self.inner.config.timeout())
+    }
+
+    /// Returns the retry policy, taking default options into account.
+    pub(crate) fn retry_policy(&self) -> &RetryPolicy {
+        self.options
+            .retry_policy()
+            .unwrap_or_else(|| self.inner.config.retry_policy())
+    }
+
+    /// Opens connections to the API ahead of a burst of requests.
+    ///
+    /// Sends `connections` concurrent `HEAD` requests to the base URL, so
+    /// that the connection pool holds that many established connections
+    /// with their TLS handshakes done. Any response counts as success.
+    /// Servers speaking HTTP/2 multiplex requests over a single connection,
+    /// so only one is opened. Idle connections are eventually closed by the
+    /// pool, so call this shortly before the burst.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use nvisy_sdk::{NvisyClient, Result};
+    /// # async fn example() -> Result<()> {
+    /// let client = NvisyClient::with_api_key("your-api-key")?;
+    /// client.warm_up(8).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn warm_up(&self, connections: usize) -> Result<()> {
+        let url = url::Url::parse(self.inner.config.base_url())?;
+        let requests = (0..connections).map(|_| {
+            let request = self.inner.client.head(url.clone()).timeout(self.timeout());
+            async move {
+                let started = Instant::now();
+                request
+                    .send()
+                    .await
+                    .map_err(|err| self.map_transport_error(err, started.elapsed()))
+            }
+        });
+        future::try_join_all(requests).await?;
+        Ok(())
+    }
+
+    /// Emits a telemetry report for the requests made since the last one.
+    ///
+    /// Returns the report, or `None` if telemetry is disabled or no requests
+    /// were made. Reports are otherwise emitted periodically and when the
+    /// client is dropped.
+    pub fn flush_telemetry(&self) -> Option<TelemetryReport> {
+        let telemetry = self.inner.config.telemetry()?;
+        let report = self.inner.telemetry.take()?;
+        telemetry.emit(&report);
+        Some(report)
+    }
+
     /// Parses the base URL and appends the given path.
     fn parse_url(&self, path: &str) -> Result<url::Url> {
         let mut url = url::Url::parse(self.inner.config.base_url())?;
@@ -169,15 +356,15 @@ impl NvisyClient {
         Ok(url)
     }
 
-    /// Builds a URL with the given path and optional query parameters.
-    fn build_url(&self, path: &str, params: &[(&str, &str)]) -> Result<url::Url> {
-        let mut url = self.parse_url(path)?;
-
-        if !params.is_empty() {
-            url.query_pairs_mut().extend_pairs(params);
-        }
+    /// Returns `true` if the URL has the same origin as the configured base URL.
+    fn is_base_origin(&self, url: &url::Url) -> bool {
+        url::Url::parse(self.inner.config.base_url())
+            .is_ok_and(|base| base.origin() == url.origin())
+    }
 
-        Ok(url)
+    /// Returns `true` if a request to `url` may carry the API credentials.
+    fn keeps_auth(&self, url: &url::Url) -> bool {
+        !self.inner.config.strip_auth_on_redirect() || self.is_base_origin(url)
     }
 
     /// Creates an HTTP request with the specified method.
@@ -190,22 +377,385 @@ impl NvisyClient {
             "Creating HTTP request"
         );
 
-        self.inner
+        let mut request = self
+            .inner
             .client
             .request(method, url)
-            .timeout(self.inner.config.timeout())
-            .header(
-                "Authorization",
-                format!("Bearer {}", self.inner.config.api_key()),
-            )
+            .timeout(self.timeout());
+        for (name, value) in self.options.headers() {
+            request = request.header(name, value);
+        }
+        if let Some(account_id) = self.options.impersonation() {
+            request = request.header(IMPERSONATE_HEADER, account_id.to_string());
+        }
+        request
+    }
+
+    /// Executes a prepared request and returns the response.
+    ///
+    /// All requests go through this method so that transport errors,
+    /// redirects, and error responses are handled consistently. Error
+    /// responses are returned as [`Error::ApiResponse`].
+    pub(crate) async fn execute(&self, request: RequestBuilder) -> Result<Response> {
+        self.execute_with(request, None).await
+    }
+
+    /// Executes a request, following redirects according to the configured policy.
+    ///
+    /// Requests with buffered bodies are cloned when a redirect requires the
+    /// body to be resent. Streaming bodies cannot be cloned, so `rebuild` is
+    /// used to produce a fresh request for the redirect target instead; if
+    /// neither is possible the redirect response is returned as-is.
+    async fn execute_with(
+        &self,
+        request: RequestBuilder,
+        rebuild: Option<&(dyn Fn(url::Url) -> RequestBuilder + Sync)>,
+    ) -> Result<Response> {
+        let request = request.build()?;
+        if self.inner.config.read_only() && !self.is_read(&request) {
+            return Err(Error::ReadOnlyMode {
+                method: request.method().clone(),
+                path: request.url().path().to_string(),
+            });
+        }
+
+        // Credentials are added here rather than when the request is
+        // created, because providers may need to fetch or refresh a token.
+        let authorization = self.inner.auth.authorization(&self.inner.client).await?;
+        let mut request = RequestBuilder::from_parts(self.inner.client.clone(), request)
+            .header(AUTHORIZATION, &authorization)
+            .build()?;
+
+        // The key is chosen once so that retries and rebuilt requests share it.
+        let idempotency_key = self.idempotency_key(&request);
+        if let Some(key) = &idempotency_key {
+            request = RequestBuilder::from_parts(self.inner.client.clone(), request)
+                .header(IDEMPOTENCY_KEY_HEADER, key)
+                .build()?;
+        }
+        // Rebuilt requests may target a redirect destination, including
+        // retries of a redirected hop, so credentials are only re-added
+        // where a redirected request would keep them.
+        let reauthorize = rebuild.map(|rebuild| {
+            move |url: url::Url| {
+                let keep_auth = self.keeps_auth(&url);
+                let request = rebuild(url);
+                let request = if keep_auth {
+                    request.header(AUTHORIZATION, &authorization)
+                } else {
+                    request
+                };
+                match &idempotency_key {
+                    Some(key) => request.header(IDEMPOTENCY_KEY_HEADER, key),
+                    None => request,
+                }
+            }
+        });
+        let rebuild = reauthorize
+            .as_ref()
+            .map(|rebuild| rebuild as &(dyn Fn(url::Url) -> RequestBuilder + Sync));
+
+        #[cfg(feature = "tracing")]
+        let span = request_span(&request);
+        #[cfg(feature = "tracing")]
+        let started = Instant::now();
+
+        let result = self.follow_redirects(request, rebuild);
+        #[cfg(feature = "tracing")]
+        let result = tracing::Instrument::instrument(result, span.clone());
+        let result = result.await;
+
+        #[cfg(feature = "tracing")]
+        record_outcome(&span, &result, started.elapsed());
+        result
+    }
+
+    /// Returns `true` if the request does not modify data.
+    ///
+    /// Besides safe methods, this covers the `POST` endpoints that only read.
+    fn is_read(&self, request: &Request) -> bool {
+        is_safe(request.method())
+            || (request.method() == Method::POST
+                && READ_ONLY_POSTS
+                    .iter()
+                    .any(|path| self.parse_url(path).is_ok_and(|url| &url == request.url())))
+    }
+
+    /// Returns the idempotency key to add to a request, if it needs one.
+    ///
+    /// `POST`, `PATCH`, and `DELETE` requests get a freshly generated key
+    /// unless the header is already set. The key from the call options is
+    /// used instead for the first of them only, so that it identifies a
+    /// single operation.
+    fn idempotency_key(&self, request: &Request) -> Option<String> {
+        let mutating = [Method::POST, Method::PATCH, Method::DELETE].contains(request.method());
+        if !mutating || request.headers().contains_key(IDEMPOTENCY_KEY_HEADER) {
+            return None;
+        }
+
+        let key = match self.options.idempotency_key() {
+            Some(key) if !self.idempotency_key_used.swap(true, Ordering::Relaxed) => key.to_owned(),
+            _ => uuid::Uuid::new_v4().to_string(),
+        };
+        Some(key)
+    }
+
+    /// Sends a request, following redirects according to the configured
+    /// policy.
+    async fn follow_redirects(
+        &self,
+        mut request: Request,
+        rebuild: Option<&(dyn Fn(url::Url) -> RequestBuilder + Sync)>,
+    ) -> Result<Response> {
+        let mut visited = Vec::new();
+
+        loop {
+            let replay = request.try_clone();
+            let response = self.dispatch_with_retries(request, rebuild).await?;
+
+            let status = response.status();
+            if status.is_client_error() || status.is_server_error() {
+                return Err(self.api_error(response).await);
+            }
+            if !redirect::is_redirect(status) {
+                return Ok(response);
+            }
+
+            let current = response.url().clone();
+            let Some(next) = response
+                .headers()
+                .get(LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .and_then(|location| current.join(location).ok())
+            else {
+                return Ok(response);
+            };
+
+            visited.push(current.clone());
+            let attempt = RedirectAttempt::new(status, &next, &visited);
+            if !self.inner.config.redirect_policy().allows(&attempt) {
+                return Ok(response);
+            }
+
+            let mut next_request = match (replay, rebuild) {
+                (Some(replay), _) => replay,
+                (None, Some(rebuild)) => rebuild(next.clone()).build()?,
+                (None, None) => return Ok(response),
+            };
+
+            // 301/302/303 redirects are followed with a body-less GET,
+            // except for HEAD requests which stay HEAD.
+            if !redirect::preserves_body(status) && next_request.method() != Method::HEAD {
+                *next_request.method_mut() = Method::GET;
+                *next_request.body_mut() = None;
+                next_request.headers_mut().remove(CONTENT_TYPE);
+                next_request.headers_mut().remove(CONTENT_LENGTH);
+            }
+
+            // Do not forward credentials to hosts other than the API.
+            if !self.keeps_auth(&next) {
+                next_request.headers_mut().remove(AUTHORIZATION);
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                target: TRACING_TARGET_CLIENT,
+                from = %current,
+                to = %next,
+                status = %status,
+                "Following redirect"
+            );
+
+            *next_request.url_mut() = next;
+            request = next_request;
+        }
+    }
+
+    /// Sends a request, retrying transient failures according to the retry
+    /// policy.
+    ///
+    /// Requests that cannot be cloned are rebuilt with `rebuild` for each
+    /// retry; if neither is possible the request is sent only once.
+    async fn dispatch_with_retries(
+        &self,
+        mut request: Request,
+        rebuild: Option<&(dyn Fn(url::Url) -> RequestBuilder + Sync)>,
+    ) -> Result<Response> {
+        let policy = self.retry_policy();
+        let mut attempt = 1;
+
+        loop {
+            let retry = if policy.allows_retry(&request, attempt) {
+                match (request.try_clone(), rebuild) {
+                    (Some(retry), _) => Some(retry),
+                    (None, Some(rebuild)) => Some(rebuild(request.url().clone()).build()?),
+                    (None, None) => None,
+                }
+            } else {
+                None
+            };
+
+            let result = self.dispatch(request).await;
+            let retryable = result
+                .as_ref()
+                .map_or(true, |response| policy.retries_status(response.status()));
+
+            let err = match result {
+                Ok(response) if response.status() == StatusCode::TOO_MANY_REQUESTS => {
+                    rate_limit::rate_limited(response.headers())
+                }
+                Ok(response) if retry.is_none() || !retryable => return Ok(response),
+                Ok(response) => match response.error_for_status() {
+                    Ok(response) => return Ok(response),
+                    Err(err) => Error::Http(err),
+                },
+                Err(err) => err,
+            };
+            let (Some(retry), true) = (retry, retryable) else {
+                return Err(err);
+            };
+
+            // Wait as long as the server asks, unless that is longer than
+            // the policy is willing to wait.
+            let delay = match &err {
+                Error::RateLimited {
+                    retry_after: Some(retry_after),
+                    ..
+                } => *retry_after,
+                _ => policy.backoff(attempt),
+            };
+            if delay > policy.max_backoff() {
+                return Err(err);
+            }
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                target: TRACING_TARGET_CLIENT,
+                attempt,
+                error = %err,
+                "Retrying request"
+            );
+
+            #[cfg(feature = "tracing")]
+            tracing::Span::current().record("retries", attempt);
+
+            self.notify_retry(attempt, &err);
+            runtime::sleep(delay).await;
+            attempt += 1;
+            request = retry;
+        }
+    }
+
+    /// Sends a single request without following redirects.
+    async fn dispatch(&self, mut request: Request) -> Result<Response> {
+        let config = &self.inner.config;
+        let hooks = config.hooks();
+        for interceptor in config.interceptors() {
+            interceptor.on_request(&mut request)?;
+        }
+        let method = request.method().clone();
+        let url = request.url().clone();
+        config
+            .rate_budgets()
+            .acquire(self.options.workspace(), &url)
+            .await;
+        hooks.request(&RequestInfo::new(&method, &url));
+
+        let started = Instant::now();
+        match self.inner.client.execute(request).await {
+            Ok(response) => {
+                let elapsed = started.elapsed();
+                for interceptor in config.interceptors() {
+                    interceptor.on_response(&response);
+                }
+                let status = response.status();
+                hooks.response(&ResponseInfo::new(&method, &url, status, elapsed));
+                #[cfg(feature = "metrics")]
+                metrics::record_response(&method, &url, status, elapsed);
+                let failed = status.is_client_error() || status.is_server_error();
+                self.record_telemetry(failed.then(|| ErrorKind::from_status(status)));
+                Ok(response)
+            }
+            Err(err) => {
+                let elapsed = started.elapsed();
+                let err = self.map_transport_error(err, elapsed);
+                hooks.error(&ErrorInfo::new(&method, &url, &err, elapsed));
+                #[cfg(feature = "metrics")]
+                metrics::record_failure(&method, &url, err.kind(), elapsed);
+                self.record_telemetry(Some(err.kind()));
+                Err(err)
+            }
+        }
+    }
+
+    /// Reads an error response into an [`Error::ApiResponse`].
+    async fn api_error(&self, response: Response) -> Error {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = self.read_bytes(response).await.unwrap_or_default();
+        Error::ApiResponse(ApiErrorBody::from_response(status, &headers, &body))
+    }
+
+    /// Counts a completed request, emitting a report if one is due.
+    fn record_telemetry(&self, error: Option<ErrorKind>) {
+        let Some(telemetry) = self.inner.config.telemetry() else {
+            return;
+        };
+        if let Some(report) = self.inner.telemetry.record(error, telemetry.interval()) {
+            telemetry.emit(&report);
+        }
+    }
+
+    /// Notifies the retry hook that an operation is about to be retried.
+    pub(crate) fn notify_retry(&self, attempt: u32, error: &Error) {
+        self.inner
+            .config
+            .hooks()
+            .retry(&RetryInfo::new(attempt, error));
+    }
+
+    /// Maps a transport error, distinguishing connect and read timeouts.
+    fn map_transport_error(&self, err: reqwest::Error, elapsed: Duration) -> Error {
+        if !err.is_timeout() {
+            return Error::Http(err);
+        }
+
+        // Browsers do not report which phase of a request timed out.
+        #[cfg(not(target_arch = "wasm32"))]
+        let phase = if err.is_connect() {
+            TimeoutPhase::Connect
+        } else {
+            TimeoutPhase::Read
+        };
+        #[cfg(target_arch = "wasm32")]
+        let phase = TimeoutPhase::Read;
+
+        let limit = match (phase, self.inner.config.connect_timeout()) {
+            (TimeoutPhase::Connect, Some(connect_timeout)) => connect_timeout.min(self.timeout()),
+            _ => self.timeout(),
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            target: TRACING_TARGET_CLIENT,
+            phase = %phase,
+            elapsed = ?elapsed,
+            limit = ?limit,
+            "Request timed out"
+        );
+
+        Error::Timeout {
+            phase,
+            elapsed,
+            limit,
+        }
     }
 
     /// Sends a request and returns the response.
     #[allow(dead_code)]
     pub(crate) async fn send(&self, method: Method, path: &str) -> Result<Response> {
         let url = self.parse_url(path)?;
-        let response = self.request(method, url).send().await?;
-        Ok(response)
+        self.execute(self.request(method, url)).await
     }
 
     /// Sends a request with JSON body.
@@ -217,43 +767,151 @@ impl NvisyClient {
         data: &T,
     ) -> Result<Response> {
         let url = self.parse_url(path)?;
-        let response = self.request(method, url).json(data).send().await?;
-        Ok(response)
-    }
-
-    /// Sends a request with query parameters.
-    #[allow(dead_code)]
-    pub(crate) async fn send_with_params(
-        &self,
-        method: Method,
-        path: &str,
-        params: &[(&str, &str)],
-    ) -> Result<Response> {
-        let url = self.build_url(path, params)?;
-        let response = self.request(method, url).send().await?;
-        Ok(response)
+        self.execute(self.request(method, url).json(data)).await
     }
 
     /// Sends a request with multipart form data.
+    ///
+    /// Multipart bodies are streamed and consumed on send, so the form is
+    /// produced by a factory that is invoked once per attempt. This allows
+    /// the body to be rebuilt when the request has to be replayed.
     #[allow(dead_code)]
-    pub(crate) async fn send_multipart(
+    pub(crate) async fn send_multipart<F>(
         &self,
         method: Method,
         path: &str,
-        form: Form,
-    ) -> Result<Response> {
+        form: F,
+    ) -> Result<Response>
+    where
+        F: Fn() -> Form + Sync,
+    {
         let url = self.parse_url(path)?;
-        let response = self.request(method, url).multipart(form).send().await?;
-        Ok(response)
+        self.execute_replayable(url, |url| {
+            self.request(method.clone(), url).multipart(form())
+        })
+        .await
+    }
+
+    /// Executes a request whose body is rebuilt for every attempt.
+    ///
+    /// Use this for streaming bodies, which are consumed on send and cannot
+    /// be cloned. `build` is invoked with the target URL whenever the request
+    /// has to be resent, such as for a `307`/`308` redirect.
+    pub(crate) async fn execute_replayable<F>(&self, url: url::Url, build: F) -> Result<Response>
+    where
+        F: Fn(url::Url) -> RequestBuilder + Sync,
+    {
+        self.execute_with(build(url), Some(&build)).await
     }
 
     /// Creates a request builder for custom query parameter building.
-    /// Use this for complex query scenarios that need conditional parameters.
+    /// Use this for complex query scenarios that need conditional parameters,
+    /// then send it with [`execute`](Self::execute).
     #[allow(dead_code)]
     pub(crate) fn request_builder(&self, method: Method, path: &str) -> Result<RequestBuilder> {
         let url = self.parse_url(path)?;
         Ok(self.request(method, url))
     }
+
+    /// Reads the response body, enforcing the configured maximum size.
+    pub(crate) async fn read_bytes(&self, response: Response) -> Result<Bytes> {
+        self.read_body(response, None).await
+    }
+
+    /// Reads a file content body, enforcing the configured maximum size and
+    /// reporting progress to the configured
+    /// [`TransferProgress`](super::TransferProgress).
+    pub(crate) async fn read_content(&self, response: Response) -> Result<Bytes> {
+        self.read_body(response, self.options().progress()).await
+    }
+
+    async fn read_body(
+        &self,
+        response: Response,
+        progress: Option<&TransferProgress>,
+    ) -> Result<Bytes> {
+        let limit = self.inner.config.max_response_size();
+        if limit.is_none() && progress.is_none() {
+            return Ok(response.bytes().await?);
+        }
+        let total = response.content_length();
+        if let Some(limit) = limit
+            && total.is_some_and(|len| len > limit)
+        {
+            return Err(Error::ResponseTooLarge { limit });
+        }
+
+        let mut body = BytesMut::new();
+        let mut chunks = response.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            self.check_response_size((body.len() + chunk.len()) as u64)?;
+            body.extend_from_slice(&chunk);
+            if let Some(progress) = progress {
+                progress.report(body.len() as u64, total);
+            }
+        }
+        Ok(body.freeze())
+    }
+
+    /// Reads and deserializes a JSON response body, enforcing the configured
+    /// maximum size.
+    ///
+    /// Bodies tagged with another schema version are rewritten by the
+    /// configured [`SchemaMigrations`](super::SchemaMigrations) first.
+    pub(crate) async fn read_json<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
+        let migrations = self.inner.config.schema_migrations();
+        let version = schema::response_version(response.headers())
+            .filter(|version| migrations.applies_to(*version));
+        let Some(version) = version else {
+            let body = self.read_bytes(response).await?;
+            return Ok(serde_json::from_slice(&body)?);
+        };
+
+        let path = response.url().path().to_string();
+        let body = self.read_bytes(response).await?;
+        let mut value: serde_json::Value = serde_json::from_slice(&body)?;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: TRACING_TARGET_CLIENT,
+            version,
+            path = %path,
+            "Migrating response schema"
+        );
+
+        migrations.migrate(version, &path, &mut value);
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Returns an error if `received` bytes exceed the maximum response size.
+    pub(crate) fn check_response_size(&self, received: u64) -> Result<()> {
+        match self.inner.config.max_response_size() {
+            Some(limit) if received > limit => Err(Error::ResponseTooLarge { limit }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an error if an upload of `size` bytes exceeds the configured
+    /// or server-advertised maximum upload size.
+    pub(crate) fn check_upload_size(&self, size: u64) -> Result<()> {
+        let advertised = self
+            .inner
+            .capabilities
+            .lock()
+            .unwrap()
+            .as_ref()
+            .and_then(|capabilities| u64::try_from(capabilities.max_upload_size).ok());
+        let limit = match (self.inner.config.max_upload_size(), advertised) {
+            (Some(configured), Some(advertised)) => Some(configured.min(advertised)),
+            (configured, advertised) => configured.or(advertised),
+        };
+
+        match limit {
+            Some(limit) if size > limit => Err(Error::UploadTooLarge { size, limit }),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl fmt::Debug for NvisyClient {
@@ -261,11 +919,69 @@ impl fmt::Debug for NvisyClient {
         f.debug_struct("NvisyClient")
             .field("api_key", &self.inner.config.masked_api_key())
             .field("base_url", &self.inner.config.base_url())
-            .field("timeout", &self.inner.config.timeout())
+            .field("timeout", &self.timeout())
+            .field("options", &self.options)
             .finish()
     }
 }
 
+/// Paths of `POST` endpoints that only read, and so are allowed in
+/// read-only mode.
+const READ_ONLY_POSTS: &[&str] = &["/health/"];
+
+/// Returns `true` if requests with the method do not modify data.
+fn is_safe(method: &Method) -> bool {
+    [Method::GET, Method::HEAD, Method::OPTIONS].contains(method)
+}
+
+/// Creates the span covering a request, including its retries and
+/// redirects.
+#[cfg(feature = "tracing")]
+fn request_span(request: &Request) -> tracing::Span {
+    use tracing::field::Empty;
+
+    tracing::info_span!(
+        target: TRACING_TARGET_CLIENT,
+        "request",
+        method = %request.method(),
+        path = request.url().path(),
+        status = Empty,
+        latency_ms = Empty,
+        retries = 0,
+        request_id = Empty,
+    )
+}
+
+/// Records the outcome of a request on its span.
+#[cfg(feature = "tracing")]
+fn record_outcome(span: &tracing::Span, result: &Result<Response>, latency: Duration) {
+    span.record("latency_ms", latency.as_millis() as u64);
+    let (status, request_id) = match result {
+        Ok(response) => (
+            Some(response.status()),
+            response
+                .headers()
+                .get(crate::error::REQUEST_ID_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+        ),
+        Err(Error::ApiResponse(body)) => (Some(body.status()), body.request_id.clone()),
+        Err(Error::Http(err)) => (err.status(), None),
+        Err(_) => (None, None),
+    };
+    if let Some(status) = status {
+        span.record("status", status.as_u16());
+    }
+    if let Some(request_id) = &request_id {
+        span.record("request_id", request_id.as_str());
+    }
+
+    span.in_scope(|| match result {
+        Ok(_) => tracing::debug!(target: TRACING_TARGET_CLIENT, "Request completed"),
+        Err(err) => tracing::debug!(target: TRACING_TARGET_CLIENT, error = %err, "Request failed"),
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use std::time::Duration;
@@ -319,6 +1035,334 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_timeout_is_mapped() -> Result<()> {
+        // The listener accepts connections at the OS level but never responds.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .with_timeout(Duration::from_millis(200))
+            .build_client()?;
+
+        let err = client.send(Method::GET, "/health/").await.unwrap_err();
+        match err {
+            Error::Timeout { phase, limit, .. } => {
+                assert_eq!(phase, TimeoutPhase::Read);
+                assert_eq!(limit, Duration::from_millis(200));
+            }
+            other => panic!("expected timeout error, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hooks_observe_each_hop() -> Result<()> {
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(302, &[("Location", "/moved")], ""),
+            mock::response(404, &[], ""),
+        ]);
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let (on_request, on_response) = (events.clone(), events.clone());
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .on_request(move |info| {
+                let event = format!("request {}", info.url().path());
+                on_request.lock().unwrap().push(event);
+            })
+            .on_response(move |info| {
+                let event = format!("response {}", info.status().as_u16());
+                on_response.lock().unwrap().push(event);
+            })
+            .build_client()?;
+
+        let err = client.send(Method::GET, "/start").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        server.join().unwrap();
+
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                "request /start",
+                "response 302",
+                "request /moved",
+                "response 404"
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_scoped_client_applies_options() -> Result<()> {
+        use uuid::Uuid;
+
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], ""),
+            mock::response(200, &[], ""),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .build_client()?;
+        let account_id = Uuid::from_u128(7);
+        let scoped = client.with_options(
+            RequestOptions::new()
+                .with_header("X-Request-Source", "reporting")
+                .with_impersonation(account_id),
+        );
+
+        scoped.send(Method::GET, "/scoped").await?;
+        client.send(Method::GET, "/shared").await?;
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].header("x-request-source"), Some("reporting"));
+        assert_eq!(
+            requests[0].header(IMPERSONATE_HEADER),
+            Some(account_id.to_string().as_str())
+        );
+        assert!(requests[1].header("x-request-source").is_none());
+        assert!(requests[1].header(IMPERSONATE_HEADER).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scoped_timeout_overrides_client_timeout() -> Result<()> {
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_timeout(Duration::from_secs(30))
+            .build_client()?;
+        let archive =
+            client.with_options(RequestOptions::new().with_timeout(Duration::from_secs(600)));
+
+        let request = archive
+            .request(Method::GET, archive.parse_url("/exports/archive")?)
+            .build()?;
+        assert_eq!(request.timeout(), Some(&Duration::from_secs(600)));
+        assert_eq!(client.timeout(), Duration::from_secs(30));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_multipart_replayed_on_redirect() -> Result<()> {
+        use reqwest::StatusCode;
+        use reqwest::multipart::Part;
+
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(307, &[("Location", "/uploads/retry")], ""),
+            mock::response(200, &[], "ok"),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let form = || Form::new().part("file", Part::bytes(b"hello".as_slice()));
+        let response = client
+            .send_multipart(Method::POST, "/uploads", form)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].request_line(), "POST /uploads/retry HTTP/1.1");
+        assert!(String::from_utf8_lossy(&requests[1].body).contains("hello"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rebuilt_retry_to_other_origin_omits_auth() -> Result<()> {
+        use reqwest::multipart::Part;
+
+        use crate::client::mock;
+
+        let (storage_url, storage) = mock::serve(vec![
+            mock::response(503, &[], ""),
+            mock::response(200, &[], "ok"),
+        ]);
+        let location = format!("{storage_url}/uploads/put");
+        let (base_url, api) = mock::serve(vec![mock::response(
+            307,
+            &[("Location", location.as_str())],
+            "",
+        )]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .with_retry_policy(RetryPolicy::default().with_base_backoff(Duration::ZERO))
+            .build_client()?;
+
+        let form = || Form::new().part("file", Part::bytes(b"hello".as_slice()));
+        client
+            .send_multipart(Method::POST, "/uploads", form)
+            .await?;
+
+        let api = api.join().unwrap();
+        assert!(api[0].header("authorization").is_some());
+        let storage = storage.join().unwrap();
+        assert_eq!(storage.len(), 2);
+        assert!(storage[0].header("authorization").is_none());
+        assert!(storage[1].header("authorization").is_none());
+        assert!(storage[1].header(IDEMPOTENCY_KEY_HEADER).is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_see_other_redirect_becomes_get() -> Result<()> {
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(303, &[("Location", "/result")], ""),
+            mock::response(200, &[], "{}"),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        client
+            .send_json(Method::POST, "/jobs", &serde_json::json!({"a": 1}))
+            .await?;
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[1].request_line(), "GET /result HTTP/1.1");
+        assert!(requests[1].body.is_empty());
+        assert!(requests[1].header("authorization").is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cross_origin_redirect_drops_authorization() -> Result<()> {
+        use crate::client::mock;
+
+        let (storage_url, storage) = mock::serve(vec![mock::response(200, &[], "data")]);
+        let location = format!("{storage_url}/blob");
+        let (base_url, api) = mock::serve(vec![mock::response(
+            302,
+            &[("Location", location.as_str())],
+            "",
+        )]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let response = client.send(Method::GET, "/files/1/content").await?;
+        assert_eq!(response.text().await?, "data");
+
+        api.join().unwrap();
+        let requests = storage.join().unwrap();
+        assert!(requests[0].header("authorization").is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_cross_origin_redirect_keeps_authorization_when_disabled() -> Result<()> {
+        use crate::client::mock;
+
+        let (storage_url, storage) = mock::serve(vec![mock::response(200, &[], "data")]);
+        let location = format!("{storage_url}/blob");
+        let (base_url, api) = mock::serve(vec![mock::response(
+            302,
+            &[("Location", location.as_str())],
+            "",
+        )]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .with_strip_auth_on_redirect(false)
+            .build_client()?;
+
+        client.send(Method::GET, "/files/1/content").await?;
+
+        api.join().unwrap();
+        let requests = storage.join().unwrap();
+        assert_eq!(requests[0].header("authorization"), Some("Bearer test_key"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_redirect_policy_none_returns_redirect() -> Result<()> {
+        use crate::client::{RedirectPolicy, mock};
+
+        let (base_url, server) =
+            mock::serve(vec![mock::response(302, &[("Location", "/elsewhere")], "")]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .with_redirect_policy(RedirectPolicy::none())
+            .build_client()?;
+
+        let response = client.send(Method::GET, "/files/").await?;
+        assert_eq!(response.status().as_u16(), 302);
+        assert_eq!(server.join().unwrap().len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_response_size_limit() -> Result<()> {
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], "0123456789"),
+            mock::response(200, &[], "\"0123456789\""),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .with_max_response_size(10u64)
+            .build_client()?;
+
+        let response = client.send(Method::GET, "/files/1/content").await?;
+        assert_eq!(client.read_bytes(response).await?.as_ref(), b"0123456789");
+
+        let response = client.send(Method::GET, "/files/2/content").await?;
+        let err = client.read_json::<String>(response).await.unwrap_err();
+        assert!(matches!(err, Error::ResponseTooLarge { limit: 10 }));
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[test]
+    fn test_upload_size_limit() -> Result<()> {
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_max_upload_size(100u64)
+            .build_client()?;
+        assert!(client.check_upload_size(100).is_ok());
+
+        *client.inner.capabilities.lock().unwrap() = Some(Capabilities {
+            max_upload_size: 50,
+            max_batch_size: None,
+        });
+        let err = client.check_upload_size(60).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::UploadTooLarge {
+                size: 60,
+                limit: 50
+            }
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_debug_impl_masks_api_key() -> Result<()> {
         let client = NvisyClient::with_api_key("secret_api_key_12345")?;
@@ -329,4 +1373,205 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_idempotent_requests_are_retried() -> Result<()> {
+        use reqwest::StatusCode;
+
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(503, &[], ""),
+            mock::response(200, &[], "{}"),
+            mock::response(503, &[], ""),
+            mock::response(200, &[], "{}"),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .with_retry_policy(RetryPolicy::default().with_base_backoff(Duration::ZERO))
+            .build_client()?;
+
+        let response = client.send(Method::GET, "/workspaces/").await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = client.send(Method::POST, "/workspaces/").await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 4);
+        assert!(requests[0].header(IDEMPOTENCY_KEY_HEADER).is_none());
+        let key = requests[2].header(IDEMPOTENCY_KEY_HEADER);
+        assert!(key.is_some());
+        assert_eq!(requests[3].header(IDEMPOTENCY_KEY_HEADER), key);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_can_be_overridden() -> Result<()> {
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], "{}"),
+            mock::response(200, &[], "{}"),
+            mock::response(200, &[], "{}"),
+            mock::response(200, &[], "{}"),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let options = RequestOptions::new().with_idempotency_key("ws-1");
+        let scoped = client.with_options(options.clone());
+        scoped.send(Method::GET, "/workspaces/").await?;
+        scoped.send(Method::POST, "/workspaces/").await?;
+        scoped.send(Method::DELETE, "/workspaces/").await?;
+        client
+            .with_options(options)
+            .send(Method::POST, "/workspaces/")
+            .await?;
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].header(IDEMPOTENCY_KEY_HEADER).is_none());
+        assert_eq!(requests[1].header(IDEMPOTENCY_KEY_HEADER), Some("ws-1"));
+        let key = requests[2].header(IDEMPOTENCY_KEY_HEADER);
+        assert!(key.is_some_and(|key| key != "ws-1"));
+        assert_eq!(requests[3].header(IDEMPOTENCY_KEY_HEADER), Some("ws-1"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_honors_retry_after() -> Result<()> {
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(429, &[("Retry-After", "0")], ""),
+            mock::response(
+                429,
+                &[("Retry-After", "0"), ("X-RateLimit-Remaining", "0")],
+                "",
+            ),
+            mock::response(
+                429,
+                &[("Retry-After", "0"), ("X-RateLimit-Limit", "100")],
+                "",
+            ),
+            mock::response(429, &[("Retry-After", "3600")], ""),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let err = client.send(Method::GET, "/workspaces/").await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::RateLimited {
+                limit: Some(100),
+                ..
+            }
+        ));
+
+        // A wait beyond the maximum backoff is left to the caller.
+        let err = client.send(Method::GET, "/workspaces/").await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::RateLimited {
+                retry_after: Some(after),
+                ..
+            } if after == Duration::from_secs(3600)
+        ));
+        assert_eq!(server.join().unwrap().len(), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_only_refuses_writes() -> Result<()> {
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], "{}")]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .with_read_only(true)
+            .build_client()?;
+
+        let err = client
+            .send(Method::DELETE, "/workspaces/1/")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::ReadOnlyMode { method, ref path } if method == Method::DELETE && path == "/workspaces/1/"
+        ));
+        client.send(Method::GET, "/workspaces/").await?;
+
+        assert_eq!(server.join().unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_resolve_overrides_host() -> Result<()> {
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], "")]);
+        let addr: std::net::SocketAddr = base_url.trim_start_matches("http://").parse().unwrap();
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(format!("http://api.nvisy.test:{}", addr.port()))
+            .with_resolve("api.nvisy.test", addr)
+            .build_client()?;
+
+        let response = client.send(Method::GET, "/health/").await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].request_line().starts_with("GET /health/"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_warm_up_opens_connections() -> Result<()> {
+        use crate::client::mock;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], ""),
+            mock::response(200, &[], ""),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_base_url(base_url)
+            .build_client()?;
+        client.warm_up(2).await?;
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(
+            requests
+                .iter()
+                .all(|r| r.request_line().starts_with("HEAD / "))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_only_refuses_before_authorizing() -> Result<()> {
+        use crate::client::OAuth2ClientCredentials;
+        use crate::client::mock;
+
+        // The token endpoint accepts no connections, so fetching a token
+        // would fail with a different error.
+        let (token_url, token_server) = mock::serve(vec![]);
+        token_server.join().unwrap();
+        let auth = OAuth2ClientCredentials::new(format!("{token_url}/oauth/token"), "id", "secret");
+        let client = NvisyConfig::builder()
+            .with_base_url(token_url)
+            .with_auth(auth)
+            .with_read_only(true)
+            .build_client()?;
+
+        let err = client.send(Method::POST, "/workspaces/").await.unwrap_err();
+        assert!(matches!(err, Error::ReadOnlyMode { .. }));
+        Ok(())
+    }
 }
@@ -0,0 +1,179 @@
+//! Named configuration profiles loaded from a TOML file.
+//!
+//! The file holds one table per profile:
+//!
+//! ```toml
+//! [staging]
+//! api_key = "nvisy_test_..."
+//! base_url = "https://staging.api.nvisy.com"
+//!
+//! [prod]
+//! api_key = "nvisy_live_..."
+//! timeout_secs = 120
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use super::config::{NvisyConfig, NvisyConfigBuilder, NvisyConfigBuilderError};
+use crate::error::{Error, Result};
+
+/// Variable overriding the location of the profiles file.
+const CONFIG_FILE_VAR: &str = "NVISY_CONFIG_FILE";
+
+/// Settings of a single profile.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Profile {
+    api_key: Option<String>,
+    base_url: Option<String>,
+    console_url: Option<String>,
+    timeout_secs: Option<u64>,
+    proxy: Option<String>,
+}
+
+impl NvisyConfigBuilder {
+    /// Applies the settings of a named profile from the default profiles
+    /// file.
+    ///
+    /// The file is read from `NVISY_CONFIG_FILE` if set, and from
+    /// `~/.nvisy/config.toml` otherwise. Settings made after this call take
+    /// precedence.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file cannot be read, and
+    /// [`Error::Config`] if it is malformed or has no such profile.
+    pub fn with_profile(self, name: &str) -> Result<Self> {
+        let path = default_path().ok_or_else(|| {
+            invalid("Cannot locate the profiles file: home directory is unknown".to_string())
+        })?;
+        self.with_profile_from(path, name)
+    }
+
+    /// Applies the settings of a named profile from the given file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file cannot be read, and
+    /// [`Error::Config`] if it is malformed or has no such profile.
+    pub fn with_profile_from(self, path: impl AsRef<Path>, name: &str) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)?;
+        self.with_profile_str(&contents, name)
+            .map_err(|err| match err {
+                Error::Config(NvisyConfigBuilderError::ValidationError(message)) => {
+                    invalid(format!("{}: {message}", path.display()))
+                }
+                err => err,
+            })
+    }
+
+    /// Applies the settings of a named profile from TOML source.
+    fn with_profile_str(mut self, contents: &str, name: &str) -> Result<Self> {
+        let mut profiles: BTreeMap<String, Profile> =
+            toml::from_str(contents).map_err(|err| invalid(err.message().to_string()))?;
+        let profile = profiles
+            .remove(name)
+            .ok_or_else(|| invalid(format!("Profile {name} not found")))?;
+
+        if let Some(api_key) = profile.api_key {
+            self = self.with_api_key(api_key);
+        }
+        if let Some(base_url) = profile.base_url {
+            self = self.with_base_url(base_url);
+        }
+        if let Some(console_url) = profile.console_url {
+            self = self.with_console_url(console_url);
+        }
+        if let Some(secs) = profile.timeout_secs {
+            self = self.with_timeout(Duration::from_secs(secs));
+        }
+        if let Some(proxy) = profile.proxy {
+            self = self.with_proxy(proxy);
+        }
+        Ok(self)
+    }
+}
+
+impl NvisyConfig {
+    /// Creates a configuration from a named profile.
+    ///
+    /// See [`NvisyConfigBuilder::with_profile`] for where profiles are
+    /// read from.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use nvisy_sdk::{NvisyConfig, Result};
+    ///
+    /// # fn example() -> Result<()> {
+    /// let client = NvisyConfig::from_profile("prod")?.build_client()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_profile(name: &str) -> Result<Self> {
+        Ok(NvisyConfig::builder().with_profile(name)?.build()?)
+    }
+}
+
+/// Returns the location of the profiles file.
+fn default_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os(CONFIG_FILE_VAR).filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    std::env::home_dir().map(|home| home.join(".nvisy").join("config.toml"))
+}
+
+/// Returns a configuration error with the given message.
+fn invalid(message: String) -> Error {
+    Error::Config(NvisyConfigBuilderError::ValidationError(message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILES: &str = r#"
+        [staging]
+        api_key = "staging-key"
+        base_url = "https://staging.api.nvisy.com"
+
+        [prod]
+        api_key = "prod-key"
+        timeout_secs = 120
+    "#;
+
+    #[test]
+    fn test_profile_selects_named_table() -> Result<()> {
+        let config = NvisyConfig::builder()
+            .with_profile_str(PROFILES, "staging")?
+            .build()?;
+        assert_eq!(config.base_url(), "https://staging.api.nvisy.com");
+
+        let config = NvisyConfig::builder()
+            .with_profile_str(PROFILES, "prod")?
+            .build()?;
+        assert_eq!(config.timeout(), Duration::from_secs(120));
+        assert_eq!(config.masked_api_key(), "prod****");
+        Ok(())
+    }
+
+    #[test]
+    fn test_profile_errors_are_descriptive() {
+        let err = NvisyConfig::builder()
+            .with_profile_str(PROFILES, "dev")
+            .map(drop)
+            .unwrap_err();
+        assert!(err.to_string().contains("Profile dev not found"));
+
+        let err = NvisyConfig::builder()
+            .with_profile_str("[prod]\napi_kye = \"typo\"", "prod")
+            .map(drop)
+            .unwrap_err();
+        assert!(err.to_string().contains("api_kye"));
+    }
+}
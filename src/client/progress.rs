@@ -0,0 +1,71 @@
+//! Progress reporting for file transfers.
+
+use std::fmt;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures_util::{Stream, TryStreamExt};
+
+/// Callback receiving the bytes transferred so far and the total size, if
+/// known.
+pub type ProgressHook = dyn Fn(u64, Option<u64>) + Send + Sync;
+
+/// Callback reporting the progress of file uploads and downloads.
+///
+/// Set with [`RequestOptions::on_progress`](crate::RequestOptions::on_progress)
+/// and applied to the `upload_file*` and `download_file*` methods of
+/// [`FilesService`](crate::service::FilesService). The callback runs on the
+/// task moving the data each time a chunk is sent or received, so it should
+/// return quickly. Upload progress is not reported on `wasm32` targets,
+/// where browsers send request bodies in one piece.
+#[derive(Clone)]
+pub struct TransferProgress {
+    hook: Arc<ProgressHook>,
+}
+
+impl TransferProgress {
+    /// Creates a progress reporter from a callback.
+    pub fn new<F>(hook: F) -> Self
+    where
+        F: Fn(u64, Option<u64>) + Send + Sync + 'static,
+    {
+        Self {
+            hook: Arc::new(hook),
+        }
+    }
+
+    /// Reports the bytes transferred so far.
+    pub(crate) fn report(&self, done: u64, total: Option<u64>) {
+        (self.hook)(done, total);
+    }
+}
+
+impl fmt::Debug for TransferProgress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransferProgress").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for TransferProgress {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.hook, &other.hook)
+    }
+}
+
+/// Wraps a stream of chunks, reporting progress as they pass through.
+pub(crate) fn track<S, E>(
+    progress: Option<TransferProgress>,
+    chunks: S,
+    total: Option<u64>,
+) -> impl Stream<Item = Result<Bytes, E>>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+{
+    let mut done = 0;
+    chunks.inspect_ok(move |chunk| {
+        if let Some(progress) = &progress {
+            done += chunk.len() as u64;
+            progress.report(done, total);
+        }
+    })
+}
@@ -0,0 +1,112 @@
+//! Client-side request rate budgets for individual workspaces.
+//!
+//! A budget spaces out the requests addressed to one workspace so that no
+//! more than the configured number are sent per second. Requests are
+//! attributed to the workspace set on the call options, or else to the
+//! workspace named by their path (`/workspaces/{id}/...`).
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use url::Url;
+use uuid::Uuid;
+
+use super::runtime::{self, Instant};
+
+/// Request rate budgets keyed by workspace.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RateBudgets {
+    budgets: HashMap<Uuid, Arc<RateBudget>>,
+}
+
+impl RateBudgets {
+    /// Limits requests to a workspace to `limit` per second.
+    pub(crate) fn set(&mut self, workspace_id: Uuid, limit: u32) {
+        self.budgets
+            .insert(workspace_id, Arc::new(RateBudget::new(limit)));
+    }
+
+    /// Returns the per-second limit for a workspace, if one is set.
+    pub(crate) fn limit(&self, workspace_id: Uuid) -> Option<u32> {
+        self.budgets.get(&workspace_id).map(|budget| budget.limit)
+    }
+
+    /// Waits until a request fits its workspace's budget.
+    ///
+    /// The request is charged to `workspace`, or to the workspace named by
+    /// `url` if none is given.
+    pub(crate) async fn acquire(&self, workspace: Option<Uuid>, url: &Url) {
+        if self.budgets.is_empty() {
+            return;
+        }
+        let workspace = workspace.or_else(|| workspace_id(url));
+        let Some(budget) = workspace.and_then(|id| self.budgets.get(&id)) else {
+            return;
+        };
+        let delay = budget.reserve(Instant::now());
+        if !delay.is_zero() {
+            runtime::sleep(delay).await;
+        }
+    }
+}
+
+/// Evenly spaced request slots for one workspace.
+#[derive(Debug)]
+struct RateBudget {
+    limit: u32,
+    interval: Duration,
+    /// Earliest time the next request may be sent.
+    next: Mutex<Option<Instant>>,
+}
+
+impl RateBudget {
+    fn new(limit: u32) -> Self {
+        let limit = limit.max(1);
+        Self {
+            limit,
+            interval: Duration::from_secs(1) / limit,
+            next: Mutex::new(None),
+        }
+    }
+
+    /// Reserves the next free slot, returning how long to wait for it.
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut next = self.next.lock().unwrap_or_else(|err| err.into_inner());
+        let slot = next.map_or(now, |next| next.max(now));
+        *next = Some(slot + self.interval);
+        slot - now
+    }
+}
+
+/// Returns the workspace a request URL addresses, if any.
+fn workspace_id(url: &Url) -> Option<Uuid> {
+    let mut segments = url.path_segments()?;
+    segments.find(|segment| *segment == "workspaces")?;
+    segments.next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_id_from_path() {
+        let id = Uuid::from_u128(7);
+        let url = Url::parse(&format!("https://api.nvisy.com/v1/workspaces/{id}/files/")).unwrap();
+        assert_eq!(workspace_id(&url), Some(id));
+
+        let url = Url::parse("https://api.nvisy.com/workspaces/").unwrap();
+        assert_eq!(workspace_id(&url), None);
+    }
+
+    #[test]
+    fn test_reserve_spaces_requests() {
+        let budget = RateBudget::new(5);
+        let now = Instant::now();
+        assert_eq!(budget.reserve(now), Duration::ZERO);
+        assert_eq!(budget.reserve(now), Duration::from_millis(200));
+        assert_eq!(budget.reserve(now), Duration::from_millis(400));
+        assert_eq!(budget.reserve(now + Duration::from_secs(1)), Duration::ZERO);
+    }
+}
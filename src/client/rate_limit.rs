@@ -0,0 +1,92 @@
+//! Rate limit response handling.
+//!
+//! When the API rejects a request with `429 Too Many Requests`, the
+//! `Retry-After` and `X-RateLimit-*` headers describe when to try again and
+//! how much of the quota remains.
+
+use std::time::Duration;
+
+use jiff::Timestamp;
+use jiff::fmt::rfc2822::DateTimeParser;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+use crate::error::Error;
+
+/// Header carrying the request quota of the current window.
+const LIMIT_HEADER: &str = "X-RateLimit-Limit";
+
+/// Header carrying the requests left in the current window.
+const REMAINING_HEADER: &str = "X-RateLimit-Remaining";
+
+/// Header carrying the seconds until the current window resets.
+const RESET_HEADER: &str = "X-RateLimit-Reset";
+
+/// Builds an [`Error::RateLimited`] from the headers of a `429` response.
+pub(crate) fn rate_limited(headers: &HeaderMap) -> Error {
+    let retry_after = header_str(headers, RETRY_AFTER.as_str())
+        .and_then(parse_retry_after)
+        .or_else(|| header_u64(headers, RESET_HEADER).map(Duration::from_secs));
+
+    Error::RateLimited {
+        retry_after,
+        limit: header_u64(headers, LIMIT_HEADER),
+        remaining: header_u64(headers, REMAINING_HEADER),
+    }
+}
+
+/// Parses a `Retry-After` value given in seconds or as an HTTP date.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let at = DateTimeParser::new().parse_timestamp(value).ok()?;
+    let remaining = at.duration_since(Timestamp::now());
+    Some(Duration::try_from(remaining).unwrap_or_default())
+}
+
+/// Returns a header value as a string, if present and valid.
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name)?.to_str().ok().map(str::trim)
+}
+
+/// Returns a header value as an integer, if present and valid.
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    header_str(headers, name)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_rate_limited_from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        headers.insert(LIMIT_HEADER, HeaderValue::from_static("100"));
+        headers.insert(REMAINING_HEADER, HeaderValue::from_static("0"));
+
+        let Error::RateLimited {
+            retry_after,
+            limit,
+            remaining,
+        } = rate_limited(&headers)
+        else {
+            panic!("expected a rate limit error");
+        };
+        assert_eq!(retry_after, Some(Duration::from_secs(7)));
+        assert_eq!(limit, Some(100));
+        assert_eq!(remaining, Some(0));
+    }
+
+    #[test]
+    fn test_retry_after_http_date() {
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert!(parse_retry_after("soon").is_none());
+    }
+}
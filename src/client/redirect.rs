@@ -0,0 +1,208 @@
+//! Redirect handling for the Nvisy API client.
+//!
+//! The client follows redirects itself rather than delegating to the HTTP
+//! client, so that a single [`RedirectPolicy`] governs every request,
+//! including uploads whose bodies must be rebuilt for `307`/`308` redirects.
+
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::StatusCode;
+use url::Url;
+
+/// Default maximum number of redirects followed.
+pub const DEFAULT_MAX_REDIRECTS: usize = 10;
+
+/// Decision function used by [`RedirectPolicy::Custom`].
+pub type RedirectFn = dyn Fn(&RedirectAttempt<'_>) -> bool + Send + Sync;
+
+/// Policy controlling which redirects the client follows.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nvisy_sdk::{NvisyConfig, RedirectPolicy};
+///
+/// // Never follow redirects.
+/// let config = NvisyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_redirect_policy(RedirectPolicy::none())
+///     .build()
+///     .unwrap();
+///
+/// // Only follow redirects that stay on nvisy.com.
+/// let config = NvisyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_redirect_policy(RedirectPolicy::custom(|attempt| {
+///         attempt.url().host_str().is_some_and(|host| host.ends_with("nvisy.com"))
+///     }))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub enum RedirectPolicy {
+    /// Never follow redirects; the redirect response is returned as-is.
+    None,
+    /// Follow at most the given number of redirects.
+    Limited(usize),
+    /// Follow redirects for which the function returns `true`.
+    Custom(Arc<RedirectFn>),
+}
+
+impl RedirectPolicy {
+    /// Creates a policy that never follows redirects.
+    pub fn none() -> Self {
+        Self::None
+    }
+
+    /// Creates a policy that follows at most `max` redirects.
+    pub fn limited(max: usize) -> Self {
+        Self::Limited(max)
+    }
+
+    /// Creates a policy that decides each redirect with the given function.
+    ///
+    /// Returning `true` follows the redirect; returning `false` stops and
+    /// returns the redirect response to the caller.
+    pub fn custom<F>(policy: F) -> Self
+    where
+        F: Fn(&RedirectAttempt<'_>) -> bool + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(policy))
+    }
+
+    /// Returns `true` if the given redirect should be followed.
+    pub(crate) fn allows(&self, attempt: &RedirectAttempt<'_>) -> bool {
+        match self {
+            Self::None => false,
+            Self::Limited(max) => attempt.previous.len() <= *max,
+            Self::Custom(policy) => policy(attempt),
+        }
+    }
+}
+
+impl Default for RedirectPolicy {
+    fn default() -> Self {
+        Self::Limited(DEFAULT_MAX_REDIRECTS)
+    }
+}
+
+impl fmt::Debug for RedirectPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => f.write_str("None"),
+            Self::Limited(max) => f.debug_tuple("Limited").field(max).finish(),
+            Self::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+/// Redirect about to be followed, passed to [`RedirectPolicy::custom`].
+#[derive(Debug)]
+pub struct RedirectAttempt<'a> {
+    status: StatusCode,
+    next: &'a Url,
+    previous: &'a [Url],
+}
+
+impl<'a> RedirectAttempt<'a> {
+    /// Creates a new redirect attempt.
+    pub(crate) fn new(status: StatusCode, next: &'a Url, previous: &'a [Url]) -> Self {
+        Self {
+            status,
+            next,
+            previous,
+        }
+    }
+
+    /// Returns the status code of the redirect response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the URL the redirect points to.
+    pub fn url(&self) -> &Url {
+        self.next
+    }
+
+    /// Returns the URLs visited so far, starting with the original request.
+    pub fn previous(&self) -> &[Url] {
+        self.previous
+    }
+}
+
+/// Returns `true` if the status code is a redirect the client can follow.
+pub(crate) fn is_redirect(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::MOVED_PERMANENTLY
+            | StatusCode::FOUND
+            | StatusCode::SEE_OTHER
+            | StatusCode::TEMPORARY_REDIRECT
+            | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+/// Returns `true` if the redirect requires the request body to be resent.
+pub(crate) fn preserves_body(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TEMPORARY_REDIRECT | StatusCode::PERMANENT_REDIRECT
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(count: usize) -> Vec<Url> {
+        (0..count)
+            .map(|i| Url::parse(&format!("https://api.nvisy.com/{i}")).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_default_policy_is_limited() {
+        assert!(matches!(
+            RedirectPolicy::default(),
+            RedirectPolicy::Limited(DEFAULT_MAX_REDIRECTS)
+        ));
+    }
+
+    #[test]
+    fn test_none_policy_never_follows() {
+        let next = Url::parse("https://api.nvisy.com/next").unwrap();
+        let previous = urls(1);
+        let attempt = RedirectAttempt::new(StatusCode::FOUND, &next, &previous);
+        assert!(!RedirectPolicy::none().allows(&attempt));
+    }
+
+    #[test]
+    fn test_limited_policy_caps_redirects() {
+        let next = Url::parse("https://api.nvisy.com/next").unwrap();
+        let policy = RedirectPolicy::limited(2);
+
+        let previous = urls(2);
+        let attempt = RedirectAttempt::new(StatusCode::FOUND, &next, &previous);
+        assert!(policy.allows(&attempt));
+
+        let previous = urls(3);
+        let attempt = RedirectAttempt::new(StatusCode::FOUND, &next, &previous);
+        assert!(!policy.allows(&attempt));
+    }
+
+    #[test]
+    fn test_custom_policy() {
+        let policy =
+            RedirectPolicy::custom(|attempt| attempt.url().host_str() == Some("api.nvisy.com"));
+        let previous = urls(1);
+
+        let same = Url::parse("https://api.nvisy.com/next").unwrap();
+        let attempt = RedirectAttempt::new(StatusCode::FOUND, &same, &previous);
+        assert!(policy.allows(&attempt));
+
+        let other = Url::parse("https://storage.example.com/blob").unwrap();
+        let attempt = RedirectAttempt::new(StatusCode::FOUND, &other, &previous);
+        assert!(!policy.allows(&attempt));
+    }
+}
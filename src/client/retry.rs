@@ -0,0 +1,235 @@
+//! Retry policy for the Nvisy API client.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use reqwest::{Method, Request, StatusCode};
+
+use super::scoped::IDEMPOTENCY_KEY_HEADER;
+
+/// Default number of times a download is retried after a checksum mismatch.
+pub const DEFAULT_CHECKSUM_RETRIES: u32 = 2;
+
+/// Default maximum number of attempts for a request, including the first.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Default delay before the first retry.
+pub const DEFAULT_BASE_BACKOFF: Duration = Duration::from_millis(200);
+
+/// Default upper bound on the delay between retries.
+pub const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Status codes retried by default.
+const DEFAULT_RETRY_ON: [u16; 5] = [429, 500, 502, 503, 504];
+
+/// Policy controlling how failed operations are retried.
+///
+/// Idempotent requests (`GET`, `HEAD`, `PUT`, `DELETE`, and `OPTIONS`) are
+/// retried on transport errors and on the configured status codes, with
+/// exponential backoff between attempts. Other requests are only retried
+/// when they carry an `Idempotency-Key` header, which lets the server
+/// recognize a repeated attempt that already took effect.
+///
+/// See [`RequestOptions::with_idempotency_key`](crate::RequestOptions::with_idempotency_key).
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use nvisy_sdk::{NvisyConfig, RetryPolicy};
+///
+/// let config = NvisyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_retry_policy(
+///         RetryPolicy::default()
+///             .with_max_attempts(5)
+///             .with_max_backoff(Duration::from_secs(30))
+///             .with_checksum_retries(5),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    checksum_retries: u32,
+    max_attempts: u32,
+    base_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    retry_on: Vec<u16>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that never retries.
+    pub fn none() -> Self {
+        Self {
+            checksum_retries: 0,
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Sets how many times a download is retried after its content fails
+    /// checksum verification.
+    pub fn with_checksum_retries(mut self, retries: u32) -> Self {
+        self.checksum_retries = retries;
+        self
+    }
+
+    /// Sets the maximum number of attempts for a request, including the
+    /// first. A value of 1 disables request retries.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry. The delay doubles with each
+    /// further retry.
+    pub fn with_base_backoff(mut self, base_backoff: Duration) -> Self {
+        self.base_backoff = base_backoff;
+        self
+    }
+
+    /// Sets the upper bound on the delay between retries.
+    pub fn with_max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets whether delays are randomized to spread out retries from many
+    /// clients. Enabled by default.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the response status codes that are retried.
+    pub fn with_retry_on(mut self, status_codes: impl IntoIterator<Item = u16>) -> Self {
+        self.retry_on = status_codes.into_iter().collect();
+        self
+    }
+
+    /// Returns how many times a download is retried after a checksum mismatch.
+    pub fn checksum_retries(&self) -> u32 {
+        self.checksum_retries
+    }
+
+    /// Returns the maximum number of attempts for a request.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the delay before the first retry.
+    pub fn base_backoff(&self) -> Duration {
+        self.base_backoff
+    }
+
+    /// Returns the upper bound on the delay between retries.
+    pub fn max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Returns whether delays are randomized.
+    pub fn jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// Returns the response status codes that are retried.
+    pub fn retry_on(&self) -> &[u16] {
+        &self.retry_on
+    }
+
+    /// Returns `true` if the request may be retried after `attempt`
+    /// attempts.
+    pub(crate) fn allows_retry(&self, request: &Request, attempt: u32) -> bool {
+        attempt < self.max_attempts
+            && (is_idempotent(request.method())
+                || request.headers().contains_key(IDEMPOTENCY_KEY_HEADER))
+    }
+
+    /// Returns `true` if responses with the given status are retried.
+    pub(crate) fn retries_status(&self, status: StatusCode) -> bool {
+        self.retry_on.contains(&status.as_u16())
+    }
+
+    /// Returns the delay before the given retry, starting at 1.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = self
+            .base_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        if !self.jitter {
+            return delay;
+        }
+
+        // Keep at least half of the delay and randomize the rest.
+        let half = delay / 2;
+        let random = RandomState::new().build_hasher().finish();
+        half + half.mul_f64((random % 1000) as f64 / 1000.0)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            checksum_retries: DEFAULT_CHECKSUM_RETRIES,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_backoff: DEFAULT_BASE_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            jitter: true,
+            retry_on: DEFAULT_RETRY_ON.to_vec(),
+        }
+    }
+}
+
+/// Returns `true` if repeating a request with the method has no further
+/// effect.
+fn is_idempotent(method: &Method) -> bool {
+    [
+        Method::GET,
+        Method::HEAD,
+        Method::PUT,
+        Method::DELETE,
+        Method::OPTIONS,
+    ]
+    .contains(method)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_is_exponential_and_capped() {
+        let policy = RetryPolicy::default()
+            .with_base_backoff(Duration::from_millis(100))
+            .with_max_backoff(Duration::from_millis(350))
+            .with_jitter(false);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+
+        let jittered = policy.with_jitter(true).backoff(2);
+        assert!(jittered >= Duration::from_millis(100) && jittered <= Duration::from_millis(200));
+    }
+
+    #[test]
+    fn test_only_idempotent_requests_are_retried() {
+        let url = url::Url::parse("https://api.nvisy.com/workspaces/").unwrap();
+        let get = Request::new(Method::GET, url.clone());
+        let mut post = Request::new(Method::POST, url);
+
+        let policy = RetryPolicy::default();
+        assert!(policy.allows_retry(&get, 1));
+        assert!(!policy.allows_retry(&get, DEFAULT_MAX_ATTEMPTS));
+        assert!(!policy.allows_retry(&post, 1));
+        assert!(!RetryPolicy::none().allows_retry(&get, 1));
+
+        post.headers_mut()
+            .insert(IDEMPOTENCY_KEY_HEADER, "key".parse().unwrap());
+        assert!(policy.allows_retry(&post, 1));
+    }
+}
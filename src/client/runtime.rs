@@ -0,0 +1,20 @@
+//! Timers and clocks that work on native and `wasm32` targets.
+//!
+//! Browsers provide neither a Tokio runtime nor a monotonic
+//! [`std::time::Instant`], so `wasm32` builds use the browser's timers and
+//! `performance.now()` instead.
+
+use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub(crate) use web_time::Instant;
+
+/// Waits for the given duration.
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
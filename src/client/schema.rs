@@ -0,0 +1,151 @@
+//! Response schema versioning.
+//!
+//! The API tags every JSON response with the version of its schema in the
+//! [`SCHEMA_VERSION_HEADER`] header. When the version differs from the one
+//! this SDK was built against, the shims registered for it in
+//! [`SchemaMigrations`] rewrite the body before it is deserialized, so minor
+//! API changes such as renamed fields do not break deployed clients.
+
+use std::fmt;
+use std::sync::Arc;
+
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+
+/// Header carrying the schema version of a response.
+pub const SCHEMA_VERSION_HEADER: &str = "X-Nvisy-Schema-Version";
+
+/// Schema version the response models of this SDK match.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Callback rewriting a response body of another schema version.
+///
+/// Receives the URL path of the request and the parsed body.
+pub type MigrationFn = dyn Fn(&str, &mut Value) + Send + Sync;
+
+/// Migration shims applied to responses by schema version.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nvisy_sdk::{NvisyConfig, SchemaMigrations};
+///
+/// // Version 2 renamed `displayName` to `name`.
+/// let config = NvisyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_schema_migrations(SchemaMigrations::new().rename_field(2, "name", "displayName"))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Default)]
+pub struct SchemaMigrations {
+    shims: Vec<(u32, Arc<MigrationFn>)>,
+}
+
+impl SchemaMigrations {
+    /// Creates an empty set of migrations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a shim for responses tagged with `version`.
+    ///
+    /// Shims for the same version run in registration order.
+    pub fn with_shim<F>(mut self, version: u32, shim: F) -> Self
+    where
+        F: Fn(&str, &mut Value) + Send + Sync + 'static,
+    {
+        self.shims.push((version, Arc::new(shim)));
+        self
+    }
+
+    /// Registers a shim renaming the field `from` to `to` in every object of
+    /// responses tagged with `version`.
+    pub fn rename_field(
+        self,
+        version: u32,
+        from: impl Into<String>,
+        to: impl Into<String>,
+    ) -> Self {
+        let (from, to) = (from.into(), to.into());
+        self.with_shim(version, move |_, body| rename(body, &from, &to))
+    }
+
+    /// Returns `true` if no shims are registered.
+    pub fn is_empty(&self) -> bool {
+        self.shims.is_empty()
+    }
+
+    /// Returns `true` if a body of the given version must be migrated.
+    pub(crate) fn applies_to(&self, version: u32) -> bool {
+        version != SCHEMA_VERSION && self.shims.iter().any(|(v, _)| *v == version)
+    }
+
+    /// Runs the shims registered for `version` on the body.
+    pub(crate) fn migrate(&self, version: u32, path: &str, body: &mut Value) {
+        for (_, shim) in self.shims.iter().filter(|(v, _)| *v == version) {
+            shim(path, body);
+        }
+    }
+}
+
+impl fmt::Debug for SchemaMigrations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let versions: Vec<u32> = self.shims.iter().map(|(version, _)| *version).collect();
+        f.debug_struct("SchemaMigrations")
+            .field("versions", &versions)
+            .finish()
+    }
+}
+
+/// Returns the schema version of a response, if tagged.
+pub(crate) fn response_version(headers: &HeaderMap) -> Option<u32> {
+    headers
+        .get(SCHEMA_VERSION_HEADER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Renames a field in every object nested in the value.
+fn rename(value: &mut Value, from: &str, to: &str) {
+    match value {
+        Value::Object(object) => {
+            if let Some(field) = object.remove(from) {
+                object.insert(to.to_string(), field);
+            }
+            object
+                .values_mut()
+                .for_each(|value| rename(value, from, to));
+        }
+        Value::Array(items) => items.iter_mut().for_each(|value| rename(value, from, to)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_migrations_apply_to_registered_versions() {
+        let migrations = SchemaMigrations::new()
+            .rename_field(2, "name", "displayName")
+            .with_shim(2, |path, body| body["path"] = json!(path));
+
+        assert!(migrations.applies_to(2));
+        assert!(!migrations.applies_to(SCHEMA_VERSION));
+        assert!(!migrations.applies_to(3));
+
+        let mut body = json!({"items": [{"name": "a"}], "hasMore": false});
+        migrations.migrate(2, "/files/", &mut body);
+        assert_eq!(
+            body,
+            json!({"items": [{"displayName": "a"}], "hasMore": false, "path": "/files/"})
+        );
+    }
+}
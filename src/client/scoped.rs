@@ -0,0 +1,262 @@
+//! Clients with default per-call options.
+//!
+//! A [`ScopedClient`] shares the connection pool and caches of the client it
+//! was created from, but applies its own [`RequestOptions`] to every call.
+//! This lets a subsystem tune timeouts, retries, or headers without
+//! affecting other users of the shared client.
+
+use std::ops::Deref;
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use super::nvisy::NvisyClient;
+use super::progress::TransferProgress;
+use super::retry::RetryPolicy;
+#[cfg(feature = "client-encryption")]
+use crate::model::FileEncryptionKey;
+
+/// Header used to act on behalf of another account.
+pub(crate) const IMPERSONATE_HEADER: &str = "X-Nvisy-Impersonate";
+
+/// Header identifying repeated attempts of the same mutating request.
+pub(crate) const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Default options applied to every call made through a [`ScopedClient`].
+///
+/// Unset options fall back to the client configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use nvisy_sdk::{NvisyClient, RequestOptions, Result};
+/// use nvisy_sdk::service::WorkspacesService;
+///
+/// # async fn example() -> Result<()> {
+/// let client = NvisyClient::with_api_key("your-api-key")?;
+///
+/// let reporting = client.with_options(
+///     RequestOptions::new()
+///         .with_timeout(Duration::from_secs(120))
+///         .with_header("X-Request-Source", "reporting"),
+/// );
+/// let workspaces = reporting.list_workspaces(None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestOptions {
+    timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    headers: Vec<(String, String)>,
+    impersonate: Option<Uuid>,
+    idempotency_key: Option<String>,
+    workspace: Option<Uuid>,
+    progress: Option<TransferProgress>,
+    #[cfg(feature = "client-encryption")]
+    encryption_key: Option<FileEncryptionKey>,
+}
+
+impl RequestOptions {
+    /// Creates empty options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the request timeout.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the retry policy.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Adds a header sent with every request.
+    ///
+    /// Invalid header names or values cause requests to fail with
+    /// [`Error::Http`](crate::Error::Http).
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Acts on behalf of the given account.
+    ///
+    /// The API key must be allowed to impersonate other accounts.
+    pub fn with_impersonation(mut self, account_id: Uuid) -> Self {
+        self.impersonate = Some(account_id);
+        self
+    }
+
+    /// Sets the `Idempotency-Key` sent with `POST`, `PATCH`, and `DELETE`
+    /// requests.
+    ///
+    /// Without it, a fresh key is generated for every call and reused for
+    /// its retries. Setting the key yourself lets a call that is repeated
+    /// after a failure, such as a retried `create_workspace`, be recognized
+    /// by the server instead of creating a duplicate.
+    ///
+    /// The key is bound to one operation: only the first mutating request
+    /// made through the resulting [`ScopedClient`] carries it. Later
+    /// requests, such as the completion step of a multipart upload, get
+    /// fresh keys. Create a new scoped client with the same key to repeat
+    /// the operation, and use a new key for each distinct operation.
+    pub fn with_idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Charges requests to the rate budget of the given workspace.
+    ///
+    /// Requests whose path names a workspace are charged to it without this
+    /// option. Set it for calls that address files or uploads directly, such
+    /// as `download_file`, so they count against the workspace's budget set
+    /// with
+    /// [`with_rate_budget`](crate::NvisyConfigBuilder::with_rate_budget).
+    pub fn with_workspace(mut self, workspace_id: Uuid) -> Self {
+        self.workspace = Some(workspace_id);
+        self
+    }
+
+    /// Reports the progress of file uploads and downloads.
+    ///
+    /// The callback receives the bytes transferred so far and the total
+    /// size, if known. See [`TransferProgress`] for where it applies.
+    pub fn on_progress<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(u64, Option<u64>) + Send + Sync + 'static,
+    {
+        self.progress = Some(TransferProgress::new(hook));
+        self
+    }
+
+    /// Decrypts downloaded files that were encrypted on the client.
+    ///
+    /// Applies to the `download_file` family of
+    /// [`FilesService`](crate::service::FilesService) methods. Files uploaded
+    /// without client-side encryption are returned as stored.
+    #[cfg(feature = "client-encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "client-encryption")))]
+    pub fn with_encryption_key(mut self, key: FileEncryptionKey) -> Self {
+        self.encryption_key = Some(key);
+        self
+    }
+
+    /// Returns the request timeout, if set.
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns the retry policy, if set.
+    pub fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    /// Returns the extra headers.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// Returns the impersonated account, if set.
+    pub fn impersonation(&self) -> Option<Uuid> {
+        self.impersonate
+    }
+
+    /// Returns the idempotency key, if set.
+    pub fn idempotency_key(&self) -> Option<&str> {
+        self.idempotency_key.as_deref()
+    }
+
+    /// Returns the workspace requests are charged to, if set.
+    pub fn workspace(&self) -> Option<Uuid> {
+        self.workspace
+    }
+
+    /// Returns the transfer progress callback, if set.
+    pub fn progress(&self) -> Option<&TransferProgress> {
+        self.progress.as_ref()
+    }
+
+    /// Returns the key downloads are decrypted with, if set.
+    #[cfg(feature = "client-encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "client-encryption")))]
+    pub fn encryption_key(&self) -> Option<&FileEncryptionKey> {
+        self.encryption_key.as_ref()
+    }
+
+    /// Returns these options with `other` applied on top.
+    ///
+    /// Options set in `other` take precedence; headers are combined.
+    pub(crate) fn merge(&self, other: RequestOptions) -> Self {
+        let mut headers = self.headers.clone();
+        headers.extend(other.headers);
+        Self {
+            timeout: other.timeout.or(self.timeout),
+            retry_policy: other.retry_policy.or_else(|| self.retry_policy.clone()),
+            headers,
+            impersonate: other.impersonate.or(self.impersonate),
+            idempotency_key: other
+                .idempotency_key
+                .or_else(|| self.idempotency_key.clone()),
+            workspace: other.workspace.or(self.workspace),
+            progress: other.progress.or_else(|| self.progress.clone()),
+            #[cfg(feature = "client-encryption")]
+            encryption_key: other.encryption_key.or_else(|| self.encryption_key.clone()),
+        }
+    }
+}
+
+/// Client applying default [`RequestOptions`] to every call.
+///
+/// Created with [`NvisyClient::with_options`]. Dereferences to
+/// [`NvisyClient`], so all service traits are available on it.
+#[derive(Clone, Debug)]
+pub struct ScopedClient {
+    client: NvisyClient,
+}
+
+impl ScopedClient {
+    /// Creates a scoped client from a client carrying the options.
+    pub(crate) fn new(client: NvisyClient) -> Self {
+        Self { client }
+    }
+
+    /// Returns the options applied to every call.
+    pub fn options(&self) -> &RequestOptions {
+        self.client.options()
+    }
+}
+
+impl Deref for ScopedClient {
+    type Target = NvisyClient;
+
+    fn deref(&self) -> &NvisyClient {
+        &self.client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_prefers_newer_options() {
+        let base = RequestOptions::new()
+            .with_timeout(Duration::from_secs(10))
+            .with_header("X-A", "1");
+        let merged = base.merge(
+            RequestOptions::new()
+                .with_timeout(Duration::from_secs(20))
+                .with_header("X-B", "2"),
+        );
+
+        assert_eq!(merged.timeout(), Some(Duration::from_secs(20)));
+        assert_eq!(merged.headers().len(), 2);
+        assert!(merged.retry_policy().is_none());
+    }
+}
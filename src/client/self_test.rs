@@ -0,0 +1,194 @@
+//! Startup self-test for readiness checks.
+//!
+//! [`NvisyClient::self_test`] exercises the whole dependency chain (API
+//! reachability, authentication, and a cheap authenticated read) so that
+//! service readiness handlers can report whether Nvisy is usable.
+
+use std::time::Duration;
+
+use super::nvisy::NvisyClient;
+use super::runtime::Instant;
+use crate::error::{Error, ErrorKind};
+use crate::model::ServiceStatus;
+use crate::service::{HealthService, ListWorkspacesOptions, WorkspacesService};
+
+/// Step of the self-test.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SelfTestStep {
+    /// The health endpoint responds and does not report an unhealthy status.
+    Health,
+    /// The configured API key is accepted.
+    Authentication,
+    /// An authenticated list call succeeds.
+    List,
+}
+
+/// Outcome of a single self-test step.
+#[derive(Debug)]
+pub struct SelfTestCheck {
+    /// Step that was run.
+    pub step: SelfTestStep,
+    /// Time the step took.
+    pub duration: Duration,
+    /// Error the step failed with, if any.
+    pub error: Option<Error>,
+}
+
+impl SelfTestCheck {
+    /// Returns `true` if the step succeeded.
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Report returned by [`NvisyClient::self_test`].
+#[derive(Debug)]
+pub struct SelfTestReport {
+    /// Outcome of each step, in the order they ran.
+    pub checks: Vec<SelfTestCheck>,
+    /// API version reported by the health endpoint.
+    pub server_version: Option<String>,
+}
+
+impl SelfTestReport {
+    /// Returns `true` if every step succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.checks.iter().all(SelfTestCheck::passed)
+    }
+
+    /// Returns the steps that failed.
+    pub fn failures(&self) -> impl Iterator<Item = &SelfTestCheck> {
+        self.checks.iter().filter(|check| !check.passed())
+    }
+}
+
+impl NvisyClient {
+    /// Runs a small suite of checks against the Nvisy API.
+    ///
+    /// The suite checks the health endpoint, verifies the API key, and lists
+    /// a single workspace. It never fails itself; inspect the returned report
+    /// instead. Intended to be called from service readiness handlers.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use nvisy_sdk::{NvisyClient, Result};
+    ///
+    /// # async fn example() -> Result<()> {
+    /// let client = NvisyClient::with_api_key("your-api-key")?;
+    /// let report = client.self_test().await;
+    /// for failure in report.failures() {
+    ///     eprintln!("{:?} failed: {:?}", failure.step, failure.error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn self_test(&self) -> SelfTestReport {
+        let mut checks = Vec::with_capacity(3);
+
+        let started = Instant::now();
+        let health = self.health(None).await;
+        let (server_version, error) = match health {
+            Ok(status) if status.status == ServiceStatus::Unhealthy => (
+                Some(status.version),
+                Some(Error::Api(
+                    "service reported an unhealthy status".to_string(),
+                )),
+            ),
+            Ok(status) => (Some(status.version), None),
+            Err(err) => (None, Some(err)),
+        };
+        checks.push(SelfTestCheck {
+            step: SelfTestStep::Health,
+            duration: started.elapsed(),
+            error,
+        });
+
+        // A single list call both verifies the API key and exercises an
+        // authenticated read, so its outcome is split across two steps.
+        let started = Instant::now();
+        let list = self
+            .list_workspaces(Some(ListWorkspacesOptions::new().limit(1)))
+            .await;
+        let duration = started.elapsed();
+        let (auth_error, list_error) = match list {
+            Ok(_) => (None, None),
+            Err(err) if err.kind() == ErrorKind::Auth => (Some(err), None),
+            Err(err) => (None, Some(err)),
+        };
+        let auth_failed = auth_error.is_some();
+        checks.push(SelfTestCheck {
+            step: SelfTestStep::Authentication,
+            duration,
+            error: auth_error,
+        });
+        checks.push(SelfTestCheck {
+            step: SelfTestStep::List,
+            duration,
+            error: if auth_failed {
+                Some(Error::Api(
+                    "skipped after authentication failure".to_string(),
+                ))
+            } else {
+                list_error
+            },
+        });
+
+        SelfTestReport {
+            checks,
+            server_version,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+    use crate::error::Result;
+
+    const HEALTHY: &str =
+        r#"{"checkedAt":"2024-01-01T00:00:00Z","status":"healthy","version":"1.2.3"}"#;
+
+    fn client(base_url: String) -> Result<NvisyClient> {
+        NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()
+    }
+
+    #[tokio::test]
+    async fn test_self_test_passes() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], HEALTHY),
+            mock::response(200, &[], r#"{"items":[],"hasMore":false}"#),
+        ]);
+
+        let report = client(base_url)?.self_test().await;
+        assert!(report.is_ok());
+        assert_eq!(report.server_version.as_deref(), Some("1.2.3"));
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[1].request_line(),
+            "GET /workspaces/?limit=1 HTTP/1.1"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_self_test_reports_auth_failure() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], HEALTHY),
+            mock::response(401, &[], ""),
+        ]);
+
+        let report = client(base_url)?.self_test().await;
+        let failed: Vec<_> = report.failures().map(|check| check.step).collect();
+        assert_eq!(failed, [SelfTestStep::Authentication, SelfTestStep::List]);
+
+        server.join().unwrap();
+        Ok(())
+    }
+}
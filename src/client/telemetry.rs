@@ -0,0 +1,245 @@
+//! Opt-in usage telemetry for the Nvisy API client.
+//!
+//! When enabled with [`NvisyConfigBuilder::with_telemetry`], the client keeps
+//! anonymous aggregate counters (request totals and errors by kind) and
+//! periodically hands a [`TelemetryReport`] to a user-supplied sink. Nothing
+//! is sent anywhere unless the sink does so; the SDK never reports to Nvisy
+//! on its own.
+//!
+//! [`NvisyConfigBuilder::with_telemetry`]: crate::NvisyConfigBuilder::with_telemetry
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::runtime::Instant;
+use crate::error::ErrorKind;
+
+/// Version of this SDK, as reported in telemetry.
+pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Default interval between telemetry reports.
+pub const DEFAULT_TELEMETRY_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Sink receiving telemetry reports.
+pub type TelemetrySink = dyn Fn(&TelemetryReport) + Send + Sync;
+
+/// Telemetry configuration.
+///
+/// Reports are emitted from the request path once the interval has elapsed,
+/// and a final report is emitted when the last clone of the client is
+/// dropped. The sink should return quickly.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use nvisy_sdk::{NvisyConfig, Telemetry};
+///
+/// let telemetry = Telemetry::new(|report| {
+///     println!(
+///         "nvisy-sdk {} made {} requests ({:.1}% errors)",
+///         report.sdk_version,
+///         report.requests,
+///         report.error_rate() * 100.0,
+///     );
+/// })
+/// .with_interval(Duration::from_secs(15 * 60));
+///
+/// let config = NvisyConfig::builder()
+///     .with_api_key("your-api-key")
+///     .with_telemetry(telemetry)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Telemetry {
+    sink: Arc<TelemetrySink>,
+    interval: Duration,
+}
+
+impl Telemetry {
+    /// Creates a telemetry configuration reporting to the given sink.
+    pub fn new<F>(sink: F) -> Self
+    where
+        F: Fn(&TelemetryReport) + Send + Sync + 'static,
+    {
+        Self {
+            sink: Arc::new(sink),
+            interval: DEFAULT_TELEMETRY_INTERVAL,
+        }
+    }
+
+    /// Sets the interval between reports.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the interval between reports.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Passes a report to the sink.
+    pub(crate) fn emit(&self, report: &TelemetryReport) {
+        (self.sink)(report);
+    }
+}
+
+impl fmt::Debug for Telemetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Telemetry")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Aggregate usage of a client over a reporting period.
+///
+/// Reports contain no request URLs, resource identifiers, or credentials.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TelemetryReport {
+    /// Version of this SDK.
+    pub sdk_version: &'static str,
+    /// API version reported by the health endpoint, if it has been called.
+    pub api_version: Option<String>,
+    /// Length of the reporting period.
+    pub period: Duration,
+    /// Number of requests sent during the period, including redirect hops.
+    pub requests: u64,
+    /// Number of failed requests during the period, by kind.
+    ///
+    /// Requests fail either without a response or with an error status.
+    pub errors: HashMap<ErrorKind, u64>,
+}
+
+impl TelemetryReport {
+    /// Returns the total number of failed requests.
+    pub fn error_count(&self) -> u64 {
+        self.errors.values().sum()
+    }
+
+    /// Returns the fraction of requests that failed, between 0 and 1.
+    pub fn error_rate(&self) -> f64 {
+        if self.requests == 0 {
+            return 0.0;
+        }
+        self.error_count() as f64 / self.requests as f64
+    }
+}
+
+/// Counters accumulated since the last report.
+#[derive(Debug)]
+struct Counters {
+    started: Instant,
+    requests: u64,
+    errors: HashMap<ErrorKind, u64>,
+    api_version: Option<String>,
+}
+
+/// Telemetry counters shared by all clones of a client.
+#[derive(Debug)]
+pub(crate) struct TelemetryState {
+    counters: Mutex<Counters>,
+}
+
+impl TelemetryState {
+    /// Creates empty counters.
+    pub(crate) fn new() -> Self {
+        Self {
+            counters: Mutex::new(Counters {
+                started: Instant::now(),
+                requests: 0,
+                errors: HashMap::new(),
+                api_version: None,
+            }),
+        }
+    }
+
+    /// Records a completed request, failed with `error` if set.
+    ///
+    /// Returns a report if the interval has elapsed, resetting the counters.
+    pub(crate) fn record(
+        &self,
+        error: Option<ErrorKind>,
+        interval: Duration,
+    ) -> Option<TelemetryReport> {
+        let mut counters = self.counters.lock().unwrap();
+        counters.requests += 1;
+        if let Some(kind) = error {
+            *counters.errors.entry(kind).or_default() += 1;
+        }
+
+        if counters.started.elapsed() < interval {
+            return None;
+        }
+        Some(Self::report(&mut counters))
+    }
+
+    /// Records the API version reported by the server.
+    pub(crate) fn set_api_version(&self, version: &str) {
+        self.counters.lock().unwrap().api_version = Some(version.to_string());
+    }
+
+    /// Returns a report of the counters, resetting them, unless no requests
+    /// were made since the last report.
+    pub(crate) fn take(&self) -> Option<TelemetryReport> {
+        let mut counters = self.counters.lock().unwrap();
+        if counters.requests == 0 {
+            return None;
+        }
+        Some(Self::report(&mut counters))
+    }
+
+    /// Builds a report from the counters and starts a new period.
+    fn report(counters: &mut Counters) -> TelemetryReport {
+        let report = TelemetryReport {
+            sdk_version: SDK_VERSION,
+            api_version: counters.api_version.clone(),
+            period: counters.started.elapsed(),
+            requests: counters.requests,
+            errors: std::mem::take(&mut counters.errors),
+        };
+        counters.started = Instant::now();
+        counters.requests = 0;
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_reports_after_interval() {
+        let state = TelemetryState::new();
+        assert!(state.record(None, Duration::from_secs(60)).is_none());
+
+        let report = state
+            .record(Some(ErrorKind::Server), Duration::ZERO)
+            .unwrap();
+        assert_eq!(report.requests, 2);
+        assert_eq!(report.error_count(), 1);
+        assert_eq!(report.errors[&ErrorKind::Server], 1);
+        assert_eq!(report.error_rate(), 0.5);
+
+        assert!(state.take().is_none());
+    }
+
+    #[test]
+    fn test_api_version_survives_reset() {
+        let state = TelemetryState::new();
+        state.set_api_version("1.2.0");
+        state.record(None, Duration::from_secs(60));
+
+        let report = state.take().unwrap();
+        assert_eq!(report.api_version.as_deref(), Some("1.2.0"));
+        assert_eq!(report.sdk_version, SDK_VERSION);
+
+        state.record(None, Duration::from_secs(60));
+        let report = state.take().unwrap();
+        assert_eq!(report.api_version.as_deref(), Some("1.2.0"));
+    }
+}
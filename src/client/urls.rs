@@ -0,0 +1,88 @@
+//! Web console URLs for Nvisy resources.
+
+use url::Url;
+use uuid::Uuid;
+
+use super::nvisy::NvisyClient;
+
+/// Builder for canonical web console URLs.
+///
+/// Obtained from [`NvisyClient::urls`]. URLs are based on the configured
+/// [`console_url`](crate::NvisyConfig::console_url), so links generated by
+/// a backend point at the same deployment the client talks to.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use nvisy_sdk::{NvisyClient, Result};
+/// # use uuid::Uuid;
+/// # fn example(workspace_id: Uuid) -> Result<()> {
+/// let client = NvisyClient::with_api_key("your-api-key")?;
+/// let link = client.urls().workspace_url(workspace_id);
+/// println!("Review the workspace: {link}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ConsoleUrls<'a> {
+    base: &'a Url,
+}
+
+impl<'a> ConsoleUrls<'a> {
+    /// Creates a URL builder for the given console base URL.
+    pub(crate) fn new(base: &'a Url) -> Self {
+        Self { base }
+    }
+
+    /// Returns the console URL of a workspace.
+    pub fn workspace_url(&self, workspace_id: Uuid) -> Url {
+        self.join(&["workspaces", &workspace_id.to_string()])
+    }
+
+    /// Returns the console URL of a file.
+    pub fn file_url(&self, file_id: Uuid) -> Url {
+        self.join(&["files", &file_id.to_string()])
+    }
+
+    /// Appends path segments to the console base URL.
+    fn join(&self, segments: &[&str]) -> Url {
+        let mut url = self.base.clone();
+        if let Ok(mut path) = url.path_segments_mut() {
+            path.pop_if_empty().extend(segments);
+        }
+        url
+    }
+}
+
+impl NvisyClient {
+    /// Returns a builder for web console URLs of resources.
+    pub fn urls(&self) -> ConsoleUrls<'_> {
+        ConsoleUrls::new(&self.inner.console_url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::error::Result;
+
+    #[test]
+    fn test_console_urls() -> Result<()> {
+        let client = NvisyConfig::builder()
+            .with_api_key("test_key")
+            .with_console_url("https://console.example.com/nvisy/")
+            .build_client()?;
+        let id = Uuid::from_u128(1);
+
+        assert_eq!(
+            client.urls().workspace_url(id).as_str(),
+            "https://console.example.com/nvisy/workspaces/00000000-0000-0000-0000-000000000001"
+        );
+        assert_eq!(
+            client.urls().file_url(id).as_str(),
+            "https://console.example.com/nvisy/files/00000000-0000-0000-0000-000000000001"
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,15 @@
 //! Error types for the Nvisy SDK.
 
+use std::fmt;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde::Deserialize;
+use uuid::Uuid;
+
 use crate::client::NvisyConfigBuilderError;
+use crate::model::FileProcessingError;
 
 /// Error type for Nvisy API operations.
 ///
@@ -16,6 +25,21 @@ pub enum Error {
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
 
+    /// Request timeout.
+    ///
+    /// This occurs when the API does not respond within the configured
+    /// timeout. The phase indicates whether the connection could not be
+    /// established or the response was not received in time.
+    #[error("Request timed out during {phase} after {elapsed:?} (limit {limit:?})")]
+    Timeout {
+        /// Phase of the request that timed out.
+        phase: TimeoutPhase,
+        /// Time elapsed before the timeout was detected.
+        elapsed: Duration,
+        /// Configured timeout limit.
+        limit: Duration,
+    },
+
     /// JSON serialization/deserialization error.
     ///
     /// This occurs when the SDK fails to parse API responses or serialize
@@ -42,6 +66,114 @@ pub enum Error {
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
+    /// Response body exceeded the configured maximum size.
+    ///
+    /// This occurs when a response is larger than
+    /// [`NvisyConfig::max_response_size`](crate::NvisyConfig::max_response_size).
+    /// The body is not read past the limit.
+    #[error("Response body exceeds the maximum size of {limit} bytes")]
+    ResponseTooLarge {
+        /// Configured maximum response size in bytes.
+        limit: u64,
+    },
+
+    /// Upload exceeded the maximum allowed size.
+    ///
+    /// This occurs before any data is sent when a file is larger than the
+    /// configured or server-advertised maximum upload size.
+    #[error("Upload of {size} bytes exceeds the maximum size of {limit} bytes")]
+    UploadTooLarge {
+        /// Size of the rejected upload in bytes.
+        size: u64,
+        /// Maximum upload size in bytes.
+        limit: u64,
+    },
+
+    /// Downloaded content did not match its expected checksum.
+    ///
+    /// This occurs when the content still fails verification after the
+    /// retries allowed by the [`RetryPolicy`](crate::RetryPolicy).
+    #[error("Checksum mismatch: expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        /// Expected hex-encoded SHA-256 checksum.
+        expected: String,
+        /// Hex-encoded SHA-256 checksum of the received content.
+        actual: String,
+    },
+
+    /// Encryption error.
+    ///
+    /// This occurs when client-side encryption or decryption fails, for
+    /// example because the published public key is malformed or a file was
+    /// encrypted with a different key.
+    #[error("Encryption error: {0}")]
+    Encryption(String),
+
+    /// File processing failed.
+    ///
+    /// This occurs when waiting for a file to be processed and the server
+    /// reports that processing failed.
+    #[error("Processing of file {file_id} failed")]
+    ProcessingFailed {
+        /// Identifier of the file.
+        file_id: Uuid,
+        /// Details of the failure, if reported.
+        error: Option<FileProcessingError>,
+    },
+
+    /// Request was refused because the client is read-only.
+    ///
+    /// This occurs when a client configured with
+    /// [`with_read_only`](crate::NvisyConfigBuilder::with_read_only) is used
+    /// for a call that could modify data. Nothing is sent to the API.
+    #[error("Client is read-only, refusing {method} {path}")]
+    ReadOnlyMode {
+        /// Method of the refused request.
+        method: reqwest::Method,
+        /// Path of the refused request.
+        path: String,
+    },
+
+    /// Credentials could not be obtained.
+    ///
+    /// This occurs when an [`AuthProvider`](crate::AuthProvider) fails to
+    /// produce credentials, for example because an OAuth2 token endpoint
+    /// rejected the client credentials.
+    #[error("Authentication failed: {0}")]
+    Authentication(String),
+
+    /// Webhook delivery failed verification.
+    ///
+    /// This occurs when the signature of an incoming webhook delivery is
+    /// missing, malformed, does not match the payload, or was created
+    /// outside the allowed timestamp tolerance.
+    #[error("Webhook verification failed: {0}")]
+    WebhookVerification(String),
+
+    /// Request was rejected by the API rate limiter.
+    ///
+    /// This occurs when the API responds with `429 Too Many Requests` and
+    /// the retries allowed by the [`RetryPolicy`](crate::RetryPolicy) are
+    /// exhausted, or the requested wait exceeds its maximum backoff.
+    #[error("Rate limited{}", retry_after.map(|after| format!(", retry after {after:?}")).unwrap_or_default())]
+    RateLimited {
+        /// How long to wait before retrying, from `Retry-After` or
+        /// `X-RateLimit-Reset`.
+        retry_after: Option<Duration>,
+        /// Request quota of the current window, from `X-RateLimit-Limit`.
+        limit: Option<u64>,
+        /// Requests left in the current window, from `X-RateLimit-Remaining`.
+        remaining: Option<u64>,
+    },
+
+    /// Error response from the API.
+    ///
+    /// This occurs when the API rejects a request with a `4xx` or `5xx`
+    /// status. The body describes the failure, so applications can branch
+    /// on [`ApiErrorBody::code`] or [`ApiErrorBody::status`].
+    #[error("{0}")]
+    ApiResponse(ApiErrorBody),
+
     /// API error.
     ///
     /// This occurs when the API returns an unexpected response format
@@ -50,8 +182,348 @@ pub enum Error {
     Api(String),
 }
 
+impl Error {
+    /// Returns the category of this error.
+    ///
+    /// This allows retry policies and middleware to classify failures
+    /// uniformly without matching on every variant.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::Http(err) => {
+                if err.is_timeout() {
+                    ErrorKind::Timeout
+                } else if let Some(status) = err.status() {
+                    ErrorKind::from_status(status)
+                } else if err.is_decode() {
+                    ErrorKind::Decode
+                } else if err.is_builder() {
+                    ErrorKind::Validation
+                } else {
+                    ErrorKind::Transport
+                }
+            }
+            Self::Timeout { .. } => ErrorKind::Timeout,
+            Self::Serialization(_) | Self::Api(_) | Self::ResponseTooLarge { .. } => {
+                ErrorKind::Decode
+            }
+            Self::Config(_)
+            | Self::UrlParse(_)
+            | Self::UploadTooLarge { .. }
+            | Self::Encryption(_)
+            | Self::ReadOnlyMode { .. }
+            | Self::WebhookVerification(_) => ErrorKind::Validation,
+            Self::Io(_) | Self::ChecksumMismatch { .. } => ErrorKind::Transport,
+            Self::ProcessingFailed { .. } => ErrorKind::Server,
+            Self::Authentication(_) => ErrorKind::Auth,
+            Self::RateLimited { .. } => ErrorKind::RateLimited,
+            Self::ApiResponse(body) => ErrorKind::from_status(body.status()),
+        }
+    }
+}
+
+/// Error code of responses to requests with an expired pagination cursor.
+const CURSOR_EXPIRED_CODE: &str = "cursor_expired";
+
+/// Header carrying the server-assigned request identifier.
+pub(crate) const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Error response returned by the API.
+///
+/// Returned in [`Error::ApiResponse`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ApiErrorBody {
+    status: StatusCode,
+    /// Machine-readable error code, such as `workspace_not_found`.
+    pub code: Option<String>,
+    /// Human-readable description of the error.
+    pub message: String,
+    /// Additional error details, such as per-field validation failures.
+    pub details: Option<serde_json::Value>,
+    /// Identifier of the failed request, for support inquiries.
+    pub request_id: Option<String>,
+}
+
+/// Wire format of an API error response.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorResponse {
+    #[serde(default)]
+    code: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+    #[serde(default)]
+    details: Option<serde_json::Value>,
+    #[serde(default)]
+    request_id: Option<String>,
+}
+
+impl ApiErrorBody {
+    /// Parses an error response.
+    ///
+    /// Bodies that are not in the API error format are kept as the message.
+    pub(crate) fn from_response(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Self {
+        let response = serde_json::from_slice::<ErrorResponse>(body).ok();
+        let (code, message, details, request_id) = match response {
+            Some(response) => (
+                response.code,
+                response.message,
+                response.details,
+                response.request_id,
+            ),
+            None => {
+                let text = String::from_utf8_lossy(body).trim().to_string();
+                (None, Some(text).filter(|text| !text.is_empty()), None, None)
+            }
+        };
+
+        Self {
+            status,
+            code,
+            message: message
+                .or_else(|| status.canonical_reason().map(str::to_string))
+                .unwrap_or_default(),
+            details,
+            request_id: request_id.or_else(|| {
+                headers
+                    .get(REQUEST_ID_HEADER)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_string)
+            }),
+        }
+    }
+
+    /// Returns the HTTP status of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns `true` if the requested resource does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.status == StatusCode::NOT_FOUND
+    }
+
+    /// Returns `true` if the request lacked valid credentials.
+    pub fn is_unauthorized(&self) -> bool {
+        self.status == StatusCode::UNAUTHORIZED
+    }
+
+    /// Returns `true` if the credentials do not permit the request.
+    pub fn is_forbidden(&self) -> bool {
+        self.status == StatusCode::FORBIDDEN
+    }
+
+    /// Returns `true` if the request conflicts with the current resource
+    /// state.
+    pub fn is_conflict(&self) -> bool {
+        self.status == StatusCode::CONFLICT
+    }
+
+    /// Returns `true` if the server failed to handle the request.
+    pub fn is_server_error(&self) -> bool {
+        self.status.is_server_error()
+    }
+
+    /// Returns `true` if a pagination cursor has expired.
+    pub fn is_cursor_expired(&self) -> bool {
+        self.status == StatusCode::GONE || self.code.as_deref() == Some(CURSOR_EXPIRED_CODE)
+    }
+}
+
+impl fmt::Display for ApiErrorBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API error {}", self.status.as_u16())?;
+        if let Some(code) = &self.code {
+            write!(f, " ({code})")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// Phase of a request in which a timeout occurred.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TimeoutPhase {
+    /// Establishing the connection (DNS, TCP, TLS).
+    Connect,
+    /// Waiting for or reading the response.
+    Read,
+    /// Waiting for a file to finish processing.
+    Processing,
+}
+
+impl fmt::Display for TimeoutPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Connect => f.write_str("connect"),
+            Self::Read => f.write_str("read"),
+            Self::Processing => f.write_str("processing"),
+        }
+    }
+}
+
+/// Broad category of an [`Error`].
+///
+/// Returned by [`Error::kind`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Network-level failure (connection refused, DNS, TLS, I/O).
+    Transport,
+    /// The request did not complete within the configured timeout.
+    Timeout,
+    /// Authentication or authorization failed (HTTP 401 or 403).
+    Auth,
+    /// The requested resource does not exist (HTTP 404).
+    NotFound,
+    /// The request conflicts with the current resource state (HTTP 409).
+    Conflict,
+    /// Too many requests were sent (HTTP 429).
+    RateLimited,
+    /// The request or configuration was invalid (HTTP 400 or 422).
+    Validation,
+    /// The server failed to handle the request (HTTP 5xx).
+    Server,
+    /// The response could not be decoded.
+    Decode,
+}
+
+impl ErrorKind {
+    /// Classifies an HTTP error status code.
+    pub(crate) fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Self::Auth,
+            StatusCode::NOT_FOUND | StatusCode::GONE => Self::NotFound,
+            StatusCode::CONFLICT | StatusCode::PRECONDITION_FAILED => Self::Conflict,
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited,
+            StatusCode::REQUEST_TIMEOUT => Self::Timeout,
+            status if status.is_server_error() => Self::Server,
+            _ => Self::Validation,
+        }
+    }
+
+    /// Returns the kind as a lowercase identifier, such as `rate_limited`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Transport => "transport",
+            Self::Timeout => "timeout",
+            Self::Auth => "auth",
+            Self::NotFound => "not_found",
+            Self::Conflict => "conflict",
+            Self::RateLimited => "rate_limited",
+            Self::Validation => "validation",
+            Self::Server => "server",
+            Self::Decode => "decode",
+        }
+    }
+
+    /// Returns `true` if an operation failing with this kind may succeed on retry.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::Transport | Self::Timeout | Self::RateLimited | Self::Server
+        )
+    }
+}
+
 /// Result type for Nvisy API operations.
 ///
 /// This is a convenience type alias for `std::result::Result<T, Error>` that is used
 /// throughout the Nvisy SDK. All SDK methods that can fail return this Result type.
 pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_error(status: u16) -> Error {
+        let response = http::Response::builder()
+            .status(status)
+            .body(String::new())
+            .unwrap();
+        reqwest::Response::from(response)
+            .error_for_status()
+            .unwrap_err()
+            .into()
+    }
+
+    #[test]
+    fn test_kind_from_http_status() {
+        assert_eq!(status_error(401).kind(), ErrorKind::Auth);
+        assert_eq!(status_error(403).kind(), ErrorKind::Auth);
+        assert_eq!(status_error(404).kind(), ErrorKind::NotFound);
+        assert_eq!(status_error(409).kind(), ErrorKind::Conflict);
+        assert_eq!(status_error(422).kind(), ErrorKind::Validation);
+        assert_eq!(status_error(429).kind(), ErrorKind::RateLimited);
+        assert_eq!(status_error(503).kind(), ErrorKind::Server);
+    }
+
+    #[test]
+    fn test_kind_from_other_variants() {
+        let err: Error = serde_json::from_str::<u32>("nope").unwrap_err().into();
+        assert_eq!(err.kind(), ErrorKind::Decode);
+
+        let err: Error = url::Url::parse("not a url").unwrap_err().into();
+        assert_eq!(err.kind(), ErrorKind::Validation);
+
+        let err: Error = std::io::Error::other("boom").into();
+        assert_eq!(err.kind(), ErrorKind::Transport);
+
+        let err = Error::ResponseTooLarge { limit: 1024 };
+        assert_eq!(err.kind(), ErrorKind::Decode);
+
+        let err = Error::UploadTooLarge {
+            size: 2048,
+            limit: 1024,
+        };
+        assert_eq!(err.kind(), ErrorKind::Validation);
+
+        let err = Error::Timeout {
+            phase: TimeoutPhase::Connect,
+            elapsed: Duration::from_secs(5),
+            limit: Duration::from_secs(5),
+        };
+        assert_eq!(err.kind(), ErrorKind::Timeout);
+        assert_eq!(
+            err.to_string(),
+            "Request timed out during connect after 5s (limit 5s)"
+        );
+    }
+
+    #[test]
+    fn test_kind_is_retryable() {
+        assert!(ErrorKind::Server.is_retryable());
+        assert!(ErrorKind::RateLimited.is_retryable());
+        assert!(!ErrorKind::NotFound.is_retryable());
+        assert!(!ErrorKind::Validation.is_retryable());
+    }
+
+    #[test]
+    fn test_api_error_body_is_parsed() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID_HEADER, "req-header".parse().unwrap());
+
+        let body = ApiErrorBody::from_response(
+            StatusCode::NOT_FOUND,
+            &headers,
+            br#"{"code": "workspace_not_found", "message": "Workspace not found",
+                 "details": {"workspaceId": "abc"}, "requestId": "req-body"}"#,
+        );
+        assert!(body.is_not_found());
+        assert!(!body.is_unauthorized());
+        assert_eq!(body.code.as_deref(), Some("workspace_not_found"));
+        assert_eq!(body.request_id.as_deref(), Some("req-body"));
+        assert_eq!(body.details.unwrap()["workspaceId"], "abc");
+
+        let err = Error::ApiResponse(ApiErrorBody::from_response(
+            StatusCode::UNAUTHORIZED,
+            &headers,
+            b"invalid api key",
+        ));
+        assert_eq!(err.kind(), ErrorKind::Auth);
+        assert_eq!(err.to_string(), "API error 401: invalid api key");
+        let Error::ApiResponse(body) = err else {
+            unreachable!()
+        };
+        assert!(body.is_unauthorized());
+        assert_eq!(body.request_id.as_deref(), Some("req-header"));
+    }
+}
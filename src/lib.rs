@@ -5,6 +5,7 @@ mod client;
 mod error;
 pub mod model;
 pub mod service;
+pub mod webhook;
 
 #[doc(hidden)]
 pub mod prelude;
@@ -14,7 +15,21 @@ pub mod prelude;
 pub(crate) const TRACING_TARGET_CLIENT: &str = "nvisy_sdk::client";
 
 // Re-export client types
-pub use client::{DEFAULT_BASE_URL, DEFAULT_TIMEOUT, NvisyClient, NvisyConfig, NvisyConfigBuilder};
+pub use client::{
+    ApiKey, AuthFuture, AuthProvider, BearerToken, ConsoleUrls, DEFAULT_BASE_BACKOFF,
+    DEFAULT_BASE_URL, DEFAULT_CHECKSUM_RETRIES, DEFAULT_CONSOLE_URL, DEFAULT_MAX_ATTEMPTS,
+    DEFAULT_MAX_BACKOFF, DEFAULT_MAX_REDIRECTS, DEFAULT_MAX_TIMEOUT, DEFAULT_REFRESH_MARGIN,
+    DEFAULT_TELEMETRY_INTERVAL, DEFAULT_TIMEOUT, DownloadCache, ErrorHook, ErrorInfo, MigrationFn,
+    NvisyClient, NvisyConfig, NvisyConfigBuilder, OAuth2ClientCredentials, ProgressHook,
+    RedirectAttempt, RedirectFn, RedirectPolicy, RequestHook, RequestInfo, RequestInterceptor,
+    RequestOptions, ResponseHook, ResponseInfo, RetryHook, RetryInfo, RetryPolicy, SCHEMA_VERSION,
+    SCHEMA_VERSION_HEADER, SDK_VERSION, SchemaMigrations, ScopedClient, SelfTestCheck,
+    SelfTestReport, SelfTestStep, Telemetry, TelemetryReport, TelemetrySink, TransferProgress,
+};
+
+// Re-export DNS resolver types used by `with_dns_resolver`
+#[cfg(not(target_arch = "wasm32"))]
+pub use reqwest::dns;
 
 // Re-export error types
-pub use error::{Error, Result};
+pub use error::{ApiErrorBody, Error, ErrorKind, Result, TimeoutPhase};
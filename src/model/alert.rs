@@ -0,0 +1,153 @@
+//! Usage alert models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Usage metric an alert is evaluated against.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageMetric {
+    /// Total stored bytes across the organization.
+    StorageBytes,
+    /// Pages processed in the current billing month.
+    ProcessedPagesMonthly,
+    /// API requests made in the current billing month.
+    ApiRequestsMonthly,
+    /// Number of active workspace members.
+    ActiveMembers,
+}
+
+/// Destination an alert is delivered to when it fires.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertChannel {
+    /// Deliver to an existing webhook.
+    #[serde(rename_all = "camelCase")]
+    Webhook {
+        /// Webhook to deliver to.
+        webhook_id: Uuid,
+    },
+    /// Deliver by email.
+    #[serde(rename_all = "camelCase")]
+    Email {
+        /// Recipient email addresses.
+        recipients: Vec<String>,
+    },
+}
+
+/// Current evaluation state of an alert.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertState {
+    /// Usage is below the threshold.
+    Ok,
+    /// Usage has crossed the threshold.
+    Triggered,
+    /// Alert is disabled and not evaluated.
+    Disabled,
+}
+
+/// Usage-based threshold alert.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageAlert {
+    /// Unique alert identifier.
+    pub alert_id: Uuid,
+    /// Human-readable name for the alert.
+    pub display_name: String,
+    /// Metric the alert watches.
+    pub metric: UsageMetric,
+    /// Threshold value that triggers the alert, in the metric's unit.
+    pub threshold: i64,
+    /// Channels notified when the alert fires.
+    pub channels: Vec<AlertChannel>,
+    /// Whether the alert is evaluated.
+    pub is_active: bool,
+    /// Account that created this alert.
+    pub created_by: Uuid,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
+
+/// Current state of a usage alert.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageAlertStatus {
+    /// Alert this status belongs to.
+    pub alert_id: Uuid,
+    /// Metric the alert watches.
+    pub metric: UsageMetric,
+    /// Current evaluation state.
+    pub state: AlertState,
+    /// Current value of the metric.
+    pub current_value: i64,
+    /// Threshold value that triggers the alert.
+    pub threshold: i64,
+    /// Time the alert last fired.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_triggered_at: Option<Timestamp>,
+    /// Time the alert was last evaluated.
+    pub evaluated_at: Timestamp,
+}
+
+/// Request payload for creating a usage alert.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateUsageAlert {
+    /// Human-readable name for the alert.
+    pub display_name: String,
+    /// Metric the alert watches.
+    pub metric: UsageMetric,
+    /// Threshold value that triggers the alert.
+    pub threshold: i64,
+    /// Channels notified when the alert fires.
+    pub channels: Vec<AlertChannel>,
+    /// Whether the alert should be active immediately.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+impl CreateUsageAlert {
+    /// Creates a new usage alert request.
+    pub fn new(
+        display_name: impl Into<String>,
+        metric: UsageMetric,
+        threshold: i64,
+        channels: Vec<AlertChannel>,
+    ) -> Self {
+        Self {
+            display_name: display_name.into(),
+            metric,
+            threshold,
+            channels,
+            is_active: None,
+        }
+    }
+
+    /// Sets whether the alert should be active immediately.
+    pub fn is_active(mut self, is_active: bool) -> Self {
+        self.is_active = Some(is_active);
+        self
+    }
+}
+
+/// Request payload for updating a usage alert.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateUsageAlert {
+    /// Updated name.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Updated threshold.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<i64>,
+    /// Updated delivery channels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<AlertChannel>>,
+    /// Updated active status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
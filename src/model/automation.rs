@@ -0,0 +1,121 @@
+//! Automation models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Trigger available in the automation catalog.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationTriggerDefinition {
+    /// Trigger key (e.g. `file_completed`).
+    pub key: String,
+    /// Human-readable name.
+    pub display_name: String,
+    /// Description of when the trigger fires.
+    pub description: String,
+    /// JSON schema of the trigger's parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters_schema: Option<serde_json::Value>,
+}
+
+/// Action available in the automation catalog.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationActionDefinition {
+    /// Action key (e.g. `add_tag`).
+    pub key: String,
+    /// Human-readable name.
+    pub display_name: String,
+    /// Description of what the action does.
+    pub description: String,
+    /// JSON schema of the action's parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters_schema: Option<serde_json::Value>,
+}
+
+/// Trigger of an automation rule.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationTrigger {
+    /// Trigger key from the catalog.
+    pub key: String,
+    /// Trigger parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+}
+
+impl AutomationTrigger {
+    /// Creates a trigger with the given catalog key.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            parameters: None,
+        }
+    }
+
+    /// Sets the trigger parameters.
+    pub fn parameters(mut self, parameters: serde_json::Value) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+}
+
+/// Action run by an automation rule.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutomationAction {
+    /// Action key from the catalog.
+    pub key: String,
+    /// Action parameters.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<serde_json::Value>,
+}
+
+impl AutomationAction {
+    /// Creates an action with the given catalog key.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            parameters: None,
+        }
+    }
+
+    /// Sets the action parameters.
+    pub fn parameters(mut self, parameters: serde_json::Value) -> Self {
+        self.parameters = Some(parameters);
+        self
+    }
+}
+
+/// Automation rule running actions when a trigger fires.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Automation {
+    /// Unique automation identifier.
+    pub automation_id: Uuid,
+    /// Workspace the automation belongs to.
+    pub workspace_id: Uuid,
+    /// Trigger that starts the automation.
+    pub trigger: AutomationTrigger,
+    /// Actions run in order when the trigger fires.
+    pub actions: Vec<AutomationAction>,
+    /// Whether the automation is active.
+    pub is_active: bool,
+    /// Account that created this automation.
+    pub created_by: Uuid,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
+
+/// Request payload for creating an automation.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CreateAutomation {
+    /// Trigger that starts the automation.
+    pub trigger: AutomationTrigger,
+    /// Actions run in order when the trigger fires.
+    pub actions: Vec<AutomationAction>,
+}
@@ -0,0 +1,81 @@
+//! Barcode extraction models.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Symbology of a decoded barcode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BarcodeFormat {
+    /// QR code.
+    QrCode,
+    /// Data Matrix.
+    DataMatrix,
+    /// PDF417.
+    Pdf417,
+    /// Aztec code.
+    Aztec,
+    /// Code 128.
+    Code128,
+    /// Code 39.
+    Code39,
+    /// EAN-13.
+    Ean13,
+    /// EAN-8.
+    Ean8,
+    /// UPC-A.
+    UpcA,
+    /// Interleaved 2 of 5.
+    Itf,
+    /// Format not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Area of a page, in points from the top-left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageRegion {
+    /// Distance from the left edge.
+    pub x: f64,
+    /// Distance from the top edge.
+    pub y: f64,
+    /// Width of the area.
+    pub width: f64,
+    /// Height of the area.
+    pub height: f64,
+}
+
+/// Barcode decoded from a file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Barcode {
+    /// Symbology of the barcode.
+    pub format: BarcodeFormat,
+    /// Decoded value.
+    pub value: String,
+    /// Page the barcode is on, starting at 1.
+    pub page_number: u32,
+    /// Position of the barcode on the page.
+    pub region: PageRegion,
+}
+
+/// Barcodes decoded from a file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Barcodes {
+    /// File the barcodes were decoded from.
+    pub file_id: Uuid,
+    /// Barcodes, in page and reading order.
+    #[serde(default)]
+    pub items: Vec<Barcode>,
+}
+
+impl Barcodes {
+    /// Returns the barcodes of the given format.
+    pub fn of_format(&self, format: BarcodeFormat) -> impl Iterator<Item = &Barcode> {
+        self.items
+            .iter()
+            .filter(move |barcode| barcode.format == format)
+    }
+}
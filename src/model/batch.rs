@@ -0,0 +1,86 @@
+//! Batch lookup and bulk operation models.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Result of looking up many resources at once.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Batch<T> {
+    /// Resources that were found, in the order they were requested.
+    pub items: Vec<T>,
+    /// Requested identifiers that do not exist or are not accessible.
+    #[serde(default)]
+    pub not_found: Vec<Uuid>,
+}
+
+impl<T> Batch<T> {
+    /// Returns `true` if every requested resource was found.
+    pub fn is_complete(&self) -> bool {
+        self.not_found.is_empty()
+    }
+}
+
+impl<T> Default for Batch<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            not_found: Vec::new(),
+        }
+    }
+}
+
+/// Outcome of an operation applied to many resources at once.
+///
+/// Bulk endpoints apply the operation to each resource independently, so
+/// some may succeed while others fail.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkResult<T> {
+    /// Results for the resources the operation succeeded on.
+    #[serde(default)]
+    pub succeeded: Vec<T>,
+    /// Resources the operation failed on.
+    #[serde(default)]
+    pub failed: Vec<BulkFailure>,
+}
+
+impl<T> BulkResult<T> {
+    /// Returns `true` if the operation succeeded on every resource.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+impl<T> Default for BulkResult<T> {
+    fn default() -> Self {
+        Self {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+}
+
+/// Resource a bulk operation failed on.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkFailure {
+    /// Identifier of the resource.
+    pub id: Uuid,
+    /// Machine-readable error code.
+    pub code: String,
+    /// Human-readable error message.
+    pub message: String,
+}
+
+impl BulkFailure {
+    /// Returns `true` if the resource does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.code == "not_found"
+    }
+
+    /// Returns `true` if the caller may not modify the resource.
+    pub fn is_forbidden(&self) -> bool {
+        self.code == "forbidden"
+    }
+}
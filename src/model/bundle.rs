@@ -0,0 +1,187 @@
+//! Document bundle models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{JobError, JobState};
+
+/// Part of a bundle, in the order it appears in the merged document.
+///
+/// Each section starts a top-level bookmark, titled with the section title
+/// or the file or folder name.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+pub enum BundleSection {
+    /// A single file.
+    File {
+        /// File to include.
+        file_id: Uuid,
+        /// Bookmark and table of contents title.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+    },
+    /// The files of a folder, ordered by name.
+    Folder {
+        /// Folder to include.
+        folder_id: Uuid,
+        /// Bookmark and table of contents title.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        /// Whether files in subfolders are included.
+        #[serde(default)]
+        recursive: bool,
+    },
+}
+
+impl BundleSection {
+    /// Creates a section containing a single file.
+    pub fn file(file_id: Uuid) -> Self {
+        Self::File {
+            file_id,
+            title: None,
+        }
+    }
+
+    /// Creates a section containing the files directly in a folder.
+    pub fn folder(folder_id: Uuid) -> Self {
+        Self::Folder {
+            folder_id,
+            title: None,
+            recursive: false,
+        }
+    }
+
+    /// Sets the section title.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        match &mut self {
+            Self::File { title: slot, .. } | Self::Folder { title: slot, .. } => {
+                *slot = Some(title.into());
+            }
+        }
+        self
+    }
+
+    /// Includes files in subfolders. Has no effect on file sections.
+    pub fn with_recursive(mut self, value: bool) -> Self {
+        if let Self::Folder { recursive, .. } = &mut self {
+            *recursive = value;
+        }
+        self
+    }
+}
+
+/// Cover page placed before the table of contents.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CoverPage {
+    /// Main title.
+    pub title: String,
+    /// Line shown below the title, such as a case number or client name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+}
+
+impl CoverPage {
+    /// Creates a cover page with the given title.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            subtitle: None,
+        }
+    }
+
+    /// Sets the subtitle.
+    pub fn with_subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+}
+
+/// Specification of a bundle to assemble.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleSpec {
+    /// Sections, in order.
+    pub sections: Vec<BundleSection>,
+    /// Whether a table of contents is generated.
+    #[serde(default)]
+    pub toc: bool,
+    /// Cover page, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_page: Option<CoverPage>,
+    /// Name of the resulting file. Generated by the server if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+    /// Folder the resulting file is placed in. Defaults to the workspace root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<Uuid>,
+}
+
+impl BundleSpec {
+    /// Creates a specification with the given sections.
+    pub fn new(sections: Vec<BundleSection>) -> Self {
+        Self {
+            sections,
+            toc: false,
+            cover_page: None,
+            file_name: None,
+            folder_id: None,
+        }
+    }
+
+    /// Sets whether a table of contents is generated.
+    pub fn with_toc(mut self, toc: bool) -> Self {
+        self.toc = toc;
+        self
+    }
+
+    /// Sets the cover page.
+    pub fn with_cover_page(mut self, cover_page: CoverPage) -> Self {
+        self.cover_page = Some(cover_page);
+        self
+    }
+
+    /// Sets the name of the resulting file.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Sets the folder the resulting file is placed in.
+    pub fn with_folder_id(mut self, folder_id: Uuid) -> Self {
+        self.folder_id = Some(folder_id);
+        self
+    }
+}
+
+/// Bundle assembled into a single PDF by an asynchronous job.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bundle {
+    /// Unique bundle identifier.
+    pub bundle_id: Uuid,
+    /// Workspace the bundle belongs to.
+    pub workspace_id: Uuid,
+    /// Job assembling the bundle.
+    pub job_id: Uuid,
+    /// Current state of the assembly.
+    pub state: JobState,
+    /// Resulting file, once the assembly succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<Uuid>,
+    /// Number of pages in the resulting file, once known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_count: Option<u32>,
+    /// Failure details, if the assembly failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<JobError>,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
@@ -0,0 +1,90 @@
+//! Server capability models.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Limits and features advertised by the Nvisy API.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    /// Maximum size of a single uploaded file in bytes.
+    pub max_upload_size: i64,
+    /// Maximum number of files accepted by batch operations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_batch_size: Option<i32>,
+}
+
+/// Feature that can be toggled per tenant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Feature {
+    /// Semantic search over file content.
+    SemanticSearch,
+    /// Electronic signature requests.
+    ESignature,
+    /// Discussions on files.
+    Comments,
+    /// Workspace snapshots and restores.
+    Snapshots,
+    /// Trigger-action automation rules.
+    Automations,
+    /// Anonymized dataset exports.
+    AnonymizedExports,
+}
+
+impl Feature {
+    /// Returns the wire name of the feature.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SemanticSearch => "semantic_search",
+            Self::ESignature => "e_signature",
+            Self::Comments => "comments",
+            Self::Snapshots => "snapshots",
+            Self::Automations => "automations",
+            Self::AnonymizedExports => "anonymized_exports",
+        }
+    }
+}
+
+/// Feature toggles of the tenant the API key belongs to.
+///
+/// Features missing from the response are reported as disabled.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeatureFlags {
+    /// Toggles keyed by feature name.
+    #[serde(default)]
+    pub features: BTreeMap<String, bool>,
+}
+
+impl FeatureFlags {
+    /// Returns `true` if the feature is enabled.
+    pub fn is_enabled(&self, feature: Feature) -> bool {
+        self.is_enabled_by_name(feature.as_str())
+    }
+
+    /// Returns `true` if the feature with the given wire name is enabled.
+    ///
+    /// Use this for features added after this version of the SDK.
+    pub fn is_enabled_by_name(&self, name: &str) -> bool {
+        self.features.get(name).copied().unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_flags_default_to_disabled() -> serde_json::Result<()> {
+        let flags: FeatureFlags = serde_json::from_str(
+            r#"{"features": {"semantic_search": true, "e_signature": false, "beta_x": true}}"#,
+        )?;
+        assert!(flags.is_enabled(Feature::SemanticSearch));
+        assert!(!flags.is_enabled(Feature::ESignature));
+        assert!(!flags.is_enabled(Feature::Comments));
+        assert!(flags.is_enabled_by_name("beta_x"));
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+//! Mobile capture session models.
+
+use std::time::Duration;
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Lifecycle state of a capture session.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureSessionStatus {
+    /// Waiting for or receiving uploads.
+    Active,
+    /// Closed by the phone after the last upload.
+    Completed,
+    /// Closed because its lifetime ran out.
+    Expired,
+    /// Status not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+impl CaptureSessionStatus {
+    /// Returns `true` if the session no longer accepts uploads.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, Self::Active)
+    }
+}
+
+/// Short-lived session letting a phone upload photos into a workspace.
+///
+/// The phone opens [`url`](Self::url), usually by scanning a QR code
+/// rendered from it, and needs no API key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureSession {
+    /// Unique session identifier.
+    pub session_id: Uuid,
+    /// Workspace uploads land in.
+    pub workspace_id: Uuid,
+    /// URL the phone opens to upload.
+    pub url: String,
+    /// QR code encoding the URL, as an SVG document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qr_code_svg: Option<String>,
+    /// Current state.
+    pub status: CaptureSessionStatus,
+    /// Files uploaded through the session so far.
+    #[serde(default)]
+    pub file_ids: Vec<Uuid>,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Time after which the session stops accepting uploads.
+    pub expires_at: Timestamp,
+}
+
+impl CaptureSession {
+    /// Returns `true` if the session has expired.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Timestamp::now()
+    }
+}
+
+/// Options for creating a capture session.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureSessionOptions {
+    /// Lifetime of the session in seconds. The server default applies if
+    /// not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in_secs: Option<u64>,
+    /// Folder uploads are placed in. Defaults to the workspace root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<Uuid>,
+    /// Maximum number of photos that can be uploaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_files: Option<u32>,
+    /// Whether the photos of the session are combined into a single PDF.
+    #[serde(default)]
+    pub combine_to_pdf: bool,
+    /// Instructions shown on the phone, such as which documents to capture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+}
+
+impl CaptureSessionOptions {
+    /// Creates options using server defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the lifetime of the session.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.expires_in_secs = Some(ttl.as_secs());
+        self
+    }
+
+    /// Sets the folder uploads are placed in.
+    pub fn with_folder_id(mut self, folder_id: Uuid) -> Self {
+        self.folder_id = Some(folder_id);
+        self
+    }
+
+    /// Sets the maximum number of photos.
+    pub fn with_max_files(mut self, max_files: u32) -> Self {
+        self.max_files = Some(max_files);
+        self
+    }
+
+    /// Sets whether the photos are combined into a single PDF.
+    pub fn with_combine_to_pdf(mut self, combine: bool) -> Self {
+        self.combine_to_pdf = combine;
+        self
+    }
+
+    /// Sets the instructions shown on the phone.
+    pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+}
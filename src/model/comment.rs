@@ -0,0 +1,84 @@
+//! File comment models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Cursor;
+
+/// Comment on a file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    /// Unique comment identifier.
+    pub comment_id: Uuid,
+    /// File the comment belongs to.
+    pub file_id: Uuid,
+    /// Comment this is a reply to, or `None` for a top-level comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<Uuid>,
+    /// Account that wrote the comment.
+    pub author_id: Uuid,
+    /// Comment text.
+    pub body: String,
+    /// Whether the discussion has been resolved.
+    #[serde(default)]
+    pub is_resolved: bool,
+    /// Account that resolved the discussion.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_by: Option<Uuid>,
+    /// Time the discussion was resolved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<Timestamp>,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
+
+impl Comment {
+    /// Returns `true` if the comment is a reply to another comment.
+    pub fn is_reply(&self) -> bool {
+        self.parent_comment_id.is_some()
+    }
+}
+
+/// Request body for creating or editing a comment.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateComment {
+    /// Comment text.
+    pub body: String,
+    /// Comment to reply to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_comment_id: Option<Uuid>,
+}
+
+impl CreateComment {
+    /// Creates a new top-level comment.
+    pub fn new(body: impl Into<String>) -> Self {
+        Self {
+            body: body.into(),
+            parent_comment_id: None,
+        }
+    }
+
+    /// Makes the comment a reply to another comment.
+    pub fn with_parent(mut self, parent_comment_id: Uuid) -> Self {
+        self.parent_comment_id = Some(parent_comment_id);
+        self
+    }
+}
+
+/// Paginated list of comments.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentsPage {
+    /// List of comments.
+    pub items: Vec<Comment>,
+    /// Cursor for the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<Cursor>,
+    /// Whether there are more results.
+    pub has_more: bool,
+}
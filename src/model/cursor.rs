@@ -0,0 +1,114 @@
+//! Pagination cursor.
+
+use std::fmt;
+use std::str::FromStr;
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Opaque cursor pointing at a position in a paginated listing.
+///
+/// Cursors are returned in the `next_cursor` field of page models and passed
+/// back through the `after` option of list requests. They can be persisted
+/// with [`Display`](fmt::Display) and restored with [`FromStr`].
+///
+/// # Examples
+///
+/// ```
+/// use nvisy_sdk::model::Cursor;
+///
+/// let cursor: Cursor = "eyJpZCI6NDJ9".parse().unwrap();
+/// assert_eq!(cursor.to_string(), "eyJpZCI6NDJ9");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Returns the cursor as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = ParseCursorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseCursorError);
+        }
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl AsRef<str> for Cursor {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Position in a listing given by the last item seen.
+///
+/// Listings are ordered by creation time and then identifier, so an anchor
+/// resumes a listing right after its item even once the cursor pointing
+/// there has expired. Passed through the `starting_after` option of list
+/// requests.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageAnchor {
+    /// Creation time of the item.
+    pub created_at: Timestamp,
+    /// Identifier of the item.
+    pub id: Uuid,
+}
+
+impl PageAnchor {
+    /// Creates an anchor at the given item.
+    pub fn new(created_at: Timestamp, id: Uuid) -> Self {
+        Self { created_at, id }
+    }
+
+    /// Returns the query parameters selecting items after the anchor.
+    pub(crate) fn query(&self) -> [(&'static str, String); 2] {
+        [
+            ("anchorCreatedAt", self.created_at.to_string()),
+            ("anchorId", self.id.to_string()),
+        ]
+    }
+}
+
+/// Error returned when parsing an invalid [`Cursor`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+#[error("invalid pagination cursor")]
+pub struct ParseCursorError;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor: Cursor = "abc123".parse().unwrap();
+        assert_eq!(cursor.as_str(), "abc123");
+        assert_eq!(cursor.to_string().parse::<Cursor>().unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_empty_cursor_rejected() {
+        assert_eq!("".parse::<Cursor>(), Err(ParseCursorError));
+    }
+
+    #[test]
+    fn test_cursor_serializes_as_string() {
+        let cursor: Cursor = serde_json::from_str("\"abc123\"").unwrap();
+        assert_eq!(serde_json::to_string(&cursor).unwrap(), "\"abc123\"");
+    }
+}
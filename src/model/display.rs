@@ -0,0 +1,167 @@
+//! Tabular rendering of models for command-line tools.
+
+use serde::Serialize;
+
+use super::{File, Integration, Webhook, Workspace};
+
+/// A model that can be rendered as a row of a text table.
+///
+/// # Examples
+///
+/// ```no_run
+/// use nvisy_sdk::model::render_table;
+/// use nvisy_sdk::service::WorkspacesService;
+/// use nvisy_sdk::{NvisyClient, Result};
+///
+/// # async fn example() -> Result<()> {
+/// let client = NvisyClient::with_api_key("your-api-key")?;
+/// let page = client.list_workspaces(None).await?;
+/// print!("{}", render_table(&page.items));
+/// # Ok(())
+/// # }
+/// ```
+pub trait TableRow {
+    /// Returns the column headers.
+    fn headers() -> &'static [&'static str];
+
+    /// Returns the cell values, in the order of [`headers`](Self::headers).
+    fn to_table_row(&self) -> Vec<String>;
+}
+
+/// Renders rows as a plain-text table with aligned columns.
+///
+/// Each line, including the last, ends with a newline.
+pub fn render_table<T: TableRow>(rows: &[T]) -> String {
+    let headers: Vec<String> = T::headers().iter().map(|h| h.to_string()).collect();
+    let rows: Vec<Vec<String>> = rows.iter().map(TableRow::to_table_row).collect();
+
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in std::iter::once(&headers).chain(&rows) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        table.push_str(cells.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+impl TableRow for File {
+    fn headers() -> &'static [&'static str] {
+        &["ID", "NAME", "FORMAT", "SIZE", "STATUS", "UPDATED"]
+    }
+
+    fn to_table_row(&self) -> Vec<String> {
+        vec![
+            self.file_id.to_string(),
+            self.display_name.clone(),
+            optional(self.format.as_ref().map(wire_name)),
+            self.file_size.to_string(),
+            wire_name(&self.status),
+            self.updated_at.to_string(),
+        ]
+    }
+}
+
+impl TableRow for Workspace {
+    fn headers() -> &'static [&'static str] {
+        &["ID", "NAME", "ROLE", "TAGS", "FILES", "MEMBERS", "UPDATED"]
+    }
+
+    fn to_table_row(&self) -> Vec<String> {
+        vec![
+            self.workspace_id.to_string(),
+            self.display_name.clone(),
+            wire_name(&self.member_role),
+            self.tags.join(","),
+            optional(self.counts.as_ref().map(|counts| counts.files)),
+            optional(self.counts.as_ref().map(|counts| counts.members)),
+            self.updated_at.to_string(),
+        ]
+    }
+}
+
+impl TableRow for Webhook {
+    fn headers() -> &'static [&'static str] {
+        &["ID", "NAME", "URL", "EVENTS", "STATUS", "LAST TRIGGERED"]
+    }
+
+    fn to_table_row(&self) -> Vec<String> {
+        let events: Vec<String> = self.events.iter().map(wire_name).collect();
+        vec![
+            self.webhook_id.to_string(),
+            self.display_name.clone(),
+            self.url.clone(),
+            events.join(","),
+            wire_name(&self.status),
+            optional(self.last_triggered_at),
+        ]
+    }
+}
+
+impl TableRow for Integration {
+    fn headers() -> &'static [&'static str] {
+        &["ID", "NAME", "TYPE", "ACTIVE", "SYNC STATUS", "LAST SYNC"]
+    }
+
+    fn to_table_row(&self) -> Vec<String> {
+        vec![
+            self.integration_id.to_string(),
+            self.integration_name.clone(),
+            wire_name(&self.integration_type),
+            self.is_active.to_string(),
+            optional(self.sync_status.as_ref().map(wire_name)),
+            optional(self.last_sync_at),
+        ]
+    }
+}
+
+/// Returns the name a value has on the wire, e.g. `in_progress`.
+fn wire_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        Ok(other) => other.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+/// Renders an optional cell, using `-` when absent.
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(|| "-".to_string(), |value| value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table_aligns_columns() -> crate::Result<()> {
+        let workspace: Workspace = serde_json::from_str(
+            r#"{"workspaceId":"00000000-0000-0000-0000-000000000001","displayName":"Legal",
+            "tags":["contracts","2024"],"enableComments":true,"requireApproval":false,
+            "memberRole":"admin","createdBy":"00000000-0000-0000-0000-000000000002",
+            "createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-02T00:00:00Z"}"#,
+        )?;
+
+        let table = render_table(&[workspace]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "ID                                    NAME   ROLE   TAGS            FILES  MEMBERS  UPDATED"
+        );
+        assert_eq!(
+            lines[1],
+            "00000000-0000-0000-0000-000000000001  Legal  admin  contracts,2024  -      -        2024-01-02T00:00:00Z"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+//! Workspace event log models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Cursor, WebhookEvent};
+
+/// Event recorded in a workspace's event log.
+///
+/// The event log contains the same events that are delivered to webhooks,
+/// and can be queried to reconcile deliveries missed while offline.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    /// Unique event identifier.
+    pub event_id: Uuid,
+    /// Workspace the event occurred in.
+    pub workspace_id: Uuid,
+    /// Type of the event.
+    pub event: WebhookEvent,
+    /// Resource the event refers to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<Uuid>,
+    /// Account that triggered the event.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<Uuid>,
+    /// Event payload, as delivered to webhooks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<serde_json::Value>,
+    /// Time the event occurred.
+    pub occurred_at: Timestamp,
+}
+
+/// Paginated list of events.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventsPage {
+    /// List of events, oldest first.
+    pub items: Vec<Event>,
+    /// Cursor for the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<Cursor>,
+    /// Whether there are more results.
+    pub has_more: bool,
+}
@@ -0,0 +1,167 @@
+//! Dataset export models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Category of personally identifiable information to redact.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiCategory {
+    /// Personal names.
+    Name,
+    /// Email addresses.
+    Email,
+    /// Phone numbers.
+    Phone,
+    /// Postal addresses.
+    Address,
+    /// Government-issued identifiers (SSN, passport numbers, etc.).
+    GovernmentId,
+    /// Payment card and bank account numbers.
+    FinancialAccount,
+    /// Dates of birth.
+    DateOfBirth,
+}
+
+/// How detected PII is replaced in the exported dataset.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactionMethod {
+    /// Replace with a fixed mask such as `[REDACTED]`.
+    #[default]
+    Mask,
+    /// Replace with a category placeholder such as `[EMAIL]`.
+    Placeholder,
+    /// Replace with a consistent pseudonym, preserving joins across documents.
+    Pseudonymize,
+}
+
+/// Redaction rule applied to one PII category.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactionRule {
+    /// Category of PII the rule applies to.
+    pub category: PiiCategory,
+    /// Replacement method for matches.
+    #[serde(default)]
+    pub method: RedactionMethod,
+}
+
+impl RedactionRule {
+    /// Creates a rule that masks the given category.
+    pub fn new(category: PiiCategory) -> Self {
+        Self {
+            category,
+            method: RedactionMethod::default(),
+        }
+    }
+
+    /// Sets the redaction method.
+    pub fn with_method(mut self, method: RedactionMethod) -> Self {
+        self.method = method;
+        self
+    }
+}
+
+/// Output format of an anonymized dataset.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatasetFormat {
+    /// Newline-delimited JSON with extracted text and metadata.
+    #[default]
+    Jsonl,
+    /// Redacted copies of the original documents.
+    Documents,
+}
+
+/// Options for creating an anonymized export.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnonymizeOptions {
+    /// Files to include. If empty, all files in the workspace are exported.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub file_ids: Vec<Uuid>,
+    /// Redaction rules. If empty, the workspace's default rules are applied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<RedactionRule>,
+    /// Output format.
+    #[serde(default)]
+    pub format: DatasetFormat,
+}
+
+impl AnonymizeOptions {
+    /// Creates new export options using workspace defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the export to the given files.
+    pub fn with_file_ids(mut self, file_ids: Vec<Uuid>) -> Self {
+        self.file_ids = file_ids;
+        self
+    }
+
+    /// Adds a redaction rule.
+    pub fn with_rule(mut self, rule: RedactionRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Sets the output format.
+    pub fn with_format(mut self, format: DatasetFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+/// Status of an export job.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportStatus {
+    /// Export is queued.
+    Pending,
+    /// Export is being generated.
+    Processing,
+    /// Export is ready to download.
+    Completed,
+    /// Export failed.
+    Failed,
+    /// Export has expired and can no longer be downloaded.
+    Expired,
+}
+
+/// Anonymized dataset export.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnonymizedExport {
+    /// Unique export identifier.
+    pub export_id: Uuid,
+    /// Workspace the export was created from.
+    pub workspace_id: Uuid,
+    /// Current status of the export.
+    pub status: ExportStatus,
+    /// Output format.
+    pub format: DatasetFormat,
+    /// Number of files included in the export.
+    pub file_count: i64,
+    /// Number of PII occurrences that were redacted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redaction_count: Option<i64>,
+    /// Size of the generated dataset in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<i64>,
+    /// Error message if the export failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Account that requested the export.
+    pub created_by: Uuid,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Completion timestamp.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Timestamp>,
+    /// Time after which the dataset can no longer be downloaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
@@ -4,18 +4,45 @@ use jiff::Timestamp;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::Cursor;
+
 /// Represents an uploaded file.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct File {
     /// Unique file identifier.
     pub file_id: Uuid,
+    /// Workspace the file belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<Uuid>,
+    /// Folder containing the file, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<Uuid>,
+    /// Client-supplied identifier correlating this file with an external system.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
     /// Display name.
     pub display_name: String,
+    /// Detected file format.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<FileFormat>,
+    /// MIME type reported at upload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
     /// File size in bytes.
     pub file_size: i64,
+    /// Hex-encoded SHA-256 checksum of the file content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
     /// Processing status.
-    pub status: ProcessingStatus,
+    pub status: FileStatus,
+    /// How a naming conflict was resolved when the file was uploaded, if
+    /// one occurred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub applied_conflict_strategy: Option<ConflictStrategy>,
+    /// Details of the most recent processing failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processing_error: Option<FileProcessingError>,
     /// How the file was created.
     pub source: FileSource,
     /// Classification tags.
@@ -24,6 +51,9 @@ pub struct File {
     pub processing_priority: i32,
     /// Knowledge extraction settings.
     pub file_knowledge: FileKnowledge,
+    /// Additional metadata attached at upload.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
     /// Account ID of the uploader.
     pub uploaded_by: Uuid,
     /// Creation timestamp.
@@ -33,9 +63,9 @@ pub struct File {
 }
 
 /// Processing status of a file.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
-pub enum ProcessingStatus {
+pub enum FileStatus {
     /// File is waiting to be processed.
     Pending,
     /// File is currently being processed.
@@ -46,6 +76,59 @@ pub enum ProcessingStatus {
     Failed,
 }
 
+impl FileStatus {
+    /// Returns `true` if processing has finished, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
+
+/// Former name of [`FileStatus`].
+#[deprecated(note = "renamed to `FileStatus`")]
+pub type ProcessingStatus = FileStatus;
+
+/// How an upload is handled when a file with the same name already exists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictStrategy {
+    /// Store the upload under a new, unique name.
+    Rename,
+    /// Replace the existing file.
+    Overwrite,
+    /// Add the upload as a new version of the existing file.
+    NewVersion,
+    /// Reject the upload with a conflict error.
+    Fail,
+}
+
+impl ConflictStrategy {
+    /// Returns the wire name of the strategy.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rename => "rename",
+            Self::Overwrite => "overwrite",
+            Self::NewVersion => "new_version",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+/// Details of a file processing failure.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileProcessingError {
+    /// Machine-readable error code.
+    pub code: String,
+    /// Human-readable error message.
+    pub message: String,
+    /// Whether reprocessing the file may succeed.
+    #[serde(default)]
+    pub retryable: bool,
+    /// Time the failure occurred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_at: Option<Timestamp>,
+}
+
 /// Source of the file.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -141,7 +224,7 @@ pub struct FilesPage {
     pub items: Vec<File>,
     /// Cursor for the next page.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_cursor: Option<String>,
+    pub next_cursor: Option<Cursor>,
     /// Whether there are more results.
     pub has_more: bool,
 }
@@ -152,6 +235,9 @@ pub struct FilesPage {
 pub struct DeleteFiles {
     /// List of file IDs to delete.
     pub file_ids: Vec<Uuid>,
+    /// Whether to keep deleting the remaining files after one fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub continue_on_error: Option<bool>,
 }
 
 /// Archive format for batch downloads.
@@ -177,3 +263,56 @@ pub struct DownloadFiles {
     #[serde(default)]
     pub format: ArchiveFormat,
 }
+
+/// Summary of the text extracted from a processed file.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtractionSummary {
+    /// Number of pages, for paged formats.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_count: Option<i32>,
+    /// Number of words extracted.
+    #[serde(default)]
+    pub word_count: i64,
+    /// Detected languages as ISO 639-1 codes, most prevalent first.
+    #[serde(default)]
+    pub languages: Vec<String>,
+}
+
+/// Summary of how a processed file was classified.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClassificationSummary {
+    /// Document category, if one was detected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Confidence of the category (0.0-1.0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+    /// Tags assigned by classification.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Extraction and classification results of a processed file.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileSummary {
+    /// Extraction results.
+    #[serde(default)]
+    pub extraction: ExtractionSummary,
+    /// Classification results.
+    #[serde(default)]
+    pub classification: ClassificationSummary,
+}
+
+/// File that finished processing, with its results.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessedFile {
+    /// The file.
+    pub file: File,
+    /// Extraction results.
+    pub extraction: ExtractionSummary,
+    /// Classification results.
+    pub classification: ClassificationSummary,
+}
@@ -0,0 +1,189 @@
+//! Client-side file encryption.
+//!
+//! Content is encrypted with a random data encryption key (DEK) generated for
+//! each file. The DEK is then wrapped with the caller's [`FileEncryptionKey`]
+//! and stored in the file metadata, so the server only ever holds ciphertext
+//! and a key it cannot unwrap.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use crypto_secretbox::aead::{Aead, AeadCore, KeyInit, OsRng};
+use crypto_secretbox::{Key, Nonce, XSalsa20Poly1305};
+use serde::{Deserialize, Serialize};
+
+use super::File;
+use crate::error::{Error, Result};
+
+/// Metadata field holding the [`EncryptionEnvelope`] of an encrypted file.
+pub const ENCRYPTION_METADATA_KEY: &str = "clientEncryption";
+
+/// Algorithm used for both content encryption and key wrapping.
+const ALGORITHM: &str = "xsalsa20poly1305";
+
+/// Length of the nonce prepended to every ciphertext.
+const NONCE_LEN: usize = 24;
+
+/// Caller-held key encryption key (KEK) for client-side file encryption.
+///
+/// The key never leaves the process. Losing it makes files encrypted with it
+/// unrecoverable.
+#[derive(Clone, PartialEq, Eq)]
+pub struct FileEncryptionKey {
+    key_id: String,
+    key: [u8; 32],
+}
+
+impl FileEncryptionKey {
+    /// Creates a key from 32 bytes of secret key material.
+    ///
+    /// The key ID is stored alongside each encrypted file so the right key
+    /// can be selected after a rotation.
+    pub fn new(key_id: impl Into<String>, key: [u8; 32]) -> Self {
+        Self {
+            key_id: key_id.into(),
+            key,
+        }
+    }
+
+    /// Returns the key identifier.
+    pub fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    /// Encrypts content under a fresh data key.
+    ///
+    /// Returns the ciphertext and the envelope to store in file metadata.
+    pub(crate) fn encrypt(&self, plaintext: &[u8]) -> Result<(Vec<u8>, EncryptionEnvelope)> {
+        let data_key = XSalsa20Poly1305::generate_key(&mut OsRng);
+        let ciphertext = seal(&data_key, plaintext)?;
+        let wrapped_key = seal(Key::from_slice(&self.key), &data_key)?;
+
+        let envelope = EncryptionEnvelope {
+            algorithm: ALGORITHM.to_string(),
+            key_id: self.key_id.clone(),
+            wrapped_key: STANDARD.encode(wrapped_key),
+        };
+        Ok((ciphertext, envelope))
+    }
+
+    /// Decrypts content encrypted with [`encrypt`](Self::encrypt).
+    pub(crate) fn decrypt(
+        &self,
+        envelope: &EncryptionEnvelope,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>> {
+        if envelope.algorithm != ALGORITHM {
+            return Err(Error::Encryption(format!(
+                "unsupported algorithm: {}",
+                envelope.algorithm
+            )));
+        }
+        if envelope.key_id != self.key_id {
+            return Err(Error::Encryption(format!(
+                "file was encrypted with key {}",
+                envelope.key_id
+            )));
+        }
+
+        let wrapped_key = STANDARD
+            .decode(&envelope.wrapped_key)
+            .map_err(|err| Error::Encryption(format!("invalid wrapped key encoding: {err}")))?;
+        let data_key = open(Key::from_slice(&self.key), &wrapped_key)?;
+        if data_key.len() != 32 {
+            return Err(Error::Encryption("invalid wrapped key length".to_string()));
+        }
+        open(Key::from_slice(&data_key), ciphertext)
+    }
+}
+
+impl std::fmt::Debug for FileEncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileEncryptionKey")
+            .field("key_id", &self.key_id)
+            .field("key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Wrapped data key stored in the metadata of a client-side encrypted file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionEnvelope {
+    /// Encryption algorithm.
+    pub algorithm: String,
+    /// Identifier of the key the data key was wrapped with.
+    pub key_id: String,
+    /// Base64-encoded data key, encrypted with the file encryption key.
+    pub wrapped_key: String,
+}
+
+impl File {
+    /// Returns the envelope of a file encrypted on the client, or `None` if
+    /// the file was uploaded unencrypted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Encryption`] if the metadata holds a malformed
+    /// envelope.
+    pub fn encryption_envelope(&self) -> Result<Option<EncryptionEnvelope>> {
+        let Some(value) = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.get(ENCRYPTION_METADATA_KEY))
+        else {
+            return Ok(None);
+        };
+        serde_json::from_value(value.clone())
+            .map(Some)
+            .map_err(|err| Error::Encryption(format!("invalid encryption envelope: {err}")))
+    }
+}
+
+/// Encrypts the plaintext, prepending the random nonce.
+fn seal(key: &Key, plaintext: &[u8]) -> Result<Vec<u8>> {
+    let nonce = XSalsa20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = XSalsa20Poly1305::new(key)
+        .encrypt(&nonce, plaintext)
+        .map_err(|_| Error::Encryption("failed to encrypt file content".to_string()))?;
+
+    let mut sealed = nonce.to_vec();
+    sealed.extend(ciphertext);
+    Ok(sealed)
+}
+
+/// Decrypts a ciphertext produced by [`seal`].
+fn open(key: &Key, sealed: &[u8]) -> Result<Vec<u8>> {
+    if sealed.len() < NONCE_LEN {
+        return Err(Error::Encryption("ciphertext is truncated".to_string()));
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    XSalsa20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| Error::Encryption("failed to decrypt file content".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_round_trip() -> Result<()> {
+        let key = FileEncryptionKey::new("kek-1", [7; 32]);
+        let (ciphertext, envelope) = key.encrypt(b"confidential")?;
+        assert_ne!(&ciphertext[NONCE_LEN..], b"confidential");
+        assert_eq!(envelope.key_id, "kek-1");
+        assert_eq!(key.decrypt(&envelope, &ciphertext)?, b"confidential");
+        Ok(())
+    }
+
+    #[test]
+    fn test_decrypt_rejects_wrong_key() -> Result<()> {
+        let (ciphertext, envelope) = FileEncryptionKey::new("kek-1", [7; 32]).encrypt(b"data")?;
+        let other = FileEncryptionKey::new("kek-1", [8; 32]);
+        assert!(matches!(
+            other.decrypt(&envelope, &ciphertext),
+            Err(Error::Encryption(_))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+//! File version models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Stored version of a file.
+///
+/// A version is added when content is uploaded with
+/// [`ConflictStrategy::NewVersion`](super::ConflictStrategy::NewVersion).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileVersion {
+    /// Version number, starting at 1.
+    pub version: u32,
+    /// Size of the content in bytes.
+    pub file_size: i64,
+    /// Hex-encoded SHA-256 checksum of the content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    /// Account that uploaded the version.
+    pub uploaded_by: Uuid,
+    /// Time the version was uploaded.
+    pub created_at: Timestamp,
+}
+
+/// Change of a metadata field between two versions.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    /// Name of the field, e.g. `displayName` or `metadata.caseNumber`.
+    pub field: String,
+    /// Value in the older version, absent if the field was added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<serde_json::Value>,
+    /// Value in the newer version, absent if the field was removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
+}
+
+/// Differences between two versions of a file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionDiff {
+    /// File the versions belong to.
+    pub file_id: Uuid,
+    /// Version compared from.
+    pub from_version: u32,
+    /// Version compared to.
+    pub to_version: u32,
+    /// Size of the newer content minus the size of the older, in bytes.
+    pub size_delta: i64,
+    /// Pages whose content differs, numbered as in the newer version.
+    #[serde(default)]
+    pub changed_pages: Vec<u32>,
+    /// Pages only present in the newer version.
+    #[serde(default)]
+    pub added_pages: Vec<u32>,
+    /// Pages only present in the older version, numbered as in it.
+    #[serde(default)]
+    pub removed_pages: Vec<u32>,
+    /// Metadata fields that differ.
+    #[serde(default)]
+    pub changed_fields: Vec<FieldChange>,
+}
+
+impl VersionDiff {
+    /// Returns `true` if the versions have the same content and metadata.
+    pub fn is_identical(&self) -> bool {
+        self.size_delta == 0
+            && self.changed_pages.is_empty()
+            && self.added_pages.is_empty()
+            && self.removed_pages.is_empty()
+            && self.changed_fields.is_empty()
+    }
+}
@@ -0,0 +1,88 @@
+//! Folder models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Cursor, File};
+
+/// Folder grouping files within a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Folder {
+    /// Unique folder identifier.
+    pub folder_id: Uuid,
+    /// Workspace the folder belongs to.
+    pub workspace_id: Uuid,
+    /// Parent folder, or `None` for folders at the workspace root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Uuid>,
+    /// Display name of the folder.
+    pub display_name: String,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
+
+/// Request body for creating a folder.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFolder {
+    /// Display name of the folder.
+    pub display_name: String,
+    /// Parent folder, or `None` to create the folder at the workspace root.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<Uuid>,
+}
+
+impl CreateFolder {
+    /// Creates a new folder request.
+    pub fn new(display_name: impl Into<String>) -> Self {
+        Self {
+            display_name: display_name.into(),
+            parent_id: None,
+        }
+    }
+
+    /// Sets the parent folder.
+    pub fn parent_id(mut self, parent_id: Uuid) -> Self {
+        self.parent_id = Some(parent_id);
+        self
+    }
+}
+
+/// Paginated direct contents of a folder.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FolderContentsPage {
+    /// Subfolders on this page.
+    #[serde(default)]
+    pub folders: Vec<Folder>,
+    /// Files on this page.
+    #[serde(default)]
+    pub files: Vec<File>,
+    /// Cursor for the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<Cursor>,
+    /// Whether there are more results.
+    pub has_more: bool,
+}
+
+/// Item found while walking a folder tree.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FolderItem {
+    /// A subfolder.
+    Folder(Folder),
+    /// A file.
+    File(Box<File>),
+}
+
+/// Entry yielded when walking a folder tree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FolderEntry {
+    /// Depth below the walked folder, starting at 1 for its direct contents.
+    pub depth: usize,
+    /// The folder or file.
+    pub item: FolderItem,
+}
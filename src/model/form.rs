@@ -0,0 +1,159 @@
+//! Form field models.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How the fields of a form were found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FormSource {
+    /// Interactive fields defined in the PDF (AcroForm).
+    AcroForm,
+    /// Fields detected on a scanned or flat document.
+    Detected,
+}
+
+/// Kind of input a form field accepts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FormFieldKind {
+    /// Free text.
+    Text,
+    /// A box that is either checked or not.
+    Checkbox,
+    /// One option out of a group.
+    Radio,
+    /// One option out of a list.
+    Choice,
+    /// A date.
+    Date,
+    /// A signature area.
+    Signature,
+    /// Kind not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Value of a form field.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FormValue {
+    /// State of a checkbox.
+    Checked(bool),
+    /// Text, date, or selected option.
+    Text(String),
+}
+
+impl From<bool> for FormValue {
+    fn from(checked: bool) -> Self {
+        Self::Checked(checked)
+    }
+}
+
+impl From<String> for FormValue {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&str> for FormValue {
+    fn from(text: &str) -> Self {
+        Self::Text(text.to_string())
+    }
+}
+
+/// Field of a form.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormField {
+    /// Name used to fill the field.
+    pub name: String,
+    /// Kind of input the field accepts.
+    pub kind: FormFieldKind,
+    /// Label printed next to the field, if found.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// Page the field is on, starting at 1.
+    pub page_number: u32,
+    /// Whether the form marks the field as required.
+    #[serde(default)]
+    pub required: bool,
+    /// Options of radio and choice fields.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<String>,
+    /// Current value, if the field is filled in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<FormValue>,
+    /// Confidence of detection (0.0-1.0), for detected fields.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confidence: Option<f64>,
+}
+
+/// Form fields of a file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FormFields {
+    /// File the fields belong to.
+    pub file_id: Uuid,
+    /// How the fields were found.
+    pub source: FormSource,
+    /// Fields, in page and reading order.
+    #[serde(default)]
+    pub fields: Vec<FormField>,
+}
+
+impl FormFields {
+    /// Returns the field with the given name, if any.
+    pub fn field(&self, name: &str) -> Option<&FormField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns the required fields that are not filled in.
+    pub fn missing_required(&self) -> impl Iterator<Item = &FormField> {
+        self.fields
+            .iter()
+            .filter(|field| field.required && field.value.is_none())
+    }
+}
+
+/// Request body for filling a form.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FillForm {
+    /// Values by field name.
+    pub values: BTreeMap<String, FormValue>,
+    /// Whether the fields are flattened into the page content, so the
+    /// completed copy can no longer be edited.
+    #[serde(default)]
+    pub flatten: bool,
+    /// Name of the completed copy. Generated by the server if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+}
+
+impl FillForm {
+    /// Creates an empty request.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the value of a field.
+    pub fn with_value(mut self, name: impl Into<String>, value: impl Into<FormValue>) -> Self {
+        self.values.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets whether the fields are flattened.
+    pub fn with_flatten(mut self, flatten: bool) -> Self {
+        self.flatten = flatten;
+        self
+    }
+
+    /// Sets the name of the completed copy.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+}
@@ -0,0 +1,160 @@
+//! Guest (external collaborator) models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Cursor;
+
+/// Resource a guest has been granted access to.
+///
+/// Guests are scoped to a single file or folder and never gain
+/// workspace-wide access.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GuestScope {
+    /// Access to a single file.
+    #[serde(rename_all = "camelCase")]
+    File {
+        /// The file the guest can access.
+        file_id: Uuid,
+    },
+    /// Access to a folder and everything inside it.
+    #[serde(rename_all = "camelCase")]
+    Folder {
+        /// The folder the guest can access.
+        folder_id: Uuid,
+    },
+}
+
+/// Permission level granted to a guest.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuestPermission {
+    /// Can only view content.
+    #[default]
+    View,
+    /// Can view content and add comments.
+    Comment,
+    /// Can view, comment, and download content.
+    Download,
+}
+
+/// Lifecycle status of a guest invitation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuestStatus {
+    /// Invitation sent but not yet accepted.
+    Pending,
+    /// Guest has accepted and can access the scoped resource.
+    Active,
+    /// Access has passed its expiry time.
+    Expired,
+    /// Access was revoked by a workspace member.
+    Revoked,
+}
+
+/// External guest with scoped access to a workspace resource.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Guest {
+    /// Unique guest identifier.
+    pub guest_id: Uuid,
+    /// Workspace the scoped resource belongs to.
+    pub workspace_id: Uuid,
+    /// Email address the invitation was sent to.
+    pub email: String,
+    /// Display name of the guest, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Resource the guest can access.
+    pub scope: GuestScope,
+    /// Permission level on the scoped resource.
+    pub permission: GuestPermission,
+    /// Current status of the guest.
+    pub status: GuestStatus,
+    /// Time after which access is no longer granted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    /// Account ID of the member who invited the guest.
+    pub invited_by: Uuid,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
+
+/// Request body for inviting a guest.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteGuest {
+    /// Email address to send the invitation to.
+    pub email: String,
+    /// Resource the guest can access.
+    pub scope: GuestScope,
+    /// Permission level on the scoped resource.
+    #[serde(default)]
+    pub permission: GuestPermission,
+    /// Time after which access is no longer granted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    /// Optional message included in the invitation email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl InviteGuest {
+    /// Creates a new guest invitation with view-only access.
+    pub fn new(email: impl Into<String>, scope: GuestScope) -> Self {
+        Self {
+            email: email.into(),
+            scope,
+            permission: GuestPermission::default(),
+            expires_at: None,
+            message: None,
+        }
+    }
+
+    /// Sets the permission level.
+    pub fn with_permission(mut self, permission: GuestPermission) -> Self {
+        self.permission = permission;
+        self
+    }
+
+    /// Sets the expiry time.
+    pub fn with_expires_at(mut self, expires_at: Timestamp) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Sets the invitation message.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// Request body for updating a guest.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateGuest {
+    /// New permission level.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<GuestPermission>,
+    /// New expiry time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+}
+
+/// Paginated list of guests.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestsPage {
+    /// List of guests.
+    pub items: Vec<Guest>,
+    /// Cursor for the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<Cursor>,
+    /// Whether there are more results.
+    pub has_more: bool,
+}
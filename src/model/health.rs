@@ -15,6 +15,41 @@ pub enum ServiceStatus {
     Unhealthy,
 }
 
+/// Reason the system reports a degraded or unhealthy status.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DegradedReason {
+    /// The primary database is unavailable or slow.
+    DatabaseUnavailable,
+    /// Object storage is unavailable or slow.
+    StorageUnavailable,
+    /// The processing queue is backed up.
+    ProcessingBacklog,
+    /// Response latency is above normal levels.
+    HighLatency,
+    /// Scheduled maintenance is in progress.
+    Maintenance,
+    /// Reason not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Health of an individual system component.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentStatus {
+    /// Component name (e.g. `database`, `storage`).
+    pub name: String,
+    /// Component health status.
+    pub status: ServiceStatus,
+    /// Latency of the component check in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<i64>,
+    /// Human-readable detail about the component's state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
 /// System monitoring status response.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,6 +60,12 @@ pub struct MonitorStatus {
     pub status: ServiceStatus,
     /// Application version.
     pub version: String,
+    /// Health of individual system components.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<ComponentStatus>,
+    /// Reasons for a degraded or unhealthy status.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub degraded_reasons: Vec<DegradedReason>,
 }
 
 /// Request payload for monitoring status endpoint.
@@ -0,0 +1,120 @@
+//! Email ingestion models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Inbound email address of a workspace.
+///
+/// Attachments of messages sent to the address are uploaded as files.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestEmail {
+    /// Workspace the address delivers into.
+    pub workspace_id: Uuid,
+    /// The inbound email address.
+    pub address: String,
+    /// Whether incoming messages are accepted.
+    pub enabled: bool,
+    /// Senders allowed to deliver, as full addresses or `@domain` entries.
+    /// Empty if any sender is accepted.
+    #[serde(default)]
+    pub allowed_senders: Vec<String>,
+    /// Folder attachments are placed in. Defaults to the workspace root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<Uuid>,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Time the address was last rotated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotated_at: Option<Timestamp>,
+}
+
+impl IngestEmail {
+    /// Returns `true` if messages from `sender` are accepted.
+    ///
+    /// Addresses and domains are compared case-insensitively.
+    pub fn allows_sender(&self, sender: &str) -> bool {
+        if self.allowed_senders.is_empty() {
+            return true;
+        }
+
+        let sender = sender.trim().to_ascii_lowercase();
+        self.allowed_senders.iter().any(|allowed| {
+            let allowed = allowed.trim().to_ascii_lowercase();
+            if allowed.starts_with('@') {
+                sender.ends_with(&allowed)
+            } else {
+                sender == allowed
+            }
+        })
+    }
+}
+
+/// Request body for updating the inbound email settings of a workspace.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateIngestEmail {
+    /// Whether incoming messages are accepted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Senders allowed to deliver. An empty list accepts any sender.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_senders: Option<Vec<String>>,
+    /// Folder attachments are placed in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder_id: Option<Uuid>,
+}
+
+impl UpdateIngestEmail {
+    /// Creates an empty update.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether incoming messages are accepted.
+    pub fn with_enabled(mut self, enabled: bool) -> Self {
+        self.enabled = Some(enabled);
+        self
+    }
+
+    /// Sets the senders allowed to deliver.
+    pub fn with_allowed_senders<I, S>(mut self, senders: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_senders = Some(senders.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the folder attachments are placed in.
+    pub fn with_folder_id(mut self, folder_id: Uuid) -> Self {
+        self.folder_id = Some(folder_id);
+        self
+    }
+}
+
+/// Payload of a [`WebhookEvent::EmailIngested`](super::WebhookEvent::EmailIngested) event.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailIngestedPayload {
+    /// Workspace the message was delivered into.
+    pub workspace_id: Uuid,
+    /// Address the message was sent to.
+    pub address: String,
+    /// Address of the sender.
+    pub sender: String,
+    /// Subject of the message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Files created from the attachments.
+    #[serde(default)]
+    pub file_ids: Vec<Uuid>,
+    /// Number of attachments that were skipped, for example because their
+    /// format is not supported.
+    #[serde(default)]
+    pub skipped_attachments: u32,
+    /// Time the message was received.
+    pub occurred_at: Timestamp,
+}
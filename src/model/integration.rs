@@ -1,9 +1,13 @@
 //! Integration models.
 
+use std::time::Duration;
+
 use jiff::Timestamp;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::Cursor;
+
 /// Defines the functional category of a workspace integration.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -36,6 +40,20 @@ pub enum IntegrationStatus {
     Cancelled,
 }
 
+/// Defines how a bidirectional sync resolves items that changed on both sides.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncConflictPolicy {
+    /// Overwrite the Nvisy copy with the remote version.
+    PreferRemote,
+    /// Overwrite the remote copy with the Nvisy version.
+    PreferNvisy,
+    /// Keep both versions by creating a renamed duplicate.
+    Duplicate,
+    /// Leave both versions unchanged and report the conflict.
+    Skip,
+}
+
 /// Workspace integration response.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -44,6 +62,9 @@ pub struct Integration {
     pub integration_id: Uuid,
     /// Reference to the workspace this integration belongs to.
     pub workspace_id: Uuid,
+    /// Client-supplied identifier correlating this resource with an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
     /// Human-readable name for the integration.
     pub integration_name: String,
     /// Detailed description of the integration's purpose and functionality.
@@ -58,6 +79,12 @@ pub struct Integration {
     /// Timestamp of the most recent successful synchronization.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_sync_at: Option<Timestamp>,
+    /// How sync conflicts are resolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflict_policy: Option<SyncConflictPolicy>,
+    /// Time the OAuth grant or access token expires, if it does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credentials_expires_at: Option<Timestamp>,
     /// Account that originally created this integration.
     pub created_by: Uuid,
     /// Timestamp when this integration was first created.
@@ -66,6 +93,18 @@ pub struct Integration {
     pub updated_at: Timestamp,
 }
 
+impl Integration {
+    /// Returns `true` if the credentials expire within the given duration.
+    ///
+    /// Credentials that do not expire never do.
+    pub fn credentials_expire_within(&self, duration: Duration) -> bool {
+        self.credentials_expires_at.is_some_and(|expires_at| {
+            let remaining = expires_at.duration_since(Timestamp::now());
+            Duration::try_from(remaining).unwrap_or_default() <= duration
+        })
+    }
+}
+
 /// Paginated list of integrations.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -74,7 +113,7 @@ pub struct IntegrationsPage {
     pub items: Vec<Integration>,
     /// Cursor to fetch the next page.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_cursor: Option<String>,
+    pub next_cursor: Option<Cursor>,
     /// Total count of items matching the query.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total: Option<i64>,
@@ -86,6 +125,9 @@ pub struct IntegrationsPage {
 pub struct CreateIntegration {
     /// Human-readable name for the integration (1-100 characters).
     pub integration_name: String,
+    /// Client-supplied identifier correlating this resource with an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
     /// Detailed description of the integration's purpose (1-500 characters).
     pub description: String,
     /// Type of third-party service this integration connects to.
@@ -93,6 +135,12 @@ pub struct CreateIntegration {
     /// Integration credentials.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<serde_json::Value>,
+    /// Integration credentials encrypted with the tenant's public key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encrypted_credentials: Option<EncryptedCredentials>,
+    /// How sync conflicts are resolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflict_policy: Option<SyncConflictPolicy>,
     /// Whether the integration should be active immediately upon creation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_active: Option<bool>,
@@ -110,20 +158,60 @@ impl CreateIntegration {
     ) -> Self {
         Self {
             integration_name: integration_name.into(),
+            external_id: None,
             description: description.into(),
             integration_type,
             credentials: None,
+            encrypted_credentials: None,
+            conflict_policy: None,
             is_active: None,
             metadata: None,
         }
     }
 
+    /// Sets the external identifier.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
     /// Sets the credentials.
     pub fn credentials(mut self, credentials: serde_json::Value) -> Self {
         self.credentials = Some(credentials);
         self
     }
 
+    /// Encrypts the credentials with the tenant's public key and sets them.
+    ///
+    /// The credentials are sealed on the client so that they never transit
+    /// in plaintext; only the Nvisy API holds the matching private key. Fetch
+    /// the key with [`IntegrationsService::get_credentials_public_key`].
+    ///
+    /// [`IntegrationsService::get_credentials_public_key`]: crate::service::IntegrationsService::get_credentials_public_key
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Encryption`](crate::Error::Encryption) if the public
+    /// key is malformed or encryption fails.
+    #[cfg(feature = "encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encryption")))]
+    pub fn credentials_encrypted(
+        mut self,
+        credentials: &serde_json::Value,
+        key: &CredentialsPublicKey,
+    ) -> crate::Result<Self> {
+        let plaintext = serde_json::to_vec(credentials)?;
+        self.credentials = None;
+        self.encrypted_credentials = Some(key.seal(&plaintext)?);
+        Ok(self)
+    }
+
+    /// Sets how sync conflicts are resolved.
+    pub fn conflict_policy(mut self, conflict_policy: SyncConflictPolicy) -> Self {
+        self.conflict_policy = Some(conflict_policy);
+        self
+    }
+
     /// Sets whether the integration should be active immediately.
     pub fn is_active(mut self, is_active: bool) -> Self {
         self.is_active = Some(is_active);
@@ -137,6 +225,52 @@ impl CreateIntegration {
     }
 }
 
+/// Public key used to encrypt integration credentials on the client.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialsPublicKey {
+    /// Identifier of the key, echoed back with encrypted credentials.
+    pub key_id: String,
+    /// Base64-encoded X25519 public key.
+    pub public_key: String,
+}
+
+impl CredentialsPublicKey {
+    /// Encrypts the plaintext into a sealed box addressed to this key.
+    #[cfg(feature = "encryption")]
+    fn seal(&self, plaintext: &[u8]) -> crate::Result<EncryptedCredentials> {
+        use base64::Engine;
+        use base64::engine::general_purpose::STANDARD;
+        use crypto_box::aead::OsRng;
+
+        use crate::Error;
+
+        let bytes = STANDARD
+            .decode(&self.public_key)
+            .map_err(|err| Error::Encryption(format!("invalid public key encoding: {err}")))?;
+        let public_key = crypto_box::PublicKey::from_slice(&bytes)
+            .map_err(|_| Error::Encryption("invalid public key length".to_string()))?;
+        let ciphertext = public_key
+            .seal(&mut OsRng, plaintext)
+            .map_err(|_| Error::Encryption("failed to seal credentials".to_string()))?;
+
+        Ok(EncryptedCredentials {
+            key_id: self.key_id.clone(),
+            ciphertext: STANDARD.encode(ciphertext),
+        })
+    }
+}
+
+/// Integration credentials sealed with a [`CredentialsPublicKey`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedCredentials {
+    /// Identifier of the key the credentials were sealed with.
+    pub key_id: String,
+    /// Base64-encoded sealed box containing the JSON credentials.
+    pub ciphertext: String,
+}
+
 /// Request payload for updating an existing workspace integration.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -153,6 +287,9 @@ pub struct UpdateIntegration {
     /// Updated credentials.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub credentials: Option<serde_json::Value>,
+    /// Updated conflict policy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflict_policy: Option<SyncConflictPolicy>,
     /// Updated active status.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_active: Option<bool>,
@@ -160,3 +297,153 @@ pub struct UpdateIntegration {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
 }
+
+/// Outcome of a single sync conflict.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncConflictOutcome {
+    /// The remote version replaced the Nvisy version.
+    KeptRemote,
+    /// The Nvisy version replaced the remote version.
+    KeptNvisy,
+    /// Both versions were kept under different names.
+    Duplicated,
+    /// The item was left unchanged on both sides.
+    Skipped,
+}
+
+/// Item that changed on both sides during a sync run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncConflict {
+    /// Path of the item in the remote storage.
+    pub remote_path: String,
+    /// Nvisy file the item maps to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<Uuid>,
+    /// How the conflict was resolved.
+    pub outcome: SyncConflictOutcome,
+    /// Name given to the duplicate, when the conflict was duplicated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_name: Option<String>,
+}
+
+/// Result of a single integration sync run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationSyncRun {
+    /// Unique sync run identifier.
+    pub run_id: Uuid,
+    /// Integration the run belongs to.
+    pub integration_id: Uuid,
+    /// Current status of the run.
+    pub status: IntegrationStatus,
+    /// Conflict policy in effect for the run.
+    pub conflict_policy: SyncConflictPolicy,
+    /// Number of items synchronized without conflict.
+    pub items_synced: i64,
+    /// Conflicts encountered and how each was resolved.
+    pub conflicts: Vec<SyncConflict>,
+    /// Time the run started.
+    pub started_at: Timestamp,
+    /// Time the run finished.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<Timestamp>,
+}
+
+/// Connectivity of an integration to its remote service.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrationConnectivity {
+    /// The remote service is reachable and accepts the credentials.
+    Connected,
+    /// The remote service is reachable but some requests fail.
+    Degraded,
+    /// The remote service is unreachable or rejects the credentials.
+    Disconnected,
+}
+
+/// Health summary of an integration.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationHealth {
+    /// Integration the summary describes.
+    pub integration_id: Uuid,
+    /// Connectivity to the remote service.
+    pub connectivity: IntegrationConnectivity,
+    /// Time the connectivity was last checked.
+    pub checked_at: Timestamp,
+    /// Time the access token or OAuth grant expires, if it does.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_expires_at: Option<Timestamp>,
+    /// Time of the last sync run that finished without error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_successful_sync_at: Option<Timestamp>,
+    /// Number of items waiting to be synchronized.
+    #[serde(default)]
+    pub queued_items: i64,
+    /// Most recent error reported by the remote service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+}
+
+impl IntegrationHealth {
+    /// Returns how long until the token expires, or `None` if it does not
+    /// expire. Expired tokens report zero.
+    pub fn token_expires_in(&self) -> Option<Duration> {
+        let expires_at = self.token_expires_at?;
+        let remaining = expires_at.duration_since(Timestamp::now());
+        Some(Duration::try_from(remaining).unwrap_or_default())
+    }
+
+    /// Returns `true` if the token expires within the given duration.
+    pub fn token_expires_within(&self, duration: Duration) -> bool {
+        self.token_expires_in()
+            .is_some_and(|remaining| remaining <= duration)
+    }
+}
+
+#[cfg(all(test, feature = "encryption"))]
+mod tests {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use crypto_box::SecretKey;
+    use crypto_box::aead::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn test_credentials_encrypted_round_trip() -> crate::Result<()> {
+        let secret_key = SecretKey::generate(&mut OsRng);
+        let key = CredentialsPublicKey {
+            key_id: "key-1".to_string(),
+            public_key: STANDARD.encode(secret_key.public_key().as_bytes()),
+        };
+        let credentials = serde_json::json!({ "token": "secret" });
+
+        let request = CreateIntegration::new("Drive", "Shared drive", IntegrationType::Storage)
+            .credentials_encrypted(&credentials, &key)?;
+        assert!(request.credentials.is_none());
+
+        let encrypted = request.encrypted_credentials.unwrap();
+        assert_eq!(encrypted.key_id, "key-1");
+        let ciphertext = STANDARD.decode(encrypted.ciphertext).unwrap();
+        let plaintext = secret_key.unseal(&ciphertext).unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&plaintext)?,
+            credentials
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_credentials_encrypted_rejects_bad_key() {
+        let key = CredentialsPublicKey {
+            key_id: "key-1".to_string(),
+            public_key: STANDARD.encode([0u8; 8]),
+        };
+        let result = CreateIntegration::new("Drive", "Shared drive", IntegrationType::Storage)
+            .credentials_encrypted(&serde_json::json!({}), &key);
+        assert!(matches!(result, Err(crate::Error::Encryption(_))));
+    }
+}
@@ -0,0 +1,91 @@
+//! Processing job models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::Cursor;
+
+/// Lifecycle state of a processing job.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    /// Waiting for a worker.
+    Queued,
+    /// Being processed.
+    Running,
+    /// Finished successfully.
+    Succeeded,
+    /// Finished with an error.
+    Failed,
+    /// Cancelled before finishing.
+    Cancelled,
+}
+
+impl JobState {
+    /// Returns `true` if the job will not change state again on its own.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// Details of why a job failed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobError {
+    /// Machine-readable error code.
+    pub code: String,
+    /// Human-readable error message.
+    pub message: String,
+    /// Whether retrying the job may succeed.
+    #[serde(default)]
+    pub retryable: bool,
+}
+
+/// Asynchronous processing job, such as extracting or classifying a file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Job {
+    /// Unique job identifier.
+    pub job_id: Uuid,
+    /// Workspace the job runs in.
+    pub workspace_id: Uuid,
+    /// File being processed, if the job concerns a single file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<Uuid>,
+    /// Kind of work performed, e.g. `extraction`.
+    pub job_type: String,
+    /// Current state.
+    pub state: JobState,
+    /// Completion percentage, from 0 to 100.
+    pub progress: u8,
+    /// Failure details, if the job failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<JobError>,
+    /// Number of times the job has been attempted.
+    #[serde(default)]
+    pub attempts: u32,
+    /// Time a worker started the job.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<Timestamp>,
+    /// Time the job reached a terminal state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<Timestamp>,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
+
+/// Paginated list of jobs.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobsPage {
+    /// List of jobs.
+    pub items: Vec<Job>,
+    /// Cursor for the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<Cursor>,
+    /// Whether there are more results.
+    pub has_more: bool,
+}
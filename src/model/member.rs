@@ -0,0 +1,138 @@
+//! Workspace member models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{Cursor, WorkspaceRole};
+
+/// Account with access to a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Member {
+    /// Account identifier.
+    pub account_id: Uuid,
+    /// Workspace the member belongs to.
+    pub workspace_id: Uuid,
+    /// Display name of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Email address of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+    /// Role in the workspace.
+    pub role: WorkspaceRole,
+    /// Time the member joined the workspace.
+    pub joined_at: Timestamp,
+    /// Time the member last made a request in the workspace, or `None` if
+    /// they never have.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_active_at: Option<Timestamp>,
+}
+
+/// Request body for inviting an account to a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteMember {
+    /// Email address to send the invitation to.
+    pub email_address: String,
+    /// Role granted once the invitation is accepted.
+    pub role: WorkspaceRole,
+    /// Optional message included in the invitation email.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl InviteMember {
+    /// Creates a new member invitation.
+    pub fn new(email_address: impl Into<String>, role: WorkspaceRole) -> Self {
+        Self {
+            email_address: email_address.into(),
+            role,
+            message: None,
+        }
+    }
+
+    /// Sets the invitation message.
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+/// Request body for updating a workspace member.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMember {
+    /// New role in the workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<WorkspaceRole>,
+}
+
+/// Paginated list of workspace members.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MembersPage {
+    /// List of members.
+    pub items: Vec<Member>,
+    /// Cursor for the next page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<Cursor>,
+    /// Whether there are more results.
+    pub has_more: bool,
+}
+
+/// Shareable link for joining a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteLink {
+    /// Unique link identifier.
+    pub invite_link_id: Uuid,
+    /// Workspace the link grants access to.
+    pub workspace_id: Uuid,
+    /// URL to share with invitees.
+    pub url: String,
+    /// Role granted to accounts joining through the link.
+    pub role: WorkspaceRole,
+    /// Time after which the link stops working.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    /// Email domains allowed to join, or empty if any domain may join.
+    #[serde(default)]
+    pub allowed_domains: Vec<String>,
+    /// Maximum number of accounts that can join, if limited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u32>,
+    /// Number of accounts that have joined through the link.
+    #[serde(default)]
+    pub use_count: u32,
+    /// Account ID of the member who created the link.
+    pub created_by: Uuid,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+}
+
+impl InviteLink {
+    /// Returns `true` if the link has expired or reached its maximum uses.
+    pub fn is_exhausted(&self) -> bool {
+        self.expires_at.is_some_and(|at| at <= Timestamp::now())
+            || self.max_uses.is_some_and(|max| self.use_count >= max)
+    }
+}
+
+/// Request body for creating an invite link.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInviteLink {
+    /// Role granted to accounts joining through the link.
+    pub role: WorkspaceRole,
+    /// Time after which the link stops working.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<Timestamp>,
+    /// Email domains allowed to join.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_domains: Vec<String>,
+    /// Maximum number of accounts that can join.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u32>,
+}
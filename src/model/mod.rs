@@ -1,13 +1,82 @@
 //! Data models for the Nvisy API.
 
+mod alert;
+mod automation;
+mod barcode;
+mod batch;
+mod bundle;
+mod capabilities;
+mod capture_session;
+mod comment;
+mod cursor;
+#[cfg(feature = "display")]
+mod display;
+mod event;
+mod export;
 mod file;
+#[cfg(feature = "client-encryption")]
+mod file_encryption;
+mod file_version;
+mod folder;
+mod form;
+mod guest;
 mod health;
+mod ingest_email;
 mod integration;
+mod job;
+mod member;
+mod multipart_upload;
+mod notification_route;
+mod ocr;
+mod security;
+mod signed_url;
+mod snapshot;
+mod upload_transaction;
 mod webhook;
+mod webhook_payload;
 mod workspace;
+mod workspace_comparison;
+mod workspace_config;
+mod workspace_merge;
+mod workspace_stats;
 
+pub use alert::*;
+pub use automation::*;
+pub use barcode::*;
+pub use batch::*;
+pub use bundle::*;
+pub use capabilities::*;
+pub use capture_session::*;
+pub use comment::*;
+pub use cursor::*;
+#[cfg(feature = "display")]
+pub use display::*;
+pub use event::*;
+pub use export::*;
 pub use file::*;
+#[cfg(feature = "client-encryption")]
+#[cfg_attr(docsrs, doc(cfg(feature = "client-encryption")))]
+pub use file_encryption::*;
+pub use file_version::*;
+pub use folder::*;
+pub use form::*;
+pub use guest::*;
 pub use health::*;
+pub use ingest_email::*;
 pub use integration::*;
+pub use job::*;
+pub use member::*;
+pub use multipart_upload::*;
+pub use notification_route::*;
+pub use ocr::*;
+pub use security::*;
+pub use signed_url::*;
+pub use snapshot::*;
+pub use upload_transaction::*;
 pub use webhook::*;
+pub use webhook_payload::*;
 pub use workspace::*;
+pub use workspace_comparison::*;
+pub use workspace_config::*;
+pub use workspace_merge::*;
+pub use workspace_stats::*;
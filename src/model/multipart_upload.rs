@@ -0,0 +1,60 @@
+//! Chunked upload models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Server-side state of a file uploaded in parts.
+///
+/// Parts can be uploaded in any order and retried individually. The file
+/// only appears in the workspace once the upload is completed. Incomplete
+/// uploads are discarded when they expire.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MultipartUpload {
+    /// Unique upload identifier.
+    pub upload_id: Uuid,
+    /// Workspace the file is uploaded to.
+    pub workspace_id: Uuid,
+    /// The file name.
+    pub file_name: String,
+    /// Time after which the upload is discarded if not completed.
+    pub expires_at: Timestamp,
+}
+
+/// Request body for starting a [`MultipartUpload`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMultipartUpload {
+    /// The file name.
+    pub file_name: String,
+}
+
+/// Part received by the server as part of a [`MultipartUpload`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadedPart {
+    /// Position of the part in the file, starting at 1.
+    pub part_number: u32,
+    /// Size of the part in bytes.
+    pub size: u64,
+    /// Hex-encoded SHA-256 checksum of the part content as received.
+    pub checksum: String,
+}
+
+/// Request body for completing a [`MultipartUpload`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompleteMultipartUpload {
+    /// All parts of the file, ordered by part number.
+    pub parts: Vec<UploadedPart>,
+}
+
+/// Progress of a chunked upload, reported after each part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UploadProgress {
+    /// Number of parts uploaded so far.
+    pub parts_uploaded: u32,
+    /// Number of bytes uploaded so far.
+    pub bytes_uploaded: u64,
+}
@@ -0,0 +1,86 @@
+//! Notification route models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::WebhookEvent;
+
+/// Route posting workspace events to a chat channel.
+///
+/// Routes bind a communication integration (such as Slack or Teams) to the
+/// workspace's notification routing.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationRoute {
+    /// Unique route identifier.
+    pub route_id: Uuid,
+    /// Workspace the route belongs to.
+    pub workspace_id: Uuid,
+    /// Communication integration used to post notifications.
+    pub integration_id: Uuid,
+    /// Channel identifier in the chat service (e.g. `#legal-review`).
+    pub channel: String,
+    /// Events posted to the channel.
+    pub events: Vec<WebhookEvent>,
+    /// Whether the route is active.
+    pub is_active: bool,
+    /// Account that created this route.
+    pub created_by: Uuid,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
+
+/// Request payload for creating a notification route.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateNotificationRoute {
+    /// Communication integration used to post notifications.
+    pub integration_id: Uuid,
+    /// Channel identifier in the chat service.
+    pub channel: String,
+    /// Events posted to the channel.
+    pub events: Vec<WebhookEvent>,
+    /// Whether the route should be active immediately.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
+
+impl CreateNotificationRoute {
+    /// Creates a new notification route request.
+    pub fn new(
+        integration_id: Uuid,
+        channel: impl Into<String>,
+        events: Vec<WebhookEvent>,
+    ) -> Self {
+        Self {
+            integration_id,
+            channel: channel.into(),
+            events,
+            is_active: None,
+        }
+    }
+
+    /// Sets whether the route should be active immediately.
+    pub fn is_active(mut self, is_active: bool) -> Self {
+        self.is_active = Some(is_active);
+        self
+    }
+}
+
+/// Request payload for updating a notification route.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateNotificationRoute {
+    /// Updated channel identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    /// Updated list of events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<WebhookEvent>>,
+    /// Updated active status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+}
@@ -0,0 +1,126 @@
+//! OCR quality and reprocessing models.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// OCR engine used to recognize text in scanned pages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OcrEngine {
+    /// Fast general-purpose engine, used by default.
+    Standard,
+    /// Slower engine with better results on degraded scans.
+    Enhanced,
+    /// Engine tuned for handwritten text.
+    Handwriting,
+    /// Engine not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Quality problem detected on a scanned page.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OcrWarning {
+    /// The scan resolution is too low for reliable recognition.
+    LowDpi,
+    /// The page is noticeably rotated.
+    Skewed,
+    /// Recognized text has low confidence.
+    LowConfidence,
+    /// No text was found on the page.
+    Blank,
+    /// Warning not known to this SDK version.
+    #[serde(other)]
+    Unknown,
+}
+
+/// OCR quality of a single page.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrPage {
+    /// Page number, starting at 1.
+    pub page_number: u32,
+    /// Mean confidence of the recognized text (0.0-1.0).
+    pub confidence: f64,
+    /// Detected rotation of the page in degrees.
+    #[serde(default)]
+    pub skew_degrees: f64,
+    /// Detected scan resolution, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dpi: Option<u32>,
+    /// Quality problems detected on the page.
+    #[serde(default)]
+    pub warnings: Vec<OcrWarning>,
+}
+
+/// OCR quality report of a processed file.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrReport {
+    /// File the report describes.
+    pub file_id: Uuid,
+    /// Engine that produced the text.
+    pub engine: OcrEngine,
+    /// Mean confidence across all pages (0.0-1.0).
+    pub confidence: f64,
+    /// Per-page quality, in page order.
+    #[serde(default)]
+    pub pages: Vec<OcrPage>,
+}
+
+impl OcrReport {
+    /// Returns the pages whose confidence is below `threshold`.
+    pub fn pages_below(&self, threshold: f64) -> impl Iterator<Item = &OcrPage> {
+        self.pages
+            .iter()
+            .filter(move |page| page.confidence < threshold)
+    }
+
+    /// Returns `true` if any page has quality warnings.
+    pub fn has_warnings(&self) -> bool {
+        self.pages.iter().any(|page| !page.warnings.is_empty())
+    }
+}
+
+/// Settings for running OCR on a file again.
+///
+/// Unset fields keep the settings of the previous run.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OcrOptions {
+    /// Expected languages as ISO 639-1 codes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub language_hints: Vec<String>,
+    /// Engine to use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub engine: Option<OcrEngine>,
+    /// Resolution pages are rasterized at before recognition.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dpi: Option<u32>,
+}
+
+impl OcrOptions {
+    /// Creates options keeping the previous settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an expected language.
+    pub fn with_language_hint(mut self, language: impl Into<String>) -> Self {
+        self.language_hints.push(language.into());
+        self
+    }
+
+    /// Sets the engine.
+    pub fn with_engine(mut self, engine: OcrEngine) -> Self {
+        self.engine = Some(engine);
+        self
+    }
+
+    /// Sets the rasterization resolution.
+    pub fn with_dpi(mut self, dpi: u32) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+}
@@ -0,0 +1,120 @@
+//! Organization security policy models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::WorkspaceRole;
+
+/// Organization-level security policy.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityPolicy {
+    /// Whether requests are restricted to the IP allowlist.
+    pub ip_allowlist_enabled: bool,
+    /// CIDR ranges allowed to access the organization.
+    pub ip_allowlist: Vec<IpAllowlistEntry>,
+    /// Maximum lifetime of issued session and API tokens, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_max_ttl_secs: Option<i64>,
+    /// Idle session timeout, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_idle_timeout_secs: Option<i64>,
+    /// Per-role download restrictions.
+    pub download_restrictions: Vec<DownloadRestriction>,
+    /// Last update timestamp.
+    pub updated_at: Timestamp,
+}
+
+/// Single entry in the IP allowlist.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IpAllowlistEntry {
+    /// Unique entry identifier.
+    pub entry_id: Uuid,
+    /// Allowed address range in CIDR notation (e.g. `10.0.0.0/8`).
+    pub cidr: String,
+    /// Optional human-readable label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// Download restriction applied to a workspace role.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadRestriction {
+    /// Role the restriction applies to.
+    pub role: WorkspaceRole,
+    /// Whether members with this role may download file content.
+    pub allow_download: bool,
+    /// Whether members with this role may download batch archives.
+    pub allow_batch_download: bool,
+}
+
+impl DownloadRestriction {
+    /// Creates a restriction that allows all downloads for the role.
+    pub fn new(role: WorkspaceRole) -> Self {
+        Self {
+            role,
+            allow_download: true,
+            allow_batch_download: true,
+        }
+    }
+
+    /// Sets whether single-file downloads are allowed.
+    pub fn with_download(mut self, allowed: bool) -> Self {
+        self.allow_download = allowed;
+        self
+    }
+
+    /// Sets whether batch archive downloads are allowed.
+    pub fn with_batch_download(mut self, allowed: bool) -> Self {
+        self.allow_batch_download = allowed;
+        self
+    }
+}
+
+/// Request body for updating the security policy.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSecurityPolicy {
+    /// Whether requests are restricted to the IP allowlist.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_allowlist_enabled: Option<bool>,
+    /// Maximum lifetime of issued tokens, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_max_ttl_secs: Option<i64>,
+    /// Idle session timeout, in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_idle_timeout_secs: Option<i64>,
+    /// Replacement set of per-role download restrictions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_restrictions: Option<Vec<DownloadRestriction>>,
+}
+
+/// Request body for adding an IP allowlist entry.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateIpAllowlistEntry {
+    /// Allowed address range in CIDR notation.
+    pub cidr: String,
+    /// Optional human-readable label.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl CreateIpAllowlistEntry {
+    /// Creates a new allowlist entry request.
+    pub fn new(cidr: impl Into<String>) -> Self {
+        Self {
+            cidr: cidr.into(),
+            label: None,
+        }
+    }
+
+    /// Sets the label.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
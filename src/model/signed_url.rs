@@ -0,0 +1,65 @@
+//! Presigned URL models.
+
+use std::time::Duration;
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::service::FilesService;
+
+/// Time-limited URL granting direct access to a file's content.
+///
+/// The URL can be handed to consumers that do not hold an API key, such as
+/// media players or browsers. It remembers the file it was issued for, so
+/// long-running consumers can renew access with [`refresh`](Self::refresh).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignedUrl {
+    /// File the URL grants access to.
+    pub file_id: Uuid,
+    /// The presigned URL.
+    pub url: String,
+    /// Time after which the URL stops working.
+    pub expires_at: Timestamp,
+}
+
+impl SignedUrl {
+    /// Returns `true` if the URL has expired.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Timestamp::now()
+    }
+
+    /// Returns how long the URL remains valid, or zero if it has expired.
+    pub fn remaining(&self) -> Duration {
+        let remaining = self.expires_at.duration_since(Timestamp::now());
+        Duration::try_from(remaining).unwrap_or_default()
+    }
+
+    /// Returns `true` if the URL expires within the given duration.
+    pub fn expires_within(&self, duration: Duration) -> bool {
+        self.remaining() <= duration
+    }
+
+    /// Replaces this URL with a newly issued one for the same file.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client used to request the new URL
+    /// * `ttl` - Lifetime of the new URL; the server default applies if not set
+    pub async fn refresh(&mut self, client: &NvisyClient, ttl: Option<Duration>) -> Result<()> {
+        *self = client.create_signed_url(self.file_id, ttl).await?;
+        Ok(())
+    }
+}
+
+/// Request body for creating a presigned URL.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSignedUrl {
+    /// Lifetime of the URL in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in_secs: Option<u64>,
+}
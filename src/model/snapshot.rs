@@ -0,0 +1,78 @@
+//! Workspace snapshot models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Status of a snapshot or restore job.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SnapshotStatus {
+    /// Job is queued.
+    Pending,
+    /// Job is running.
+    Running,
+    /// Job finished successfully.
+    Completed,
+    /// Job failed.
+    Failed,
+}
+
+impl SnapshotStatus {
+    /// Returns `true` if the job has finished, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
+
+/// Point-in-time capture of a workspace's files, metadata, and settings.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSnapshot {
+    /// Unique snapshot identifier.
+    pub snapshot_id: Uuid,
+    /// Workspace the snapshot was taken of.
+    pub workspace_id: Uuid,
+    /// Current status of the snapshot job.
+    pub status: SnapshotStatus,
+    /// Number of files captured.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_count: Option<i64>,
+    /// Total size of captured content in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<i64>,
+    /// Error message if the snapshot failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Account that requested the snapshot.
+    pub created_by: Uuid,
+    /// Time the snapshot was requested.
+    pub created_at: Timestamp,
+    /// Time the snapshot finished.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Timestamp>,
+}
+
+/// Job restoring a workspace to a snapshot.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotRestore {
+    /// Unique restore job identifier.
+    pub restore_id: Uuid,
+    /// Snapshot being restored.
+    pub snapshot_id: Uuid,
+    /// Workspace being restored.
+    pub workspace_id: Uuid,
+    /// Current status of the restore job.
+    pub status: SnapshotStatus,
+    /// Error message if the restore failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Account that requested the restore.
+    pub created_by: Uuid,
+    /// Time the restore was requested.
+    pub created_at: Timestamp,
+    /// Time the restore finished.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Timestamp>,
+}
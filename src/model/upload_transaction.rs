@@ -0,0 +1,35 @@
+//! Transactional upload models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Server-side staging area for uploading several files atomically.
+///
+/// Files staged in a transaction only become visible in the workspace once
+/// the transaction is committed. Uncommitted transactions are discarded
+/// when they expire.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadTransaction {
+    /// Unique transaction identifier.
+    pub transaction_id: Uuid,
+    /// Workspace the files are uploaded to.
+    pub workspace_id: Uuid,
+    /// Time after which the transaction is discarded if not committed.
+    pub expires_at: Timestamp,
+    /// Creation timestamp.
+    pub created_at: Timestamp,
+}
+
+/// File staged in an [`UploadTransaction`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StagedFile {
+    /// Unique identifier of the staged file.
+    pub staged_file_id: Uuid,
+    /// The file name.
+    pub file_name: String,
+    /// File size in bytes.
+    pub file_size: i64,
+}
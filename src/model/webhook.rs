@@ -6,6 +6,8 @@ use jiff::Timestamp;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::Cursor;
+
 /// Defines the types of events that can trigger webhook delivery.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -38,6 +40,8 @@ pub enum WebhookEvent {
     IntegrationSynced,
     /// An integration was desynchronized.
     IntegrationDesynced,
+    /// Attachments of an email were ingested as files.
+    EmailIngested,
 }
 
 /// Defines the operational status of a workspace webhook.
@@ -62,6 +66,41 @@ pub enum WebhookType {
     Integration,
 }
 
+/// Shape of the payload delivered to a webhook endpoint.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookPayloadFormat {
+    /// Each event includes the full resource representation.
+    #[default]
+    Full,
+    /// Each event includes only the event type and resource ID.
+    Thin,
+}
+
+/// Batching configuration for webhook deliveries.
+///
+/// Events are accumulated and delivered in a single POST once either
+/// `max_events` events are pending or `max_wait_secs` seconds have elapsed
+/// since the first pending event, whichever comes first.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookBatching {
+    /// Maximum number of events per delivery.
+    pub max_events: u32,
+    /// Maximum time to hold events before delivering, in seconds.
+    pub max_wait_secs: u32,
+}
+
+impl WebhookBatching {
+    /// Creates a new batching configuration.
+    pub fn new(max_events: u32, max_wait_secs: u32) -> Self {
+        Self {
+            max_events,
+            max_wait_secs,
+        }
+    }
+}
+
 /// Workspace webhook response.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -70,6 +109,9 @@ pub struct Webhook {
     pub webhook_id: Uuid,
     /// Reference to the workspace this webhook belongs to.
     pub workspace_id: Uuid,
+    /// Client-supplied identifier correlating this resource with an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
     /// Human-readable name for the webhook.
     pub display_name: String,
     /// Detailed description of the webhook's purpose.
@@ -82,6 +124,12 @@ pub struct Webhook {
     pub headers: HashMap<String, String>,
     /// Current status of the webhook.
     pub status: WebhookStatus,
+    /// Payload format of deliveries.
+    #[serde(default)]
+    pub payload_format: WebhookPayloadFormat,
+    /// Batching configuration, if deliveries are batched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batching: Option<WebhookBatching>,
     /// Origin type of the webhook.
     pub webhook_type: WebhookType,
     /// Reference to integration (present for integration type webhooks).
@@ -106,7 +154,7 @@ pub struct WebhooksPage {
     pub items: Vec<Webhook>,
     /// Cursor to fetch the next page.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_cursor: Option<String>,
+    pub next_cursor: Option<Cursor>,
     /// Total count of items matching the query.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total: Option<i64>,
@@ -118,6 +166,9 @@ pub struct WebhooksPage {
 pub struct CreateWebhook {
     /// Human-readable name for the webhook (1-100 characters).
     pub display_name: String,
+    /// Client-supplied identifier correlating this resource with an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
     /// Detailed description of the webhook's purpose (max 500 characters).
     pub description: String,
     /// The URL to send webhook payloads to.
@@ -130,6 +181,12 @@ pub struct CreateWebhook {
     /// Initial status for the webhook.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<WebhookStatus>,
+    /// Payload format of deliveries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_format: Option<WebhookPayloadFormat>,
+    /// Batching configuration. Events are delivered individually if unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batching: Option<WebhookBatching>,
 }
 
 impl CreateWebhook {
@@ -142,14 +199,23 @@ impl CreateWebhook {
     ) -> Self {
         Self {
             display_name: display_name.into(),
+            external_id: None,
             description: description.into(),
             url: url.into(),
             events,
             headers: None,
             status: None,
+            payload_format: None,
+            batching: None,
         }
     }
 
+    /// Sets the external identifier.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
     /// Sets custom headers.
     pub fn headers(mut self, headers: HashMap<String, String>) -> Self {
         self.headers = Some(headers);
@@ -161,6 +227,18 @@ impl CreateWebhook {
         self.status = Some(status);
         self
     }
+
+    /// Sets the payload format.
+    pub fn payload_format(mut self, payload_format: WebhookPayloadFormat) -> Self {
+        self.payload_format = Some(payload_format);
+        self
+    }
+
+    /// Enables batched deliveries.
+    pub fn batching(mut self, batching: WebhookBatching) -> Self {
+        self.batching = Some(batching);
+        self
+    }
 }
 
 /// Request payload for updating an existing workspace webhook.
@@ -185,6 +263,12 @@ pub struct UpdateWebhook {
     /// Updated status.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<WebhookStatus>,
+    /// Updated payload format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_format: Option<WebhookPayloadFormat>,
+    /// Updated batching configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batching: Option<WebhookBatching>,
 }
 
 /// Request payload for testing a webhook.
@@ -194,6 +278,19 @@ pub struct TestWebhook {
     /// Custom payload to send in the test.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub payload: Option<serde_json::Value>,
+    /// Event type to simulate. A sample payload for this event is generated
+    /// unless a custom payload is provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<WebhookEvent>,
+    /// Number of test deliveries to send (defaults to 1).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+    /// Payload sizes in bytes, applied to deliveries in order.
+    ///
+    /// Generated payloads are padded to reach each size, which helps
+    /// exercise receiver body-size limits.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub payload_sizes: Vec<u32>,
 }
 
 impl TestWebhook {
@@ -207,6 +304,24 @@ impl TestWebhook {
         self.payload = Some(payload);
         self
     }
+
+    /// Sets the event type to simulate.
+    pub fn event(mut self, event: WebhookEvent) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// Sets the number of test deliveries to send.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets the payload sizes for successive deliveries.
+    pub fn payload_sizes(mut self, payload_sizes: Vec<u32>) -> Self {
+        self.payload_sizes = payload_sizes;
+        self
+    }
 }
 
 /// Result of a webhook delivery attempt.
@@ -217,4 +332,35 @@ pub struct WebhookResult {
     pub status_code: i32,
     /// Time taken to receive a response in milliseconds.
     pub response_time_ms: i64,
+    /// Full request/response dump for every test delivery sent.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deliveries: Vec<WebhookDeliveryDump>,
+}
+
+/// Request and response details of a single test delivery.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDeliveryDump {
+    /// Sequence number of the delivery, starting at 1.
+    pub sequence: u32,
+    /// Event type that was simulated.
+    pub event: WebhookEvent,
+    /// Headers sent to the webhook endpoint.
+    pub request_headers: HashMap<String, String>,
+    /// Body sent to the webhook endpoint.
+    pub request_body: String,
+    /// HTTP status code returned by the endpoint, if a response was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<i32>,
+    /// Headers returned by the endpoint.
+    #[serde(default)]
+    pub response_headers: HashMap<String, String>,
+    /// Body returned by the endpoint, truncated by the server if large.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_body: Option<String>,
+    /// Time taken to receive a response in milliseconds.
+    pub response_time_ms: i64,
+    /// Transport error, if the endpoint could not be reached.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
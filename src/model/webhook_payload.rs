@@ -0,0 +1,282 @@
+//! Typed webhook payload models.
+//!
+//! These types describe the `data` delivered with webhook events, so
+//! receivers can act on them without calling back into the API.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{EmailIngestedPayload, File, Integration, WebhookEvent, WorkspaceRole};
+use crate::error::Result;
+
+/// Account that performed the action behind a webhook event.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookActor {
+    /// Account identifier.
+    pub account_id: Uuid,
+    /// Display name of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /// Email address of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+}
+
+/// Payload of a [`WebhookEvent::MemberAdded`](super::WebhookEvent::MemberAdded) event.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberAddedPayload {
+    /// Workspace the member was added to.
+    pub workspace_id: Uuid,
+    /// Account of the added member.
+    pub account_id: Uuid,
+    /// Role granted to the member.
+    pub role: WorkspaceRole,
+    /// Account that added the member, absent for system actions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<WebhookActor>,
+    /// Time the member was added.
+    pub occurred_at: Timestamp,
+}
+
+/// Payload of a [`WebhookEvent::MemberUpdated`](super::WebhookEvent::MemberUpdated) event.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberUpdatedPayload {
+    /// Workspace the member belongs to.
+    pub workspace_id: Uuid,
+    /// Account of the updated member.
+    pub account_id: Uuid,
+    /// Role before the update, present when the role changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_role: Option<WorkspaceRole>,
+    /// Role after the update.
+    pub role: WorkspaceRole,
+    /// Account that updated the member, absent for system actions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<WebhookActor>,
+    /// Time the member was updated.
+    pub occurred_at: Timestamp,
+}
+
+impl MemberUpdatedPayload {
+    /// Returns `true` if the update changed the member's role.
+    pub fn is_role_change(&self) -> bool {
+        self.previous_role
+            .as_ref()
+            .is_some_and(|previous| *previous != self.role)
+    }
+}
+
+/// Payload of a [`WebhookEvent::MemberDeleted`](super::WebhookEvent::MemberDeleted) event.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberDeletedPayload {
+    /// Workspace the member was removed from.
+    pub workspace_id: Uuid,
+    /// Account of the removed member.
+    pub account_id: Uuid,
+    /// Role the member held before removal.
+    pub role: WorkspaceRole,
+    /// Account that removed the member, absent for system actions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<WebhookActor>,
+    /// Time the member was removed.
+    pub occurred_at: Timestamp,
+}
+
+/// Typed payload of a webhook delivery, keyed by its [`WebhookEvent`].
+///
+/// Deliveries are JSON objects holding the event name in `event` and its
+/// data in `data`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(
+    tag = "event",
+    content = "data",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+pub enum WebhookPayload {
+    /// A new document was created.
+    DocumentCreated {
+        /// The created document.
+        document: File,
+    },
+    /// A document was updated.
+    DocumentUpdated {
+        /// The document after the update.
+        document: File,
+    },
+    /// A document was deleted.
+    DocumentDeleted {
+        /// Identifier of the deleted document.
+        document_id: Uuid,
+        /// Workspace the document belonged to.
+        workspace_id: Uuid,
+    },
+    /// A new file was created.
+    FileCreated {
+        /// The created file.
+        file: File,
+    },
+    /// A file was updated.
+    FileUpdated {
+        /// The file after the update.
+        file: File,
+    },
+    /// A file was deleted.
+    FileDeleted {
+        /// Identifier of the deleted file.
+        file_id: Uuid,
+        /// Workspace the file belonged to.
+        workspace_id: Uuid,
+    },
+    /// A member was added to the workspace.
+    MemberAdded(MemberAddedPayload),
+    /// A member was deleted from the workspace.
+    MemberDeleted(MemberDeletedPayload),
+    /// A member's details were updated.
+    MemberUpdated(MemberUpdatedPayload),
+    /// An integration was created.
+    IntegrationCreated {
+        /// The created integration.
+        integration: Integration,
+    },
+    /// An integration was updated.
+    IntegrationUpdated {
+        /// The integration after the update.
+        integration: Integration,
+    },
+    /// An integration was deleted.
+    IntegrationDeleted {
+        /// Identifier of the deleted integration.
+        integration_id: Uuid,
+        /// Workspace the integration belonged to.
+        workspace_id: Uuid,
+    },
+    /// An integration was synchronized.
+    IntegrationSynced {
+        /// The synchronized integration.
+        integration: Integration,
+    },
+    /// An integration was desynchronized.
+    IntegrationDesynced {
+        /// The desynchronized integration.
+        integration: Integration,
+    },
+    /// Attachments of an email were ingested as files.
+    EmailIngested(EmailIngestedPayload),
+}
+
+impl WebhookPayload {
+    /// Parses the body of a webhook delivery.
+    ///
+    /// The body is not authenticated; use
+    /// [`WebhookVerifier::parse`](crate::webhook::WebhookVerifier::parse) for
+    /// requests received over the network.
+    pub fn parse(body: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    /// Builds the typed payload from an event and its raw data, as stored in
+    /// the [`Event`](super::Event) log.
+    pub fn from_parts(event: WebhookEvent, data: serde_json::Value) -> Result<Self> {
+        let envelope = serde_json::json!({ "event": event, "data": data });
+        Ok(serde_json::from_value(envelope)?)
+    }
+
+    /// Returns the event the payload was delivered for.
+    pub fn event(&self) -> WebhookEvent {
+        match self {
+            Self::DocumentCreated { .. } => WebhookEvent::DocumentCreated,
+            Self::DocumentUpdated { .. } => WebhookEvent::DocumentUpdated,
+            Self::DocumentDeleted { .. } => WebhookEvent::DocumentDeleted,
+            Self::FileCreated { .. } => WebhookEvent::FileCreated,
+            Self::FileUpdated { .. } => WebhookEvent::FileUpdated,
+            Self::FileDeleted { .. } => WebhookEvent::FileDeleted,
+            Self::MemberAdded(_) => WebhookEvent::MemberAdded,
+            Self::MemberDeleted(_) => WebhookEvent::MemberDeleted,
+            Self::MemberUpdated(_) => WebhookEvent::MemberUpdated,
+            Self::IntegrationCreated { .. } => WebhookEvent::IntegrationCreated,
+            Self::IntegrationUpdated { .. } => WebhookEvent::IntegrationUpdated,
+            Self::IntegrationDeleted { .. } => WebhookEvent::IntegrationDeleted,
+            Self::IntegrationSynced { .. } => WebhookEvent::IntegrationSynced,
+            Self::IntegrationDesynced { .. } => WebhookEvent::IntegrationDesynced,
+            Self::EmailIngested(_) => WebhookEvent::EmailIngested,
+        }
+    }
+
+    /// Returns the workspace the event occurred in, if the payload names it.
+    pub fn workspace_id(&self) -> Option<Uuid> {
+        match self {
+            Self::DocumentDeleted { workspace_id, .. }
+            | Self::FileDeleted { workspace_id, .. }
+            | Self::IntegrationDeleted { workspace_id, .. } => Some(*workspace_id),
+            Self::MemberAdded(payload) => Some(payload.workspace_id),
+            Self::MemberDeleted(payload) => Some(payload.workspace_id),
+            Self::MemberUpdated(payload) => Some(payload.workspace_id),
+            Self::EmailIngested(payload) => Some(payload.workspace_id),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_struct_variant() -> Result<()> {
+        let payload = WebhookPayload::parse(
+            br#"{"event":"file_deleted","data":{
+                "fileId":"00000000-0000-0000-0000-000000000001",
+                "workspaceId":"00000000-0000-0000-0000-000000000002"}}"#,
+        )?;
+
+        assert_eq!(payload.event(), WebhookEvent::FileDeleted);
+        assert_eq!(payload.workspace_id(), Some(Uuid::from_u128(2)),);
+        let round_trip = serde_json::to_vec(&payload)?;
+        assert!(matches!(
+            WebhookPayload::parse(&round_trip)?,
+            WebhookPayload::FileDeleted { file_id, .. } if file_id == Uuid::from_u128(1)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_parts_matches_event() -> Result<()> {
+        let payload = WebhookPayload::from_parts(
+            WebhookEvent::MemberDeleted,
+            serde_json::json!({
+                "workspaceId": "00000000-0000-0000-0000-000000000001",
+                "accountId": "00000000-0000-0000-0000-000000000002",
+                "role": "viewer",
+                "occurredAt": "2024-01-01T00:00:00Z"
+            }),
+        )?;
+        assert!(matches!(payload, WebhookPayload::MemberDeleted(_)));
+        assert!(WebhookPayload::parse(br#"{"event":"file_deleted","data":{}}"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_email_ingested() -> Result<()> {
+        let payload = WebhookPayload::parse(
+            br#"{"event":"email_ingested","data":{
+                "workspaceId":"00000000-0000-0000-0000-000000000002",
+                "address":"legal-7f3k@in.nvisy.com","sender":"clerk@courts.gov",
+                "fileIds":["00000000-0000-0000-0000-000000000003"],
+                "occurredAt":"2024-01-01T00:00:00Z"}}"#,
+        )?;
+
+        assert_eq!(payload.event(), WebhookEvent::EmailIngested);
+        assert_eq!(payload.workspace_id(), Some(Uuid::from_u128(2)));
+        assert!(matches!(
+            payload,
+            WebhookPayload::EmailIngested(email) if email.file_ids.len() == 1
+        ));
+        Ok(())
+    }
+}
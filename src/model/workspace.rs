@@ -4,12 +4,17 @@ use jiff::Timestamp;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::Cursor;
+
 /// Represents a workspace.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Workspace {
     /// Unique workspace identifier.
     pub workspace_id: Uuid,
+    /// Client-supplied identifier correlating this resource with an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
     /// Display name of the workspace.
     pub display_name: String,
     /// Description of the workspace.
@@ -29,6 +34,28 @@ pub struct Workspace {
     pub created_at: Timestamp,
     /// Last update timestamp.
     pub updated_at: Timestamp,
+    /// Number of resources in the workspace, present when requested with
+    /// [`ListWorkspacesOptions::include_counts`](crate::service::ListWorkspacesOptions::include_counts).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counts: Option<WorkspaceCounts>,
+}
+
+/// Number of resources in a workspace.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceCounts {
+    /// Files in the workspace.
+    #[serde(default)]
+    pub files: u64,
+    /// Members of the workspace.
+    #[serde(default)]
+    pub members: u64,
+    /// Webhooks of the workspace.
+    #[serde(default)]
+    pub webhooks: u64,
+    /// Integrations of the workspace.
+    #[serde(default)]
+    pub integrations: u64,
 }
 
 /// Role of a member in a workspace.
@@ -51,6 +78,9 @@ pub enum WorkspaceRole {
 pub struct CreateWorkspace {
     /// Display name of the workspace.
     pub display_name: String,
+    /// Client-supplied identifier correlating this resource with an external system.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
     /// Description of the workspace.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
@@ -74,6 +104,7 @@ impl CreateWorkspace {
     pub fn new(display_name: impl Into<String>) -> Self {
         Self {
             display_name: display_name.into(),
+            external_id: None,
             description: None,
             tags: Vec::new(),
             enable_comments: true,
@@ -81,6 +112,12 @@ impl CreateWorkspace {
         }
     }
 
+    /// Sets the external identifier.
+    pub fn with_external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
     /// Sets the description.
     pub fn with_description(mut self, description: impl Into<String>) -> Self {
         self.description = Some(description.into());
@@ -135,7 +172,7 @@ pub struct WorkspacesPage {
     pub items: Vec<Workspace>,
     /// Cursor for the next page.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub next_cursor: Option<String>,
+    pub next_cursor: Option<Cursor>,
     /// Whether there are more results.
     pub has_more: bool,
 }
@@ -184,3 +221,89 @@ pub struct UpdateNotificationSettings {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub events: Option<Vec<NotificationEvent>>,
 }
+
+/// Data residency and encryption information for a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceDataInfo {
+    /// Storage region where workspace data is stored (e.g. `eu-west-1`).
+    pub storage_region: String,
+    /// Encryption-at-rest configuration.
+    pub encryption: EncryptionInfo,
+    /// Data retention guarantees.
+    pub retention: RetentionInfo,
+}
+
+/// Encryption-at-rest configuration for a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptionInfo {
+    /// Type of key used to encrypt data at rest.
+    pub key_type: EncryptionKeyType,
+    /// Identifier of the customer-managed key, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    /// Timestamp of the most recent key rotation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_rotated_at: Option<Timestamp>,
+}
+
+/// Type of encryption key protecting workspace data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncryptionKeyType {
+    /// Key managed by the Nvisy platform.
+    Platform,
+    /// Key managed by the customer in their own KMS.
+    CustomerManaged,
+}
+
+/// Data retention guarantees for a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionInfo {
+    /// Days soft-deleted data is kept before permanent removal.
+    pub soft_delete_retention_days: i32,
+    /// Days backups are kept.
+    pub backup_retention_days: i32,
+    /// Whether a legal hold currently prevents deletion.
+    pub legal_hold: bool,
+}
+
+/// Preview of the next notification digest for a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDigest {
+    /// Whether a digest would be sent under the current settings.
+    pub will_send: bool,
+    /// Time the next digest is scheduled to be sent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<Timestamp>,
+    /// Subject line of the digest email.
+    pub subject: String,
+    /// Entries that would be included, grouped by event type.
+    pub sections: Vec<NotificationDigestSection>,
+}
+
+/// Group of digest entries sharing an event type.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDigestSection {
+    /// Event type of the entries.
+    pub event: NotificationEvent,
+    /// Entries in this section.
+    pub items: Vec<NotificationDigestItem>,
+}
+
+/// Single entry in a notification digest.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationDigestItem {
+    /// Short summary of the activity.
+    pub summary: String,
+    /// Resource the activity relates to, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<Uuid>,
+    /// Time the activity occurred.
+    pub occurred_at: Timestamp,
+}
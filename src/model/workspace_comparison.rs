@@ -0,0 +1,260 @@
+//! Workspace comparison models.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::{File, WorkspaceConfig, WorkspaceRole};
+
+/// Differences between two workspaces.
+///
+/// Files are matched by checksum when both have one, and by display name
+/// otherwise. The two workspaces are referred to as `a` and `b`, in the order
+/// they were passed to the comparison.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceComparison {
+    /// Identifier of the first workspace.
+    pub workspace_a: Uuid,
+    /// Identifier of the second workspace.
+    pub workspace_b: Uuid,
+    /// Files in the first workspace with no match in the second.
+    pub only_in_a: Vec<File>,
+    /// Files in the second workspace with no match in the first.
+    pub only_in_b: Vec<File>,
+    /// Settings that differ between the workspaces.
+    pub settings: Vec<SettingDifference>,
+    /// Members whose role differs or who belong to only one workspace.
+    pub members: Vec<MemberDifference>,
+}
+
+impl WorkspaceComparison {
+    /// Compares two workspaces from their files and exported configurations.
+    pub(crate) fn new(
+        (workspace_a, files_a, config_a): (Uuid, Vec<File>, WorkspaceConfig),
+        (workspace_b, files_b, config_b): (Uuid, Vec<File>, WorkspaceConfig),
+    ) -> Self {
+        let only_in_a = unmatched_files(&files_a, &files_b);
+        let only_in_b = unmatched_files(&files_b, &files_a);
+
+        let mut settings = Vec::new();
+        diff_settings(
+            "",
+            serde_json::to_value(&config_a.settings).unwrap_or_default(),
+            serde_json::to_value(&config_b.settings).unwrap_or_default(),
+            &mut settings,
+        );
+        diff_settings(
+            "notifications.",
+            serde_json::to_value(&config_a.notifications).unwrap_or_default(),
+            serde_json::to_value(&config_b.notifications).unwrap_or_default(),
+            &mut settings,
+        );
+
+        let mut members: Vec<MemberDifference> = config_a
+            .roles
+            .iter()
+            .map(|a| MemberDifference {
+                account_id: a.account_id,
+                role_a: Some(a.role.clone()),
+                role_b: config_b
+                    .roles
+                    .iter()
+                    .find(|b| b.account_id == a.account_id)
+                    .map(|b| b.role.clone()),
+            })
+            .chain(
+                config_b
+                    .roles
+                    .iter()
+                    .filter(|b| !config_a.roles.iter().any(|a| a.account_id == b.account_id))
+                    .map(|b| MemberDifference {
+                        account_id: b.account_id,
+                        role_a: None,
+                        role_b: Some(b.role.clone()),
+                    }),
+            )
+            .filter(|member| member.role_a != member.role_b)
+            .collect();
+        members.sort_by_key(|member| member.account_id);
+
+        Self {
+            workspace_a,
+            workspace_b,
+            only_in_a,
+            only_in_b,
+            settings,
+            members,
+        }
+    }
+
+    /// Returns `true` if no differences were found.
+    pub fn is_identical(&self) -> bool {
+        self.only_in_a.is_empty()
+            && self.only_in_b.is_empty()
+            && self.settings.is_empty()
+            && self.members.is_empty()
+    }
+}
+
+/// Setting with different values in two workspaces.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingDifference {
+    /// Name of the setting, as in the workspace configuration document
+    /// (e.g. `requireApproval` or `notifications.emailEnabled`).
+    pub setting: String,
+    /// Value in the first workspace, or null if unset.
+    pub value_a: Value,
+    /// Value in the second workspace, or null if unset.
+    pub value_b: Value,
+}
+
+/// Member whose access differs between two workspaces.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberDifference {
+    /// Account identifier of the member.
+    pub account_id: Uuid,
+    /// Role in the first workspace, if a member.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_a: Option<WorkspaceRole>,
+    /// Role in the second workspace, if a member.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_b: Option<WorkspaceRole>,
+}
+
+/// Returns `true` if two files are considered the same document.
+fn same_file(a: &File, b: &File) -> bool {
+    match (&a.checksum, &b.checksum) {
+        (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+        _ => a.display_name == b.display_name,
+    }
+}
+
+/// Returns the files in `files` with no match in `others`.
+fn unmatched_files(files: &[File], others: &[File]) -> Vec<File> {
+    files
+        .iter()
+        .filter(|file| !others.iter().any(|other| same_file(file, other)))
+        .cloned()
+        .collect()
+}
+
+/// Records the top-level fields of two JSON objects that differ.
+fn diff_settings(prefix: &str, a: Value, b: Value, differences: &mut Vec<SettingDifference>) {
+    let a = match a {
+        Value::Object(map) => map,
+        _ => Default::default(),
+    };
+    let mut b = match b {
+        Value::Object(map) => map,
+        _ => Default::default(),
+    };
+
+    for (key, value_a) in a {
+        let value_b = b.remove(&key).unwrap_or_default();
+        if value_a != value_b {
+            differences.push(SettingDifference {
+                setting: format!("{prefix}{key}"),
+                value_a,
+                value_b,
+            });
+        }
+    }
+    for (key, value_b) in b {
+        differences.push(SettingDifference {
+            setting: format!("{prefix}{key}"),
+            value_a: Value::Null,
+            value_b,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{RoleConfig, WORKSPACE_CONFIG_VERSION, WorkspaceSettingsConfig};
+
+    fn file(name: &str, checksum: Option<&str>) -> File {
+        let json = serde_json::json!({
+            "fileId": Uuid::new_v4(),
+            "displayName": name,
+            "fileSize": 1,
+            "checksum": checksum,
+            "status": "completed",
+            "source": "uploaded",
+            "tags": [],
+            "processingPriority": 5,
+            "fileKnowledge": {},
+            "uploadedBy": Uuid::nil(),
+            "createdAt": "2024-01-01T00:00:00Z",
+            "updatedAt": "2024-01-01T00:00:00Z",
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn config(require_approval: bool, roles: Vec<RoleConfig>) -> WorkspaceConfig {
+        WorkspaceConfig {
+            version: WORKSPACE_CONFIG_VERSION,
+            settings: WorkspaceSettingsConfig {
+                display_name: "Legal".to_string(),
+                description: None,
+                tags: Vec::new(),
+                enable_comments: true,
+                require_approval,
+            },
+            notifications: None,
+            webhooks: Vec::new(),
+            integrations: Vec::new(),
+            roles,
+            folders: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_compare_workspaces() {
+        let a = Uuid::from_u128(1);
+        let b = Uuid::from_u128(2);
+        let files_a = vec![
+            file("contract.pdf", Some("aa")),
+            file("renamed.pdf", Some("bb")),
+            file("draft.docx", None),
+        ];
+        let files_b = vec![
+            file("contract-copy.pdf", Some("AA")),
+            file("notes.txt", None),
+        ];
+        let roles_a = vec![
+            RoleConfig {
+                account_id: a,
+                role: WorkspaceRole::Owner,
+            },
+            RoleConfig {
+                account_id: b,
+                role: WorkspaceRole::Editor,
+            },
+        ];
+        let roles_b = vec![RoleConfig {
+            account_id: a,
+            role: WorkspaceRole::Owner,
+        }];
+
+        let comparison = WorkspaceComparison::new(
+            (a, files_a, config(false, roles_a)),
+            (b, files_b, config(true, roles_b)),
+        );
+
+        let names = |files: &[File]| -> Vec<String> {
+            files.iter().map(|file| file.display_name.clone()).collect()
+        };
+        assert_eq!(names(&comparison.only_in_a), ["renamed.pdf", "draft.docx"]);
+        assert_eq!(names(&comparison.only_in_b), ["notes.txt"]);
+        assert_eq!(comparison.settings.len(), 1);
+        assert_eq!(comparison.settings[0].setting, "requireApproval");
+        assert_eq!(comparison.members.len(), 1);
+        assert_eq!(comparison.members[0].account_id, b);
+        assert_eq!(comparison.members[0].role_b, None);
+        assert!(!comparison.is_identical());
+    }
+}
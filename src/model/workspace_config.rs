@@ -0,0 +1,180 @@
+//! Declarative workspace configuration models.
+//!
+//! A [`WorkspaceConfig`] describes a workspace's setup as a single document
+//! that can be exported, stored in version control, diffed, and applied to
+//! the same or another workspace. Secrets such as integration credentials
+//! are never included.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{
+    IntegrationType, NotificationSettings, SyncConflictPolicy, WebhookBatching, WebhookEvent,
+    WebhookPayloadFormat, WebhookStatus, WorkspaceRole,
+};
+
+/// Current version of the workspace configuration document format.
+pub const WORKSPACE_CONFIG_VERSION: u32 = 1;
+
+/// Declarative description of a workspace's setup.
+///
+/// Maps are ordered, so serializing the same configuration always produces
+/// the same document.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceConfig {
+    /// Version of the document format.
+    pub version: u32,
+    /// General workspace settings.
+    pub settings: WorkspaceSettingsConfig,
+    /// Notification settings.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<NotificationSettings>,
+    /// Webhooks, identified by display name.
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+    /// Integrations without credentials, identified by name.
+    #[serde(default)]
+    pub integrations: Vec<IntegrationConfig>,
+    /// Member role assignments.
+    #[serde(default)]
+    pub roles: Vec<RoleConfig>,
+    /// Folder paths, relative to the workspace root.
+    #[serde(default)]
+    pub folders: Vec<String>,
+}
+
+/// General settings in a [`WorkspaceConfig`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSettingsConfig {
+    /// Display name of the workspace.
+    pub display_name: String,
+    /// Description of the workspace.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Tags associated with the workspace.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Whether comments are enabled.
+    pub enable_comments: bool,
+    /// Whether approval is required for processed files.
+    pub require_approval: bool,
+}
+
+/// Webhook entry in a [`WorkspaceConfig`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookConfig {
+    /// Human-readable name, used to match existing webhooks.
+    pub display_name: String,
+    /// Description of the webhook's purpose.
+    pub description: String,
+    /// The URL to send webhook payloads to.
+    pub url: String,
+    /// Event types the webhook receives.
+    pub events: Vec<WebhookEvent>,
+    /// Custom headers included in webhook requests.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Operational status.
+    pub status: WebhookStatus,
+    /// Payload format of deliveries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_format: Option<WebhookPayloadFormat>,
+    /// Batching configuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batching: Option<WebhookBatching>,
+}
+
+/// Integration entry in a [`WorkspaceConfig`].
+///
+/// Credentials are not part of the configuration; integrations created by
+/// applying a configuration must have their credentials set separately.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IntegrationConfig {
+    /// Integration name, used to match existing integrations.
+    pub integration_name: String,
+    /// Description of the integration's purpose.
+    pub description: String,
+    /// Type of third-party service.
+    pub integration_type: IntegrationType,
+    /// How sync conflicts are resolved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflict_policy: Option<SyncConflictPolicy>,
+    /// Whether the integration is active.
+    pub is_active: bool,
+    /// Additional metadata.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// Member role assignment in a [`WorkspaceConfig`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleConfig {
+    /// Account the role is assigned to.
+    pub account_id: Uuid,
+    /// Role in the workspace.
+    pub role: WorkspaceRole,
+}
+
+/// Kind of resource affected by applying a [`WorkspaceConfig`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigResource {
+    /// General workspace settings.
+    Settings,
+    /// Notification settings.
+    Notifications,
+    /// A webhook.
+    Webhook,
+    /// An integration.
+    Integration,
+    /// A member role assignment.
+    Role,
+    /// A folder.
+    Folder,
+}
+
+/// Change made to a resource when applying a [`WorkspaceConfig`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigAction {
+    /// The resource was created.
+    Created,
+    /// The resource was updated to match the configuration.
+    Updated,
+    /// The resource was removed because it is not in the configuration.
+    Deleted,
+}
+
+/// Single change made when applying a [`WorkspaceConfig`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigChange {
+    /// Kind of resource that changed.
+    pub resource: ConfigResource,
+    /// Name, path, or identifier of the resource.
+    pub name: String,
+    /// Change that was made.
+    pub action: ConfigAction,
+}
+
+/// Result of applying a [`WorkspaceConfig`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceConfigApplied {
+    /// Changes made to the workspace. Empty if it already matched.
+    pub changes: Vec<ConfigChange>,
+}
+
+impl WorkspaceConfigApplied {
+    /// Returns `true` if applying the configuration changed anything.
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_empty()
+    }
+}
@@ -0,0 +1,242 @@
+//! Workspace merge models.
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::ConflictStrategy;
+
+/// Options for merging one workspace into another.
+///
+/// By default everything is merged: files, members, webhooks, and settings.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeOptions {
+    /// Whether files and folders are moved.
+    pub files: bool,
+    /// Whether members are added to the target workspace.
+    pub members: bool,
+    /// Whether webhooks are moved.
+    pub webhooks: bool,
+    /// Whether settings missing from the target are copied from the source.
+    pub settings: bool,
+    /// How files whose name already exists in the target are handled.
+    ///
+    /// [`ConflictStrategy::NewVersion`] keeps the source file as a new
+    /// version of the target file. The server default applies if not set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_conflicts: Option<ConflictStrategy>,
+    /// Whether the source workspace is deleted once the merge completes.
+    pub delete_source: bool,
+    /// Whether to only report what the merge would do, without changing
+    /// either workspace.
+    pub dry_run: bool,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            files: true,
+            members: true,
+            webhooks: true,
+            settings: true,
+            file_conflicts: None,
+            delete_source: false,
+            dry_run: false,
+        }
+    }
+}
+
+impl MergeOptions {
+    /// Creates options merging everything.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether files and folders are moved.
+    pub fn with_files(mut self, files: bool) -> Self {
+        self.files = files;
+        self
+    }
+
+    /// Sets whether members are added to the target workspace.
+    pub fn with_members(mut self, members: bool) -> Self {
+        self.members = members;
+        self
+    }
+
+    /// Sets whether webhooks are moved.
+    pub fn with_webhooks(mut self, webhooks: bool) -> Self {
+        self.webhooks = webhooks;
+        self
+    }
+
+    /// Sets whether settings are copied.
+    pub fn with_settings(mut self, settings: bool) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Sets how conflicting file names are handled.
+    pub fn with_file_conflicts(mut self, strategy: ConflictStrategy) -> Self {
+        self.file_conflicts = Some(strategy);
+        self
+    }
+
+    /// Deletes the source workspace once the merge completes.
+    pub fn with_delete_source(mut self, delete_source: bool) -> Self {
+        self.delete_source = delete_source;
+        self
+    }
+
+    /// Only previews the merge without changing either workspace.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+}
+
+/// Request body for starting a workspace merge.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWorkspaceMerge {
+    /// Workspace whose contents are merged into the target.
+    pub source_workspace_id: Uuid,
+    /// What to merge and how.
+    #[serde(flatten)]
+    pub options: MergeOptions,
+}
+
+/// Status of a workspace merge job.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeStatus {
+    /// Job is queued.
+    Pending,
+    /// Job is running.
+    Running,
+    /// Job finished successfully.
+    Completed,
+    /// Job failed.
+    Failed,
+}
+
+impl MergeStatus {
+    /// Returns `true` if the job has finished, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Completed | Self::Failed)
+    }
+}
+
+/// Counts of the changes made, or that would be made by a dry run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeSummary {
+    /// Files moved to the target.
+    #[serde(default)]
+    pub files_moved: u64,
+    /// Files skipped because of a name conflict.
+    #[serde(default)]
+    pub files_skipped: u64,
+    /// Members added to the target.
+    #[serde(default)]
+    pub members_added: u64,
+    /// Existing target members whose role was raised to match the source.
+    #[serde(default)]
+    pub members_updated: u64,
+    /// Webhooks moved to the target.
+    #[serde(default)]
+    pub webhooks_moved: u64,
+    /// Settings copied to the target.
+    #[serde(default)]
+    pub settings_copied: u64,
+}
+
+/// Kind of resource involved in a merge conflict.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MergeResource {
+    /// A file or folder.
+    File,
+    /// A workspace member.
+    Member,
+    /// A webhook.
+    Webhook,
+    /// A workspace setting.
+    Setting,
+}
+
+/// Item that exists in both workspaces and how it was resolved.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeConflict {
+    /// Kind of conflicting resource.
+    pub resource: MergeResource,
+    /// Name of the item, such as a file path or setting key.
+    pub name: String,
+    /// How the conflict was, or would be, resolved.
+    pub resolution: String,
+}
+
+/// Job merging one workspace into another.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceMerge {
+    /// Unique merge job identifier.
+    pub merge_id: Uuid,
+    /// Workspace whose contents are merged.
+    pub source_workspace_id: Uuid,
+    /// Workspace receiving the contents.
+    pub target_workspace_id: Uuid,
+    /// Current status of the merge job.
+    pub status: MergeStatus,
+    /// Whether the job is a preview that changes nothing.
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Changes made so far, or planned by a dry run.
+    #[serde(default)]
+    pub summary: MergeSummary,
+    /// Conflicts encountered and how each was resolved.
+    #[serde(default)]
+    pub conflicts: Vec<MergeConflict>,
+    /// Error message if the merge failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Account that requested the merge.
+    pub created_by: Uuid,
+    /// Time the merge was requested.
+    pub created_at: Timestamp,
+    /// Time the merge finished.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<Timestamp>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_request_flattens_options() -> serde_json::Result<()> {
+        let request = CreateWorkspaceMerge {
+            source_workspace_id: Uuid::nil(),
+            options: MergeOptions::new()
+                .with_webhooks(false)
+                .with_file_conflicts(ConflictStrategy::NewVersion)
+                .with_dry_run(true),
+        };
+        assert_eq!(
+            serde_json::to_value(&request)?,
+            serde_json::json!({
+                "sourceWorkspaceId": "00000000-0000-0000-0000-000000000000",
+                "files": true,
+                "members": true,
+                "webhooks": false,
+                "settings": true,
+                "fileConflicts": "new_version",
+                "deleteSource": false,
+                "dryRun": true,
+            })
+        );
+        Ok(())
+    }
+}
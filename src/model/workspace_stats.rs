@@ -0,0 +1,135 @@
+//! Workspace activity statistics models.
+
+use std::ops::AddAssign;
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Time range covered by workspace statistics.
+///
+/// The server picks the bucket size for each window: hourly buckets for the
+/// last day, daily buckets otherwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StatsWindow {
+    /// The last 24 hours.
+    #[serde(rename = "24h")]
+    Day,
+    /// The last 7 days.
+    #[default]
+    #[serde(rename = "7d")]
+    Week,
+    /// The last 30 days.
+    #[serde(rename = "30d")]
+    Month,
+    /// The last 90 days.
+    #[serde(rename = "90d")]
+    Quarter,
+}
+
+impl StatsWindow {
+    /// Returns the wire name of the window.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Day => "24h",
+            Self::Week => "7d",
+            Self::Month => "30d",
+            Self::Quarter => "90d",
+        }
+    }
+}
+
+/// Activity counts for a period.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityCounts {
+    /// Files uploaded.
+    #[serde(default)]
+    pub uploads: u64,
+    /// Files downloaded.
+    #[serde(default)]
+    pub downloads: u64,
+    /// Comments posted.
+    #[serde(default)]
+    pub comments: u64,
+    /// Webhook deliveries attempted.
+    #[serde(default)]
+    pub webhook_deliveries: u64,
+    /// Files whose processing failed.
+    #[serde(default)]
+    pub processing_failures: u64,
+}
+
+impl AddAssign for ActivityCounts {
+    fn add_assign(&mut self, other: Self) {
+        self.uploads += other.uploads;
+        self.downloads += other.downloads;
+        self.comments += other.comments;
+        self.webhook_deliveries += other.webhook_deliveries;
+        self.processing_failures += other.processing_failures;
+    }
+}
+
+/// Activity counts for one time bucket.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsBucket {
+    /// Start of the bucket, inclusive.
+    pub start: Timestamp,
+    /// End of the bucket, exclusive.
+    pub end: Timestamp,
+    /// Activity within the bucket.
+    pub counts: ActivityCounts,
+}
+
+/// Time-bucketed activity statistics for a workspace.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceStats {
+    /// Workspace the statistics describe.
+    pub workspace_id: Uuid,
+    /// Time range covered.
+    pub window: StatsWindow,
+    /// Buckets in chronological order.
+    #[serde(default)]
+    pub buckets: Vec<StatsBucket>,
+}
+
+impl WorkspaceStats {
+    /// Returns the activity summed over all buckets.
+    pub fn totals(&self) -> ActivityCounts {
+        let mut totals = ActivityCounts::default();
+        for bucket in &self.buckets {
+            totals += bucket.counts;
+        }
+        totals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspace_stats_totals() -> serde_json::Result<()> {
+        let stats: WorkspaceStats = serde_json::from_str(
+            r#"{
+                "workspaceId": "00000000-0000-0000-0000-000000000000",
+                "window": "24h",
+                "buckets": [
+                    {"start": "2024-01-01T00:00:00Z", "end": "2024-01-01T01:00:00Z",
+                     "counts": {"uploads": 3, "processingFailures": 1}},
+                    {"start": "2024-01-01T01:00:00Z", "end": "2024-01-01T02:00:00Z",
+                     "counts": {"uploads": 2, "downloads": 5}}
+                ]
+            }"#,
+        )?;
+
+        assert_eq!(stats.window, StatsWindow::Day);
+        let totals = stats.totals();
+        assert_eq!(totals.uploads, 5);
+        assert_eq!(totals.downloads, 5);
+        assert_eq!(totals.processing_failures, 1);
+        Ok(())
+    }
+}
@@ -7,4 +7,4 @@
 //! ```
 
 pub use crate::client::{NvisyClient, NvisyConfig, NvisyConfigBuilder};
-pub use crate::error::{Error, Result};
+pub use crate::error::{ApiErrorBody, Error, ErrorKind, Result, TimeoutPhase};
@@ -0,0 +1,109 @@
+//! Alerts API service.
+//!
+//! This module provides methods for configuring usage-based threshold alerts.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{CreateUsageAlert, UpdateUsageAlert, UsageAlert, UsageAlertStatus};
+
+/// Trait for Alerts API operations.
+pub trait AlertsService {
+    /// Lists all configured usage alerts.
+    fn list_usage_alerts(&self) -> impl Future<Output = Result<Vec<UsageAlert>>>;
+
+    /// Gets a usage alert by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `alert_id` - The alert identifier
+    fn get_usage_alert(&self, alert_id: Uuid) -> impl Future<Output = Result<UsageAlert>>;
+
+    /// Creates a new usage alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The alert creation request
+    fn create_usage_alert(
+        &self,
+        request: CreateUsageAlert,
+    ) -> impl Future<Output = Result<UsageAlert>>;
+
+    /// Updates a usage alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `alert_id` - The alert identifier
+    /// * `update` - The update request
+    fn update_usage_alert(
+        &self,
+        alert_id: Uuid,
+        update: UpdateUsageAlert,
+    ) -> impl Future<Output = Result<UsageAlert>>;
+
+    /// Deletes a usage alert.
+    ///
+    /// # Arguments
+    ///
+    /// * `alert_id` - The alert identifier
+    fn delete_usage_alert(&self, alert_id: Uuid) -> impl Future<Output = Result<()>>;
+
+    /// Lists the current evaluation state of every usage alert.
+    fn list_usage_alert_states(&self) -> impl Future<Output = Result<Vec<UsageAlertStatus>>>;
+}
+
+impl AlertsService for NvisyClient {
+    async fn list_usage_alerts(&self) -> Result<Vec<UsageAlert>> {
+        let response = self.send(Method::GET, "/alerts/usage/").await?;
+        let response = response.error_for_status()?;
+        let alerts: Vec<UsageAlert> = self.read_json(response).await?;
+        Ok(alerts)
+    }
+
+    async fn get_usage_alert(&self, alert_id: Uuid) -> Result<UsageAlert> {
+        let path = format!("/alerts/usage/{}", alert_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let alert: UsageAlert = self.read_json(response).await?;
+        Ok(alert)
+    }
+
+    async fn create_usage_alert(&self, request: CreateUsageAlert) -> Result<UsageAlert> {
+        let response = self
+            .send_json(Method::POST, "/alerts/usage/", &request)
+            .await?;
+        let response = response.error_for_status()?;
+        let alert: UsageAlert = self.read_json(response).await?;
+        Ok(alert)
+    }
+
+    async fn update_usage_alert(
+        &self,
+        alert_id: Uuid,
+        update: UpdateUsageAlert,
+    ) -> Result<UsageAlert> {
+        let path = format!("/alerts/usage/{}", alert_id);
+        let response = self.send_json(Method::PATCH, &path, &update).await?;
+        let response = response.error_for_status()?;
+        let alert: UsageAlert = self.read_json(response).await?;
+        Ok(alert)
+    }
+
+    async fn delete_usage_alert(&self, alert_id: Uuid) -> Result<()> {
+        let path = format!("/alerts/usage/{}", alert_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+
+    async fn list_usage_alert_states(&self) -> Result<Vec<UsageAlertStatus>> {
+        let response = self.send(Method::GET, "/alerts/usage/states").await?;
+        let response = response.error_for_status()?;
+        let states: Vec<UsageAlertStatus> = self.read_json(response).await?;
+        Ok(states)
+    }
+}
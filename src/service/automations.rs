@@ -0,0 +1,99 @@
+//! Automations API service.
+//!
+//! This module provides methods for browsing the automation catalog and
+//! managing simple trigger-action rules in a workspace.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{
+    Automation, AutomationAction, AutomationActionDefinition, AutomationTrigger,
+    AutomationTriggerDefinition, CreateAutomation,
+};
+
+/// Trait for Automations API operations.
+pub trait AutomationService {
+    /// Lists the triggers available in the automation catalog.
+    fn list_triggers(&self) -> impl Future<Output = Result<Vec<AutomationTriggerDefinition>>>;
+
+    /// Lists the actions available in the automation catalog.
+    fn list_actions(&self) -> impl Future<Output = Result<Vec<AutomationActionDefinition>>>;
+
+    /// Lists automations in a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn list_automations(&self, workspace_id: Uuid)
+    -> impl Future<Output = Result<Vec<Automation>>>;
+
+    /// Creates an automation that runs the actions when the trigger fires.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `trigger` - The trigger that starts the automation
+    /// * `actions` - The actions to run, in order
+    fn create_automation(
+        &self,
+        workspace_id: Uuid,
+        trigger: AutomationTrigger,
+        actions: Vec<AutomationAction>,
+    ) -> impl Future<Output = Result<Automation>>;
+
+    /// Deletes an automation.
+    ///
+    /// # Arguments
+    ///
+    /// * `automation_id` - The automation identifier
+    fn delete_automation(&self, automation_id: Uuid) -> impl Future<Output = Result<()>>;
+}
+
+impl AutomationService for NvisyClient {
+    async fn list_triggers(&self) -> Result<Vec<AutomationTriggerDefinition>> {
+        let response = self.send(Method::GET, "/automations/triggers").await?;
+        let response = response.error_for_status()?;
+        let triggers: Vec<AutomationTriggerDefinition> = self.read_json(response).await?;
+        Ok(triggers)
+    }
+
+    async fn list_actions(&self) -> Result<Vec<AutomationActionDefinition>> {
+        let response = self.send(Method::GET, "/automations/actions").await?;
+        let response = response.error_for_status()?;
+        let actions: Vec<AutomationActionDefinition> = self.read_json(response).await?;
+        Ok(actions)
+    }
+
+    async fn list_automations(&self, workspace_id: Uuid) -> Result<Vec<Automation>> {
+        let path = format!("/workspaces/{}/automations/", workspace_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let automations: Vec<Automation> = self.read_json(response).await?;
+        Ok(automations)
+    }
+
+    async fn create_automation(
+        &self,
+        workspace_id: Uuid,
+        trigger: AutomationTrigger,
+        actions: Vec<AutomationAction>,
+    ) -> Result<Automation> {
+        let path = format!("/workspaces/{}/automations/", workspace_id);
+        let request = CreateAutomation { trigger, actions };
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let automation: Automation = self.read_json(response).await?;
+        Ok(automation)
+    }
+
+    async fn delete_automation(&self, automation_id: Uuid) -> Result<()> {
+        let path = format!("/automations/{}", automation_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+}
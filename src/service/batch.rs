@@ -0,0 +1,103 @@
+//! Batch lookups shared by the `get_*_batch` methods.
+
+use std::future::Future;
+
+use futures_util::{StreamExt, stream};
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+
+use super::query::{ListStyle, Query};
+use crate::client::NvisyClient;
+use crate::error::{Error, Result};
+use crate::model::Batch;
+
+/// Maximum number of identifiers sent in one batch request.
+const MAX_BATCH_SIZE: usize = 100;
+
+/// Number of concurrent lookups when the batch endpoint is unavailable.
+const FALLBACK_CONCURRENCY: usize = 8;
+
+/// Looks up resources through the batch endpoint at `path`.
+///
+/// Servers without the endpoint are handled by calling `get_one` for each
+/// identifier, with bounded concurrency.
+pub(crate) async fn get_batch<T, F, Fut>(
+    client: &NvisyClient,
+    path: &str,
+    ids: &[Uuid],
+    get_one: F,
+) -> Result<Batch<T>>
+where
+    T: DeserializeOwned,
+    F: Fn(Uuid) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut batch = Batch::default();
+    for (index, chunk) in ids.chunks(MAX_BATCH_SIZE).enumerate() {
+        match lookup(client, path, chunk).await {
+            Ok(found) => {
+                batch.items.extend(found.items);
+                batch.not_found.extend(found.not_found);
+            }
+            Err(Error::ApiResponse(body)) if is_unsupported(body.status()) => {
+                let rest = &ids[index * MAX_BATCH_SIZE..];
+                let found = get_each(rest, get_one).await?;
+                batch.items.extend(found.items);
+                batch.not_found.extend(found.not_found);
+                break;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(batch)
+}
+
+/// Sends one batch request.
+async fn lookup<T: DeserializeOwned>(
+    client: &NvisyClient,
+    path: &str,
+    ids: &[Uuid],
+) -> Result<Batch<T>> {
+    let req = Query::new()
+        .list("ids", ids, ListStyle::Comma)
+        .apply(client.request_builder(Method::GET, path)?);
+    let response = client.execute(req).await?;
+    let response = response.error_for_status()?;
+    client.read_json(response).await
+}
+
+/// Looks up each identifier separately, preserving their order.
+async fn get_each<T, F, Fut>(ids: &[Uuid], get_one: F) -> Result<Batch<T>>
+where
+    F: Fn(Uuid) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let results: Vec<(Uuid, Result<T>)> = stream::iter(ids.iter().copied())
+        .map(|id| {
+            let lookup = get_one(id);
+            async move { (id, lookup.await) }
+        })
+        .buffered(FALLBACK_CONCURRENCY)
+        .collect()
+        .await;
+
+    let mut batch = Batch::default();
+    for (id, result) in results {
+        match result {
+            Ok(item) => batch.items.push(item),
+            Err(Error::ApiResponse(body)) if body.is_not_found() => batch.not_found.push(id),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(batch)
+}
+
+/// Returns `true` if a batch request failed because the server does not
+/// provide the endpoint.
+fn is_unsupported(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED
+    )
+}
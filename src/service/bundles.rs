@@ -0,0 +1,106 @@
+//! Bundles API service.
+//!
+//! This module provides methods for assembling workspace files into a
+//! single bookmarked PDF, such as a court-ready or client-ready packet.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{Bundle, BundleSpec};
+
+/// Trait for Bundles API operations.
+pub trait BundlesService {
+    /// Starts assembling a bundle.
+    ///
+    /// The sections are merged into a single PDF with a bookmark per
+    /// section, optionally preceded by a cover page and a table of contents.
+    /// The PDF is stored as a new file in the workspace. Assembly runs as a
+    /// job; poll [`get_bundle`] until [`Bundle::state`] is terminal, then
+    /// use [`Bundle::file_id`].
+    ///
+    /// [`get_bundle`]: BundlesService::get_bundle
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `spec` - Sections and layout of the bundle
+    fn create_bundle(
+        &self,
+        workspace_id: Uuid,
+        spec: BundleSpec,
+    ) -> impl Future<Output = Result<Bundle>>;
+
+    /// Gets a bundle by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `bundle_id` - The bundle identifier
+    fn get_bundle(&self, bundle_id: Uuid) -> impl Future<Output = Result<Bundle>>;
+}
+
+impl BundlesService for NvisyClient {
+    async fn create_bundle(&self, workspace_id: Uuid, spec: BundleSpec) -> Result<Bundle> {
+        let path = format!("/workspaces/{}/bundles/", workspace_id);
+        let response = self.send_json(Method::POST, &path, &spec).await?;
+        let response = response.error_for_status()?;
+        let bundle: Bundle = self.read_json(response).await?;
+        Ok(bundle)
+    }
+
+    async fn get_bundle(&self, bundle_id: Uuid) -> Result<Bundle> {
+        let path = format!("/bundles/{}", bundle_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let bundle: Bundle = self.read_json(response).await?;
+        Ok(bundle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+    use crate::model::{BundleSection, CoverPage, JobState};
+
+    #[tokio::test]
+    async fn test_create_bundle_sends_sections_in_order() -> Result<()> {
+        let bundle = r#"{"bundleId":"00000000-0000-0000-0000-000000000009",
+            "workspaceId":"00000000-0000-0000-0000-000000000001",
+            "jobId":"00000000-0000-0000-0000-000000000003","state":"queued",
+            "createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-01T00:00:00Z"}"#;
+        let (base_url, server) = mock::serve(vec![mock::response(202, &[], bundle)]);
+
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+        let spec = BundleSpec::new(vec![
+            BundleSection::file(Uuid::from_u128(2)).with_title("Complaint"),
+            BundleSection::folder(Uuid::from_u128(4)).with_recursive(true),
+        ])
+        .with_toc(true)
+        .with_cover_page(CoverPage::new("Exhibits").with_subtitle("Case 24-101"));
+        let bundle = client.create_bundle(Uuid::from_u128(1), spec).await?;
+
+        assert_eq!(bundle.state, JobState::Queued);
+        assert!(bundle.file_id.is_none());
+
+        let requests = server.join().unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body)?;
+        assert_eq!(body["sections"][0]["type"], "file");
+        assert_eq!(body["sections"][0]["title"], "Complaint");
+        assert_eq!(
+            body["sections"][1]["folderId"],
+            Uuid::from_u128(4).to_string()
+        );
+        assert_eq!(body["sections"][1]["recursive"], true);
+        assert_eq!(body["coverPage"]["subtitle"], "Case 24-101");
+        assert_eq!(body["toc"], true);
+        Ok(())
+    }
+}
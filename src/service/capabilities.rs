@@ -0,0 +1,44 @@
+//! Capabilities API service.
+//!
+//! This module provides methods for discovering the limits advertised by
+//! the Nvisy API and the features enabled for the tenant.
+
+use std::future::Future;
+
+use reqwest::Method;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{Capabilities, FeatureFlags};
+
+/// Trait for Capabilities API operations.
+pub trait CapabilitiesService {
+    /// Gets the limits and features advertised by the API.
+    ///
+    /// The result is remembered by the client, and its maximum upload size
+    /// is enforced before subsequent uploads are sent.
+    fn get_capabilities(&self) -> impl Future<Output = Result<Capabilities>>;
+
+    /// Gets the feature toggles of the tenant the API key belongs to.
+    ///
+    /// Use this to enable code paths for optional features, instead of
+    /// probing their endpoints and interpreting `404` responses.
+    fn features(&self) -> impl Future<Output = Result<FeatureFlags>>;
+}
+
+impl CapabilitiesService for NvisyClient {
+    async fn get_capabilities(&self) -> Result<Capabilities> {
+        let response = self.send(Method::GET, "/capabilities").await?;
+        let response = response.error_for_status()?;
+        let capabilities: Capabilities = self.read_json(response).await?;
+        *self.inner.capabilities.lock().unwrap() = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+
+    async fn features(&self) -> Result<FeatureFlags> {
+        let response = self.send(Method::GET, "/features").await?;
+        let response = response.error_for_status()?;
+        let flags: FeatureFlags = self.read_json(response).await?;
+        Ok(flags)
+    }
+}
@@ -0,0 +1,112 @@
+//! Capture API service.
+//!
+//! This module provides methods for letting phones upload photos of
+//! documents directly into a workspace, such as for field agents.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{CaptureSession, CaptureSessionOptions};
+
+/// Trait for Capture API operations.
+pub trait CaptureService {
+    /// Creates a capture session for a workspace.
+    ///
+    /// Hand [`CaptureSession::url`] to the phone, for example as the
+    /// returned QR code, then poll [`get_capture_session`] to collect the
+    /// uploaded files.
+    ///
+    /// [`get_capture_session`]: CaptureService::get_capture_session
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Lifetime, destination folder, and upload limits
+    fn create_capture_session(
+        &self,
+        workspace_id: Uuid,
+        options: CaptureSessionOptions,
+    ) -> impl Future<Output = Result<CaptureSession>>;
+
+    /// Gets a capture session by ID, including the files uploaded so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The session identifier
+    fn get_capture_session(&self, session_id: Uuid)
+    -> impl Future<Output = Result<CaptureSession>>;
+}
+
+impl CaptureService for NvisyClient {
+    async fn create_capture_session(
+        &self,
+        workspace_id: Uuid,
+        options: CaptureSessionOptions,
+    ) -> Result<CaptureSession> {
+        let path = format!("/workspaces/{}/capture-sessions/", workspace_id);
+        let response = self.send_json(Method::POST, &path, &options).await?;
+        let response = response.error_for_status()?;
+        let session: CaptureSession = self.read_json(response).await?;
+        Ok(session)
+    }
+
+    async fn get_capture_session(&self, session_id: Uuid) -> Result<CaptureSession> {
+        let path = format!("/capture-sessions/{}", session_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let session: CaptureSession = self.read_json(response).await?;
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+    use crate::model::CaptureSessionStatus;
+
+    #[tokio::test]
+    async fn test_capture_session_collects_files() -> Result<()> {
+        let active = r#"{"sessionId":"00000000-0000-0000-0000-000000000005",
+            "workspaceId":"00000000-0000-0000-0000-000000000001",
+            "url":"https://app.nvisy.com/capture/abc","status":"active",
+            "createdAt":"2024-01-01T00:00:00Z","expiresAt":"2024-01-01T00:15:00Z"}"#;
+        let completed = active.replace(
+            r#""status":"active""#,
+            r#""status":"completed","fileIds":["00000000-0000-0000-0000-000000000007"]"#,
+        );
+        let (base_url, server) = mock::serve(vec![
+            mock::response(201, &[], active),
+            mock::response(200, &[], &completed),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let options = CaptureSessionOptions::new()
+            .with_ttl(Duration::from_secs(900))
+            .with_max_files(10);
+        let session = client
+            .create_capture_session(Uuid::from_u128(1), options)
+            .await?;
+        assert!(!session.status.is_terminal());
+
+        let session = client.get_capture_session(session.session_id).await?;
+        assert_eq!(session.status, CaptureSessionStatus::Completed);
+        assert_eq!(session.file_ids, [Uuid::from_u128(7)]);
+
+        let requests = server.join().unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body)?;
+        assert_eq!(body["expiresInSecs"], 900);
+        assert_eq!(body["maxFiles"], 10);
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+//! Comments API service.
+//!
+//! This module provides methods for reading and posting discussions on
+//! files in workspaces with comments enabled.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use super::query::Query;
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{Comment, CommentsPage, CreateComment, Cursor};
+
+/// Trait for Comments API operations.
+pub trait CommentsService {
+    /// Lists the comments on a file, oldest first.
+    ///
+    /// Replies are included alongside top-level comments; use
+    /// [`Comment::parent_comment_id`] to thread them.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `options` - Optional listing options (pagination)
+    fn list_comments(
+        &self,
+        file_id: Uuid,
+        options: Option<ListCommentsOptions>,
+    ) -> impl Future<Output = Result<CommentsPage>>;
+
+    /// Posts a comment on a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `request` - The comment creation request
+    fn create_comment(
+        &self,
+        file_id: Uuid,
+        request: CreateComment,
+    ) -> impl Future<Output = Result<Comment>>;
+
+    /// Posts a reply to a comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The comment to reply to
+    /// * `body` - The reply text
+    fn reply_to_comment(
+        &self,
+        comment_id: Uuid,
+        body: &str,
+    ) -> impl Future<Output = Result<Comment>>;
+
+    /// Edits the text of a comment.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The comment identifier
+    /// * `body` - The new comment text
+    fn update_comment(&self, comment_id: Uuid, body: &str)
+    -> impl Future<Output = Result<Comment>>;
+
+    /// Deletes a comment and its replies.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The comment identifier
+    fn delete_comment(&self, comment_id: Uuid) -> impl Future<Output = Result<()>>;
+
+    /// Marks the discussion started by a comment as resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `comment_id` - The comment identifier
+    fn resolve_comment(&self, comment_id: Uuid) -> impl Future<Output = Result<Comment>>;
+}
+
+/// Options for listing comments.
+#[derive(Clone, Debug, Default)]
+pub struct ListCommentsOptions {
+    /// Pagination cursor.
+    pub after: Option<Cursor>,
+    /// Maximum number of results.
+    pub limit: Option<i32>,
+}
+
+impl ListCommentsOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pagination cursor.
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Sets the maximum number of results.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl CommentsService for NvisyClient {
+    async fn list_comments(
+        &self,
+        file_id: Uuid,
+        options: Option<ListCommentsOptions>,
+    ) -> Result<CommentsPage> {
+        let path = format!("/files/{}/comments/", file_id);
+        let opts = options.unwrap_or_default();
+
+        let query = Query::new()
+            .opt("after", opts.after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let page: CommentsPage = self.read_json(response).await?;
+        Ok(page)
+    }
+
+    async fn create_comment(&self, file_id: Uuid, request: CreateComment) -> Result<Comment> {
+        let path = format!("/files/{}/comments/", file_id);
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let comment: Comment = self.read_json(response).await?;
+        Ok(comment)
+    }
+
+    async fn reply_to_comment(&self, comment_id: Uuid, body: &str) -> Result<Comment> {
+        let path = format!("/comments/{}/replies", comment_id);
+        let request = CreateComment::new(body);
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let comment: Comment = self.read_json(response).await?;
+        Ok(comment)
+    }
+
+    async fn update_comment(&self, comment_id: Uuid, body: &str) -> Result<Comment> {
+        let path = format!("/comments/{}/", comment_id);
+        let request = CreateComment::new(body);
+        let response = self.send_json(Method::PATCH, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let comment: Comment = self.read_json(response).await?;
+        Ok(comment)
+    }
+
+    async fn delete_comment(&self, comment_id: Uuid) -> Result<()> {
+        let path = format!("/comments/{}/", comment_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+
+    async fn resolve_comment(&self, comment_id: Uuid) -> Result<Comment> {
+        let path = format!("/comments/{}/resolve", comment_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let comment: Comment = self.read_json(response).await?;
+        Ok(comment)
+    }
+}
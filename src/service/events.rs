@@ -0,0 +1,99 @@
+//! Events API service.
+//!
+//! This module provides methods for querying a workspace's event log, which
+//! records the same events delivered to webhooks.
+
+use std::future::Future;
+
+use jiff::Timestamp;
+use reqwest::Method;
+use uuid::Uuid;
+
+use super::query::{ListStyle, Query};
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{Cursor, EventsPage, WebhookEvent};
+
+/// Trait for Events API operations.
+pub trait EventsService {
+    /// Lists events that occurred in a workspace since the given time.
+    ///
+    /// Use this to backfill webhook deliveries missed while a consumer was
+    /// offline. Events are returned oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `since` - Only return events that occurred at or after this time
+    /// * `types` - Event types to include; an empty list includes all types
+    /// * `options` - Optional listing options (end of window, pagination)
+    fn list_events(
+        &self,
+        workspace_id: Uuid,
+        since: Timestamp,
+        types: Vec<WebhookEvent>,
+        options: Option<ListEventsOptions>,
+    ) -> impl Future<Output = Result<EventsPage>>;
+}
+
+/// Options for listing events.
+#[derive(Clone, Debug, Default)]
+pub struct ListEventsOptions {
+    /// Only return events that occurred before this time.
+    pub until: Option<Timestamp>,
+    /// Pagination cursor.
+    pub after: Option<Cursor>,
+    /// Maximum number of results.
+    pub limit: Option<i32>,
+}
+
+impl ListEventsOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the end of the time window.
+    pub fn until(mut self, until: Timestamp) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Sets the pagination cursor.
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Sets the maximum number of results.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl EventsService for NvisyClient {
+    async fn list_events(
+        &self,
+        workspace_id: Uuid,
+        since: Timestamp,
+        types: Vec<WebhookEvent>,
+        options: Option<ListEventsOptions>,
+    ) -> Result<EventsPage> {
+        let path = format!("/workspaces/{}/events/", workspace_id);
+        let opts = options.unwrap_or_default();
+
+        let query = Query::new()
+            .push("since", &since)
+            .list("type", &types, ListStyle::Repeat)
+            .opt("until", opts.until.as_ref())
+            .opt("after", opts.after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let page: EventsPage = self.read_json(response).await?;
+        Ok(page)
+    }
+}
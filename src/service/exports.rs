@@ -0,0 +1,84 @@
+//! Exports API service.
+//!
+//! This module provides methods for producing anonymized dataset exports
+//! of workspace documents.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{AnonymizeOptions, AnonymizedExport};
+
+/// Trait for Exports API operations.
+pub trait ExportsService {
+    /// Starts an anonymized export of a workspace.
+    ///
+    /// The export is generated asynchronously. Poll [`get_anonymized_export`]
+    /// until the status is `Completed`, then download it with
+    /// [`download_anonymized_export`].
+    ///
+    /// [`get_anonymized_export`]: ExportsService::get_anonymized_export
+    /// [`download_anonymized_export`]: ExportsService::download_anonymized_export
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Files to include, redaction rules, and output format
+    fn create_anonymized_export(
+        &self,
+        workspace_id: Uuid,
+        options: AnonymizeOptions,
+    ) -> impl Future<Output = Result<AnonymizedExport>>;
+
+    /// Gets an anonymized export by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `export_id` - The export identifier
+    fn get_anonymized_export(
+        &self,
+        export_id: Uuid,
+    ) -> impl Future<Output = Result<AnonymizedExport>>;
+
+    /// Downloads a completed anonymized export.
+    ///
+    /// Returns the raw bytes of the dataset archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `export_id` - The export identifier
+    fn download_anonymized_export(&self, export_id: Uuid) -> impl Future<Output = Result<Vec<u8>>>;
+}
+
+impl ExportsService for NvisyClient {
+    async fn create_anonymized_export(
+        &self,
+        workspace_id: Uuid,
+        options: AnonymizeOptions,
+    ) -> Result<AnonymizedExport> {
+        let path = format!("/workspaces/{}/exports/anonymized", workspace_id);
+        let response = self.send_json(Method::POST, &path, &options).await?;
+        let response = response.error_for_status()?;
+        let export: AnonymizedExport = self.read_json(response).await?;
+        Ok(export)
+    }
+
+    async fn get_anonymized_export(&self, export_id: Uuid) -> Result<AnonymizedExport> {
+        let path = format!("/exports/{}/", export_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let export: AnonymizedExport = self.read_json(response).await?;
+        Ok(export)
+    }
+
+    async fn download_anonymized_export(&self, export_id: Uuid) -> Result<Vec<u8>> {
+        let path = format!("/exports/{}/content", export_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let bytes = self.read_bytes(response).await?;
+        Ok(bytes.to_vec())
+    }
+}
@@ -3,17 +3,60 @@
 //! This module provides methods for managing files in workspaces.
 
 use std::future::Future;
+use std::time::Duration;
 
-use reqwest::Method;
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+
+use bytes::Bytes;
+#[cfg(not(target_arch = "wasm32"))]
+use bytes::BytesMut;
+#[cfg(feature = "client-encryption")]
+use futures_util::future::Either;
+use futures_util::stream;
+use futures_util::{Stream, StreamExt, TryStreamExt};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Body;
+use reqwest::header::{CONTENT_TYPE, RANGE};
 use reqwest::multipart::{Form, Part};
+use reqwest::{Method, StatusCode};
+use sha2::{Digest, Sha256};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use uuid::Uuid;
 
-use crate::client::NvisyClient;
-use crate::error::Result;
+use super::batch;
+use super::pagination::Paginator;
+use super::query::{ListStyle, Query};
+use crate::client::progress::{self, TransferProgress};
+use crate::client::runtime::{self, Instant};
+use crate::client::{NvisyClient, RequestOptions};
+use crate::error::{Error, Result, TimeoutPhase};
+#[cfg(feature = "client-encryption")]
+use crate::model::FileEncryptionKey;
 use crate::model::{
-    ArchiveFormat, DeleteFiles, DownloadFiles, File, FileFormat, FilesPage, UpdateFile,
+    ArchiveFormat, Barcodes, Batch, BulkResult, CompleteMultipartUpload, ConflictStrategy,
+    CreateMultipartUpload, CreateSignedUrl, Cursor, DeleteFiles, DownloadFiles, File, FileFormat,
+    FileStatus, FileSummary, FileVersion, FilesPage, Job, MultipartUpload, OcrOptions, OcrReport,
+    PageAnchor, ProcessedFile, SignedUrl, StagedFile, UpdateFile, UploadProgress,
+    UploadTransaction, UploadedPart, VersionDiff,
 };
 
+/// Delay before the first poll of a file being processed.
+const PROCESSING_POLL_INITIAL: Duration = Duration::from_secs(1);
+
+/// Minimum delay between polls of a file being processed.
+const PROCESSING_POLL_MIN: Duration = Duration::from_millis(100);
+
+/// Maximum delay between polls of a file being processed.
+const PROCESSING_POLL_MAX: Duration = Duration::from_secs(10);
+
+/// Size of the chunks read from streamed upload sources.
+#[cfg(not(target_arch = "wasm32"))]
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How long to wait for a file to finish processing.
+const PROCESSING_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
 /// Trait for Files API operations.
 pub trait FilesService {
     /// Lists files in a workspace with optional filtering and pagination.
@@ -28,6 +71,50 @@ pub trait FilesService {
         options: Option<ListFilesOptions>,
     ) -> impl Future<Output = Result<FilesPage>>;
 
+    /// Lists all files in a workspace, following pagination cursors.
+    ///
+    /// Pages are fetched lazily as the stream is polled. The `after` option
+    /// sets where the listing starts and `limit` sets the page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (filters, page size)
+    fn list_files_stream(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListFilesOptions>,
+    ) -> impl Stream<Item = Result<File>> + '_;
+
+    /// Returns a paginator over all files in a workspace.
+    ///
+    /// Pages are fetched as they are requested. The `after` option sets
+    /// where the listing starts and `limit` sets the page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (filters, page size)
+    fn paginate_files(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListFilesOptions>,
+    ) -> Paginator<'_, File>;
+
+    /// Finds a file in a workspace by its external identifier.
+    ///
+    /// Returns `None` if no file in the workspace has the given identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `external_id` - The external identifier
+    fn find_file_by_external_id(
+        &self,
+        workspace_id: Uuid,
+        external_id: &str,
+    ) -> impl Future<Output = Result<Option<File>>>;
+
     /// Gets a file by ID.
     ///
     /// # Arguments
@@ -35,6 +122,17 @@ pub trait FilesService {
     /// * `file_id` - The file identifier
     fn get_file(&self, file_id: Uuid) -> impl Future<Output = Result<File>>;
 
+    /// Gets many files by ID with as few requests as possible.
+    ///
+    /// Files that do not exist are listed in [`Batch::not_found`] instead of
+    /// failing the call. Against servers without the batch endpoint, the
+    /// files are fetched individually with bounded concurrency.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_ids` - The file identifiers
+    fn get_files_batch(&self, file_ids: &[Uuid]) -> impl Future<Output = Result<Batch<File>>>;
+
     /// Updates a file's metadata.
     ///
     /// # Arguments
@@ -54,13 +152,112 @@ pub trait FilesService {
 
     /// Downloads a file's content.
     ///
-    /// Returns the raw bytes of the file content.
+    /// Returns the raw bytes of the file content. If a
+    /// [`DownloadCache`](crate::DownloadCache) is configured, the file
+    /// metadata is fetched first and content with a matching checksum is
+    /// served from the cache.
+    ///
+    /// If the request options carry an encryption key, set with
+    /// `RequestOptions::with_encryption_key`, content encrypted on the client
+    /// is decrypted with it.
     ///
     /// # Arguments
     ///
     /// * `file_id` - The file identifier
     fn download_file(&self, file_id: Uuid) -> impl Future<Output = Result<Vec<u8>>>;
 
+    /// Creates a presigned URL for downloading a file without an API key.
+    ///
+    /// Renew the URL before it expires with [`SignedUrl::refresh`].
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `ttl` - Lifetime of the URL; the server default applies if not set
+    fn create_signed_url(
+        &self,
+        file_id: Uuid,
+        ttl: Option<Duration>,
+    ) -> impl Future<Output = Result<SignedUrl>>;
+
+    /// Downloads a file's content and verifies it against the file checksum.
+    ///
+    /// The SHA-256 of the downloaded content is compared with the checksum in
+    /// the file metadata. On mismatch the download is retried, up to
+    /// [`RetryPolicy::checksum_retries`] times, before
+    /// [`Error::ChecksumMismatch`] is returned. Content shorter than the file
+    /// size is completed with a `Range` request; other mismatches restart the
+    /// download from the beginning. Files without a checksum are
+    /// returned unverified. Content encrypted on the client is verified
+    /// before it is decrypted, as with [`download_file`](Self::download_file).
+    ///
+    /// [`RetryPolicy::checksum_retries`]: crate::RetryPolicy::checksum_retries
+    /// [`Error::ChecksumMismatch`]: crate::Error::ChecksumMismatch
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    fn download_file_verified(&self, file_id: Uuid) -> impl Future<Output = Result<Vec<u8>>>;
+
+    /// Downloads a file's content as a stream of chunks.
+    ///
+    /// The request is sent when the stream is first polled, and chunks are
+    /// yielded as they arrive, so the file is never buffered in memory. The
+    /// download cache is not consulted.
+    ///
+    /// If the request options carry an encryption key, the file is
+    /// downloaded with [`download_file`](Self::download_file) and yielded as
+    /// a single decrypted chunk, since the content can only be authenticated
+    /// as a whole.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    fn download_file_stream(&self, file_id: Uuid) -> impl Stream<Item = Result<Bytes>> + '_;
+
+    /// Downloads a file's content into an async writer.
+    ///
+    /// The content is written chunk by chunk as it arrives, so the file is
+    /// never buffered in memory. With a download cache or an encryption key
+    /// configured, the file is downloaded with
+    /// [`download_file`](Self::download_file) and written in one piece.
+    /// Returns the number of bytes written.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `writer` - Destination for the file content
+    fn download_file_to_writer<W>(
+        &self,
+        file_id: Uuid,
+        writer: W,
+    ) -> impl Future<Output = Result<u64>>
+    where
+        W: AsyncWrite + Unpin;
+
+    /// Downloads a file, decrypting content that was encrypted on the client.
+    ///
+    /// Files uploaded without client-side encryption are returned as stored.
+    /// To decrypt every download made through a client, set the key with
+    /// `RequestOptions::with_encryption_key` instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `key` - The key the file was encrypted with
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Encryption`] if the file was encrypted with a
+    /// different key or the content fails authentication.
+    #[cfg(feature = "client-encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "client-encryption")))]
+    fn download_file_decrypted(
+        &self,
+        file_id: Uuid,
+        key: &FileEncryptionKey,
+    ) -> impl Future<Output = Result<Vec<u8>>>;
+
     /// Uploads a file to a workspace.
     ///
     /// # Arguments
@@ -75,171 +272,1265 @@ pub trait FilesService {
         file_data: Vec<u8>,
     ) -> impl Future<Output = Result<File>>;
 
-    /// Deletes multiple files in a batch.
+    /// Uploads a file and waits for it to finish processing.
+    ///
+    /// The file is polled with increasing delays for up to ten minutes.
+    /// Fails with [`Error::ProcessingFailed`] if processing fails, or with
+    /// [`Error::Timeout`] if it does not finish in time.
     ///
     /// # Arguments
     ///
     /// * `workspace_id` - The workspace identifier
-    /// * `file_ids` - List of file IDs to delete
-    fn delete_files_batch(
+    /// * `file_name` - The file name
+    /// * `file_data` - The file content as bytes
+    fn upload_and_process(
         &self,
         workspace_id: Uuid,
-        file_ids: Vec<Uuid>,
-    ) -> impl Future<Output = Result<()>>;
+        file_name: &str,
+        file_data: Vec<u8>,
+    ) -> impl Future<Output = Result<ProcessedFile>>;
 
-    /// Downloads multiple files as an archive.
+    /// Waits for a file to finish processing.
+    ///
+    /// The file is polled starting at the configured interval, doubling the
+    /// delay after each poll up to ten seconds. Fails with
+    /// [`Error::ProcessingFailed`] if processing fails, or with
+    /// [`Error::Timeout`] in the [`TimeoutPhase::Processing`] phase if it
+    /// does not finish in time.
     ///
     /// # Arguments
     ///
-    /// * `workspace_id` - The workspace identifier
-    /// * `file_ids` - List of file IDs to download (empty for all files)
-    /// * `format` - Archive format (ZIP or TAR.GZ)
-    fn download_files_batch(
+    /// * `file_id` - The file identifier
+    /// * `options` - Polling interval and timeout
+    fn wait_for_processing(
         &self,
-        workspace_id: Uuid,
-        file_ids: Vec<Uuid>,
-        format: ArchiveFormat,
-    ) -> impl Future<Output = Result<Vec<u8>>>;
-}
-
-/// Options for listing files.
-#[derive(Clone, Debug, Default)]
-pub struct ListFilesOptions {
-    /// Filter by file formats.
-    pub formats: Option<Vec<FileFormat>>,
-    /// Search query.
-    pub search: Option<String>,
-    /// Pagination cursor.
-    pub after: Option<String>,
-    /// Maximum number of results.
-    pub limit: Option<i32>,
-}
-
-impl ListFilesOptions {
-    /// Creates a new options builder.
-    pub fn new() -> Self {
-        Self::default()
-    }
-
-    /// Sets the file formats filter.
-    pub fn formats(mut self, formats: Vec<FileFormat>) -> Self {
-        self.formats = Some(formats);
-        self
-    }
+        file_id: Uuid,
+        options: PollOptions,
+    ) -> impl Future<Output = Result<File>>;
 
-    /// Sets the search query.
-    pub fn search(mut self, query: impl Into<String>) -> Self {
-        self.search = Some(query.into());
-        self
-    }
+    /// Watches a file's processing status, yielding each change.
+    ///
+    /// The current status is yielded first, followed by every transition
+    /// observed by polling the file with increasing delays. The stream ends
+    /// after yielding a terminal status, or with the first error. Polling
+    /// starts when the stream is first polled.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    fn watch_file_status(&self, file_id: Uuid) -> impl Stream<Item = Result<FileStatus>> + '_;
 
-    /// Sets the pagination cursor.
-    pub fn after(mut self, cursor: impl Into<String>) -> Self {
-        self.after = Some(cursor.into());
-        self
-    }
+    /// Gets the extraction and classification results of a processed file.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    fn get_file_summary(&self, file_id: Uuid) -> impl Future<Output = Result<FileSummary>>;
 
-    /// Sets the maximum number of results.
-    pub fn limit(mut self, limit: i32) -> Self {
-        self.limit = Some(limit);
-        self
-    }
-}
+    /// Lists the stored versions of a file, oldest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    fn list_versions(&self, file_id: Uuid) -> impl Future<Output = Result<Vec<FileVersion>>>;
 
-impl FilesService for NvisyClient {
-    async fn list_files(
+    /// Compares two versions of a file.
+    ///
+    /// Reports changed pages, changed metadata fields, and the size
+    /// difference without restoring either version.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `from_version` - The older version
+    /// * `to_version` - The newer version
+    fn compare_versions(
         &self,
-        workspace_id: Uuid,
-        options: Option<ListFilesOptions>,
-    ) -> Result<FilesPage> {
-        let path = format!("/workspaces/{}/files/", workspace_id);
-        let opts = options.unwrap_or_default();
-
-        let mut req = self.request_builder(Method::GET, &path)?;
-
-        if let Some(formats) = &opts.formats {
-            for format in formats {
-                req = req.query(&[("formats", format)]);
-            }
-        }
-        if let Some(search) = &opts.search {
-            req = req.query(&[("search", search)]);
-        }
-        if let Some(after) = &opts.after {
-            req = req.query(&[("after", after)]);
-        }
-        if let Some(limit) = opts.limit {
-            req = req.query(&[("limit", limit)]);
-        }
+        file_id: Uuid,
+        from_version: u32,
+        to_version: u32,
+    ) -> impl Future<Output = Result<VersionDiff>>;
 
-        let response = req.send().await?;
-        let response = response.error_for_status()?;
-        let page: FilesPage = response.json().await?;
-        Ok(page)
-    }
-
-    async fn get_file(&self, file_id: Uuid) -> Result<File> {
-        let path = format!("/files/{}", file_id);
-        let response = self.send(Method::GET, &path).await?;
-        let response = response.error_for_status()?;
-        let file: File = response.json().await?;
-        Ok(file)
-    }
+    /// Downloads the content of a past version of a file.
+    ///
+    /// The current version of the file is left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `version` - The version number
+    fn get_version_content(
+        &self,
+        file_id: Uuid,
+        version: u32,
+    ) -> impl Future<Output = Result<Vec<u8>>>;
 
-    async fn update_file(&self, file_id: Uuid, update: UpdateFile) -> Result<File> {
-        let path = format!("/files/{}", file_id);
-        let response = self.send_json(Method::PATCH, &path, &update).await?;
-        let response = response.error_for_status()?;
-        let file: File = response.json().await?;
-        Ok(file)
-    }
+    /// Gets the OCR quality report of a processed file.
+    ///
+    /// The report lists per-page confidence, skew, and resolution warnings,
+    /// so low-quality scans can be found and passed to [`reocr_file`].
+    ///
+    /// [`reocr_file`]: FilesService::reocr_file
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    fn get_ocr_report(&self, file_id: Uuid) -> impl Future<Output = Result<OcrReport>>;
 
-    async fn delete_file(&self, file_id: Uuid) -> Result<()> {
-        let path = format!("/files/{}", file_id);
-        let response = self.send(Method::DELETE, &path).await?;
-        response.error_for_status()?;
-        Ok(())
-    }
+    /// Runs OCR on a file again with different settings.
+    ///
+    /// Returns the job doing the work; see
+    /// [`JobsService`](crate::service::JobsService) to follow it.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `options` - Languages, engine, and resolution to use
+    fn reocr_file(&self, file_id: Uuid, options: OcrOptions) -> impl Future<Output = Result<Job>>;
 
-    async fn download_file(&self, file_id: Uuid) -> Result<Vec<u8>> {
-        let path = format!("/files/{}/content", file_id);
-        let response = self.send(Method::GET, &path).await?;
-        let response = response.error_for_status()?;
-        let bytes = response.bytes().await?;
-        Ok(bytes.to_vec())
-    }
+    /// Gets the barcodes and QR codes decoded from a processed file.
+    ///
+    /// Each barcode carries its page and position, so documents such as
+    /// scanned mail can be routed by the codes printed on them.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    fn get_barcodes(&self, file_id: Uuid) -> impl Future<Output = Result<Barcodes>>;
 
-    async fn upload_file(
+    /// Uploads a file to a workspace, streaming its content from a reader.
+    ///
+    /// The content is read chunk by chunk as the request body is sent, so
+    /// the file never has to fit in memory. Because the reader is consumed,
+    /// the upload cannot be replayed on a `307`/`308` redirect, and the
+    /// configured maximum upload size cannot be checked before sending.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `file_name` - The file name
+    /// * `reader` - Source of the file content
+    fn upload_file_stream<R>(
         &self,
         workspace_id: Uuid,
         file_name: &str,
-        file_data: Vec<u8>,
-    ) -> Result<File> {
-        let path = format!("/workspaces/{}/files/", workspace_id);
-
-        let file_part = Part::bytes(file_data).file_name(file_name.to_string());
-        let form = Form::new().part("file", file_part);
-
-        let response = self.send_multipart(Method::POST, &path, form).await?;
-        let response = response.error_for_status()?;
-        let files: Vec<File> = response.json().await?;
-
-        // API returns array of uploaded files, we uploaded one
-        files
-            .into_iter()
-            .next()
-            .ok_or_else(|| crate::error::Error::Api("upload returned no files".into()))
-    }
+        reader: R,
+    ) -> impl Future<Output = Result<File>>
+    where
+        R: AsyncRead + Send + 'static;
 
-    async fn delete_files_batch(&self, workspace_id: Uuid, file_ids: Vec<Uuid>) -> Result<()> {
-        let path = format!("/workspaces/{}/files/batch", workspace_id);
-        let body = DeleteFiles { file_ids };
-        let response = self.send_json(Method::DELETE, &path, &body).await?;
-        response.error_for_status()?;
-        Ok(())
-    }
+    /// Uploads a file from the local filesystem, streaming its content.
+    ///
+    /// The file name is taken from the last component of `path`. Not
+    /// available on `wasm32` targets, which have no file system.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `path` - Path of the file to upload
+    #[cfg(not(target_arch = "wasm32"))]
+    fn upload_file_from_path(
+        &self,
+        workspace_id: Uuid,
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = Result<File>>;
 
-    async fn download_files_batch(
+    /// Uploads a file in parts through the chunked upload endpoints.
+    ///
+    /// Use this for files too large for a single request. The reader is
+    /// consumed `chunk_size` bytes at a time, so at most one part is held in
+    /// memory. Each part is verified against the checksum the server
+    /// reports and resent on mismatch, up to
+    /// [`RetryPolicy::checksum_retries`] times; transport errors are retried
+    /// according to the [`RetryPolicy`]. If a part cannot be uploaded, the
+    /// upload is aborted and the error is returned.
+    ///
+    /// [`RetryPolicy`]: crate::RetryPolicy
+    /// [`RetryPolicy::checksum_retries`]: crate::RetryPolicy::checksum_retries
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `file_name` - The file name
+    /// * `reader` - Source of the file content
+    /// * `chunk_size` - Size of each part in bytes (at least 1)
+    fn upload_file_multipart<R>(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        reader: R,
+        chunk_size: usize,
+    ) -> impl Future<Output = Result<File>>
+    where
+        R: AsyncRead + Unpin;
+
+    /// Uploads a file in parts, reporting progress after each part.
+    ///
+    /// Behaves like [`upload_file_multipart`](Self::upload_file_multipart).
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `file_name` - The file name
+    /// * `reader` - Source of the file content
+    /// * `chunk_size` - Size of each part in bytes (at least 1)
+    /// * `on_progress` - Callback invoked after each uploaded part
+    fn upload_file_multipart_with_progress<R, F>(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        reader: R,
+        chunk_size: usize,
+        on_progress: F,
+    ) -> impl Future<Output = Result<File>>
+    where
+        R: AsyncRead + Unpin,
+        F: FnMut(&UploadProgress);
+
+    /// Uploads a file to a workspace with additional options.
+    ///
+    /// If a naming conflict occurred, the strategy used to resolve it is
+    /// reported in [`File::applied_conflict_strategy`].
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `file_name` - The file name
+    /// * `file_data` - The file content as bytes
+    /// * `options` - Upload options (external identifier, conflict strategy)
+    fn upload_file_with_options(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        file_data: Vec<u8>,
+        options: UploadFileOptions,
+    ) -> impl Future<Output = Result<File>>;
+
+    /// Uploads several files atomically.
+    ///
+    /// The files are staged on the server and committed together, so either
+    /// all of them land in the workspace or none do. If staging any file
+    /// fails, the transaction is aborted and the error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `items` - Files to upload
+    fn upload_transaction(
+        &self,
+        workspace_id: Uuid,
+        items: Vec<UploadItem>,
+    ) -> impl Future<Output = Result<Vec<File>>>;
+
+    /// Starts a transaction for uploading several files atomically.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn begin_upload_transaction(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<UploadTransaction>>;
+
+    /// Stages a file in an upload transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction identifier
+    /// * `item` - The file to stage
+    fn stage_transaction_file(
+        &self,
+        transaction_id: Uuid,
+        item: UploadItem,
+    ) -> impl Future<Output = Result<StagedFile>>;
+
+    /// Commits an upload transaction, adding all staged files to the workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction identifier
+    fn commit_upload_transaction(
+        &self,
+        transaction_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<File>>>;
+
+    /// Aborts an upload transaction, discarding all staged files.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_id` - The transaction identifier
+    fn abort_upload_transaction(&self, transaction_id: Uuid) -> impl Future<Output = Result<()>>;
+
+    /// Deletes multiple files in a batch.
+    ///
+    /// Each file is deleted independently. The result lists the IDs of the
+    /// deleted files and the reason each remaining file was not deleted.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `file_ids` - List of file IDs to delete
+    fn delete_files_batch(
+        &self,
+        workspace_id: Uuid,
+        file_ids: Vec<Uuid>,
+    ) -> impl Future<Output = Result<BulkResult<Uuid>>>;
+
+    /// Deletes multiple files in a batch with additional options.
+    ///
+    /// With [`DeleteFilesOptions::continue_on_error`], files that are
+    /// already gone or may not be deleted are reported in
+    /// [`BulkResult::failed`] (see [`BulkFailure::is_not_found`] and
+    /// [`BulkFailure::is_forbidden`]) instead of failing the whole call.
+    ///
+    /// [`BulkFailure::is_not_found`]: crate::model::BulkFailure::is_not_found
+    /// [`BulkFailure::is_forbidden`]: crate::model::BulkFailure::is_forbidden
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `file_ids` - List of file IDs to delete
+    /// * `options` - Deletion options
+    fn delete_files_batch_with_options(
+        &self,
+        workspace_id: Uuid,
+        file_ids: Vec<Uuid>,
+        options: DeleteFilesOptions,
+    ) -> impl Future<Output = Result<BulkResult<Uuid>>>;
+
+    /// Downloads multiple files as an archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `file_ids` - List of file IDs to download (empty for all files)
+    /// * `format` - Archive format (ZIP or TAR.GZ)
+    fn download_files_batch(
+        &self,
+        workspace_id: Uuid,
+        file_ids: Vec<Uuid>,
+        format: ArchiveFormat,
+    ) -> impl Future<Output = Result<Vec<u8>>>;
+
+    /// Downloads multiple files and packs them into a ZIP archive locally.
+    ///
+    /// This produces the same output as [`download_files_batch`] with
+    /// [`ArchiveFormat::Zip`], but builds the archive on the client. Use it
+    /// when the server-side batch endpoint is unavailable or when the files
+    /// belong to different workspaces. Entries are named after each file's
+    /// display name, with a numeric suffix added to duplicates.
+    ///
+    /// Each entry is downloaded with
+    /// [`download_file_stream`](Self::download_file_stream), so it is
+    /// decrypted and reported to the progress callback the same way. The
+    /// maximum response size applies to each file, not to the archive.
+    ///
+    /// [`download_files_batch`]: FilesService::download_files_batch
+    ///
+    /// # Arguments
+    ///
+    /// * `file_ids` - List of file IDs to include
+    #[cfg(feature = "zip")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "zip")))]
+    fn download_files_zip(&self, file_ids: Vec<Uuid>) -> impl Future<Output = Result<Vec<u8>>>;
+
+    /// Uploads many files with bounded concurrency.
+    ///
+    /// At most `max_in_flight` uploads run at the same time. Individual
+    /// failures do not abort the remaining uploads; each item gets its own
+    /// outcome in the returned report, in the same order as `items`.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `items` - Files to upload
+    /// * `max_in_flight` - Maximum number of concurrent uploads (at least 1)
+    fn upload_files_concurrent(
+        &self,
+        workspace_id: Uuid,
+        items: Vec<UploadItem>,
+        max_in_flight: usize,
+    ) -> impl Future<Output = UploadReport>;
+}
+
+/// Options for uploading a file.
+#[derive(Clone, Debug, Default)]
+pub struct UploadFileOptions {
+    /// External identifier to attach to the file.
+    pub external_id: Option<String>,
+    /// How to handle an existing file with the same name.
+    ///
+    /// The server default applies if not set.
+    pub conflict_strategy: Option<ConflictStrategy>,
+    /// Additional metadata to attach to the file.
+    pub metadata: Option<serde_json::Value>,
+    /// Key to encrypt the content with before it leaves the client.
+    #[cfg(feature = "client-encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "client-encryption")))]
+    pub encryption_key: Option<FileEncryptionKey>,
+}
+
+impl UploadFileOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the external identifier.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Sets the naming conflict strategy.
+    pub fn conflict_strategy(mut self, strategy: ConflictStrategy) -> Self {
+        self.conflict_strategy = Some(strategy);
+        self
+    }
+
+    /// Sets additional metadata.
+    pub fn metadata(mut self, metadata: serde_json::Value) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Encrypts the content on the client before uploading it.
+    ///
+    /// A fresh data key is generated for the file and stored, wrapped with
+    /// `key`, in the file metadata. Metadata set with [`metadata`] must be a
+    /// JSON object so the envelope can be added to it. Read the content back
+    /// with [`FilesService::download_file_decrypted`], or through a client
+    /// whose options carry the key, set with
+    /// [`RequestOptions::with_encryption_key`]. Without the key, downloads
+    /// return the stored ciphertext.
+    ///
+    /// [`RequestOptions::with_encryption_key`]: crate::RequestOptions::with_encryption_key
+    ///
+    /// [`metadata`]: UploadFileOptions::metadata
+    #[cfg(feature = "client-encryption")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "client-encryption")))]
+    pub fn encrypt_with(mut self, key: FileEncryptionKey) -> Self {
+        self.encryption_key = Some(key);
+        self
+    }
+}
+
+/// Options for deleting files in a batch.
+#[derive(Clone, Debug, Default)]
+pub struct DeleteFilesOptions {
+    /// Whether to keep deleting the remaining files after one fails.
+    pub continue_on_error: bool,
+}
+
+impl DeleteFilesOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps deleting the remaining files after one fails, reporting each
+    /// failure in the result.
+    pub fn continue_on_error(mut self) -> Self {
+        self.continue_on_error = true;
+        self
+    }
+}
+
+/// Options for polling a file until it finishes processing.
+#[derive(Clone, Debug)]
+pub struct PollOptions {
+    /// Delay before the first poll. Values below 100 milliseconds are
+    /// raised to 100 milliseconds.
+    pub interval: Duration,
+    /// How long to wait in total before giving up.
+    pub timeout: Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            interval: PROCESSING_POLL_INITIAL,
+            timeout: PROCESSING_TIMEOUT,
+        }
+    }
+}
+
+impl PollOptions {
+    /// Creates options with a one second interval and a ten minute timeout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the delay before the first poll. Values below 100 milliseconds
+    /// are raised to 100 milliseconds.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval.max(PROCESSING_POLL_MIN);
+        self
+    }
+
+    /// Sets how long to wait in total before giving up.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// File to upload as part of a bulk operation.
+#[derive(Clone, Debug)]
+pub struct UploadItem {
+    /// The file name.
+    pub file_name: String,
+    /// The file content.
+    pub data: Vec<u8>,
+    /// External identifier to attach to the file.
+    pub external_id: Option<String>,
+    /// How to handle an existing file with the same name.
+    pub conflict_strategy: Option<ConflictStrategy>,
+}
+
+impl UploadItem {
+    /// Creates a new upload item.
+    pub fn new(file_name: impl Into<String>, data: Vec<u8>) -> Self {
+        Self {
+            file_name: file_name.into(),
+            data,
+            external_id: None,
+            conflict_strategy: None,
+        }
+    }
+
+    /// Sets the external identifier.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Sets the naming conflict strategy.
+    pub fn conflict_strategy(mut self, strategy: ConflictStrategy) -> Self {
+        self.conflict_strategy = Some(strategy);
+        self
+    }
+}
+
+/// Outcome of a single upload in a bulk operation.
+#[derive(Debug)]
+pub struct UploadOutcome {
+    /// The file name that was uploaded.
+    pub file_name: String,
+    /// Size of the file content in bytes.
+    pub bytes: u64,
+    /// Time taken by the upload.
+    pub duration: Duration,
+    /// The uploaded file, or the error that occurred.
+    pub result: Result<File>,
+}
+
+/// Aggregate statistics for a bulk upload.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UploadSummary {
+    /// Number of uploads that succeeded.
+    pub succeeded: usize,
+    /// Number of uploads that failed.
+    pub failed: usize,
+    /// Total bytes of all items.
+    pub total_bytes: u64,
+    /// Bytes of the items that were uploaded successfully.
+    pub uploaded_bytes: u64,
+    /// Wall-clock time of the whole operation.
+    pub elapsed: Duration,
+}
+
+/// Report of a bulk upload.
+#[derive(Debug)]
+pub struct UploadReport {
+    /// Per-item outcomes, in input order.
+    pub outcomes: Vec<UploadOutcome>,
+    /// Aggregate statistics.
+    pub summary: UploadSummary,
+}
+
+impl UploadReport {
+    /// Returns `true` if every upload succeeded.
+    pub fn is_success(&self) -> bool {
+        self.summary.failed == 0
+    }
+
+    /// Returns an iterator over the failed outcomes.
+    pub fn failures(&self) -> impl Iterator<Item = &UploadOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.result.is_err())
+    }
+}
+
+/// Options for listing files.
+#[derive(Clone, Debug, Default)]
+pub struct ListFilesOptions {
+    /// Filter by external identifier.
+    pub external_id: Option<String>,
+    /// Filter by file formats.
+    pub formats: Option<Vec<FileFormat>>,
+    /// Search query.
+    pub search: Option<String>,
+    /// Pagination cursor.
+    pub after: Option<Cursor>,
+    /// Resume after the given item instead of at a cursor.
+    pub starting_after: Option<PageAnchor>,
+    /// Maximum number of results.
+    pub limit: Option<i32>,
+}
+
+impl ListFilesOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the file formats filter.
+    pub fn formats(mut self, formats: Vec<FileFormat>) -> Self {
+        self.formats = Some(formats);
+        self
+    }
+
+    /// Sets the search query.
+    pub fn search(mut self, query: impl Into<String>) -> Self {
+        self.search = Some(query.into());
+        self
+    }
+
+    /// Sets the external identifier filter.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    /// Sets the pagination cursor.
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Resumes the listing after the given item.
+    pub fn starting_after(mut self, anchor: PageAnchor) -> Self {
+        self.starting_after = Some(anchor);
+        self
+    }
+
+    /// Sets the maximum number of results.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl FilesService for NvisyClient {
+    async fn list_files(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListFilesOptions>,
+    ) -> Result<FilesPage> {
+        let path = format!("/workspaces/{}/files/", workspace_id);
+        let opts = options.unwrap_or_default();
+
+        let query = Query::new()
+            .list("formats", opts.formats.iter().flatten(), ListStyle::Repeat)
+            .opt("search", opts.search.as_ref())
+            .opt("externalId", opts.external_id.as_ref())
+            .opt("after", opts.after.as_ref())
+            .anchor(opts.starting_after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let page: FilesPage = self.read_json(response).await?;
+        Ok(page)
+    }
+
+    fn list_files_stream(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListFilesOptions>,
+    ) -> impl Stream<Item = Result<File>> + '_ {
+        self.paginate_files(workspace_id, options).into_stream()
+    }
+
+    fn paginate_files(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListFilesOptions>,
+    ) -> Paginator<'_, File> {
+        let mut options = options.unwrap_or_default();
+        let first = options.after.take();
+        let resume_options = options.clone();
+        Paginator::new(first, move |after| {
+            let options = ListFilesOptions {
+                after,
+                ..options.clone()
+            };
+            self.list_files(workspace_id, Some(options))
+        })
+        .with_resume(move |anchor| {
+            let options = ListFilesOptions {
+                after: None,
+                starting_after: Some(anchor),
+                ..resume_options.clone()
+            };
+            self.list_files(workspace_id, Some(options))
+        })
+    }
+
+    async fn find_file_by_external_id(
+        &self,
+        workspace_id: Uuid,
+        external_id: &str,
+    ) -> Result<Option<File>> {
+        let options = ListFilesOptions::new().external_id(external_id).limit(1);
+        let page = self.list_files(workspace_id, Some(options)).await?;
+        Ok(page.items.into_iter().next())
+    }
+
+    async fn get_file(&self, file_id: Uuid) -> Result<File> {
+        let path = format!("/files/{}", file_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let file: File = self.read_json(response).await?;
+        Ok(file)
+    }
+
+    async fn get_files_batch(&self, file_ids: &[Uuid]) -> Result<Batch<File>> {
+        batch::get_batch(self, "/files/batch", file_ids, |file_id| {
+            self.get_file(file_id)
+        })
+        .await
+    }
+
+    async fn update_file(&self, file_id: Uuid, update: UpdateFile) -> Result<File> {
+        let path = format!("/files/{}", file_id);
+        let response = self.send_json(Method::PATCH, &path, &update).await?;
+        let response = response.error_for_status()?;
+        let file: File = self.read_json(response).await?;
+        Ok(file)
+    }
+
+    async fn delete_file(&self, file_id: Uuid) -> Result<()> {
+        let path = format!("/files/{}", file_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+
+    async fn download_file(&self, file_id: Uuid) -> Result<Vec<u8>> {
+        #[cfg(feature = "client-encryption")]
+        if let Some(key) = self.options().encryption_key() {
+            return self.download_file_decrypted(file_id, key).await;
+        }
+
+        if self.config().download_cache().is_none() {
+            return fetch_content(self, file_id).await;
+        }
+        let file = self.get_file(file_id).await?;
+        download_content(self, &file).await
+    }
+
+    async fn create_signed_url(&self, file_id: Uuid, ttl: Option<Duration>) -> Result<SignedUrl> {
+        let path = format!("/files/{}/signed-url", file_id);
+        let request = CreateSignedUrl {
+            expires_in_secs: ttl.map(|ttl| ttl.as_secs()),
+        };
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let signed_url: SignedUrl = self.read_json(response).await?;
+        Ok(signed_url)
+    }
+
+    async fn download_file_verified(&self, file_id: Uuid) -> Result<Vec<u8>> {
+        let file = self.get_file(file_id).await?;
+        let Some(expected) = file.checksum.as_deref() else {
+            let content = download_content(self, &file).await?;
+            return decrypt_download(self, &file, content);
+        };
+
+        let retries = self.retry_policy().checksum_retries();
+        let file_size = usize::try_from(file.file_size).unwrap_or_default();
+        let mut content = download_content(self, &file).await?;
+        let mut attempt = 0;
+        loop {
+            let actual = format!("{:x}", Sha256::digest(&content));
+            if actual.eq_ignore_ascii_case(expected) {
+                return decrypt_download(self, &file, content);
+            }
+
+            let err = Error::ChecksumMismatch {
+                expected: expected.to_string(),
+                actual,
+            };
+            if attempt == retries {
+                return Err(err);
+            }
+            attempt += 1;
+            self.notify_retry(attempt, &err);
+
+            if content.len() < file_size {
+                resume_content(self, file_id, &mut content).await?;
+            } else {
+                content = fetch_content(self, file_id).await?;
+            }
+        }
+    }
+
+    fn download_file_stream(&self, file_id: Uuid) -> impl Stream<Item = Result<Bytes>> + '_ {
+        let chunks = stream::once(async move {
+            let path = format!("/files/{}/content", file_id);
+            let response = self.send(Method::GET, &path).await?;
+            let response = response.error_for_status()?;
+            let total = response.content_length();
+            let chunks = response.bytes_stream().map_err(Error::from);
+            Ok::<_, Error>(progress::track(
+                self.options().progress().cloned(),
+                chunks,
+                total,
+            ))
+        })
+        .try_flatten();
+
+        #[cfg(feature = "client-encryption")]
+        let chunks = match self.options().encryption_key() {
+            Some(_) => Either::Left(stream::once(self.download_file(file_id)).map_ok(Bytes::from)),
+            None => Either::Right(chunks),
+        };
+        chunks
+    }
+
+    async fn download_file_to_writer<W>(&self, file_id: Uuid, mut writer: W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let buffered = self.config().download_cache().is_some();
+        #[cfg(feature = "client-encryption")]
+        let buffered = buffered || self.options().encryption_key().is_some();
+        if buffered {
+            let content = self.download_file(file_id).await?;
+            writer.write_all(&content).await?;
+            writer.flush().await?;
+            return Ok(content.len() as u64);
+        }
+
+        let path = format!("/files/{}/content", file_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+
+        let mut written = 0;
+        let total = response.content_length();
+        let progress = self.options().progress();
+        let mut chunks = response.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+            if let Some(progress) = progress {
+                progress.report(written, total);
+            }
+        }
+        writer.flush().await?;
+
+        Ok(written)
+    }
+
+    #[cfg(feature = "client-encryption")]
+    async fn download_file_decrypted(
+        &self,
+        file_id: Uuid,
+        key: &FileEncryptionKey,
+    ) -> Result<Vec<u8>> {
+        let file = self.get_file(file_id).await?;
+        let content = download_content(self, &file).await?;
+        match file.encryption_envelope()? {
+            Some(envelope) => key.decrypt(&envelope, &content),
+            None => Ok(content),
+        }
+    }
+
+    async fn upload_file(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        file_data: Vec<u8>,
+    ) -> Result<File> {
+        self.upload_file_with_options(workspace_id, file_name, file_data, UploadFileOptions::new())
+            .await
+    }
+
+    async fn upload_and_process(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        file_data: Vec<u8>,
+    ) -> Result<ProcessedFile> {
+        let file = self.upload_file(workspace_id, file_name, file_data).await?;
+        let file = poll_until_processed(self, file, &PollOptions::default()).await?;
+        let summary = self.get_file_summary(file.file_id).await?;
+        Ok(ProcessedFile {
+            file,
+            extraction: summary.extraction,
+            classification: summary.classification,
+        })
+    }
+
+    async fn wait_for_processing(&self, file_id: Uuid, options: PollOptions) -> Result<File> {
+        let file = self.get_file(file_id).await?;
+        poll_until_processed(self, file, &options).await
+    }
+
+    fn watch_file_status(&self, file_id: Uuid) -> impl Stream<Item = Result<FileStatus>> + '_ {
+        // The state is the last status yielded and the delay before the
+        // next poll, or `None` once a terminal status has been yielded.
+        let initial: Option<(Option<FileStatus>, Duration)> = Some((None, Duration::ZERO));
+        stream::try_unfold(initial, move |state| async move {
+            let Some((last, mut delay)) = state else {
+                return Ok(None);
+            };
+            loop {
+                runtime::sleep(delay).await;
+                delay = (delay * 2).clamp(PROCESSING_POLL_INITIAL, PROCESSING_POLL_MAX);
+
+                let status = self.get_file(file_id).await?.status;
+                if last.as_ref() != Some(&status) {
+                    let next = (!status.is_terminal())
+                        .then(|| (Some(status.clone()), PROCESSING_POLL_INITIAL));
+                    return Ok(Some((status, next)));
+                }
+            }
+        })
+    }
+
+    async fn get_file_summary(&self, file_id: Uuid) -> Result<FileSummary> {
+        let path = format!("/files/{}/summary", file_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let summary: FileSummary = self.read_json(response).await?;
+        Ok(summary)
+    }
+
+    async fn list_versions(&self, file_id: Uuid) -> Result<Vec<FileVersion>> {
+        let path = format!("/files/{}/versions/", file_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let versions: Vec<FileVersion> = self.read_json(response).await?;
+        Ok(versions)
+    }
+
+    async fn compare_versions(
+        &self,
+        file_id: Uuid,
+        from_version: u32,
+        to_version: u32,
+    ) -> Result<VersionDiff> {
+        let path = format!("/files/{}/versions/compare", file_id);
+        let query = Query::new()
+            .push("from", &from_version)
+            .push("to", &to_version);
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let diff: VersionDiff = self.read_json(response).await?;
+        Ok(diff)
+    }
+
+    async fn get_version_content(&self, file_id: Uuid, version: u32) -> Result<Vec<u8>> {
+        let path = format!("/files/{}/versions/{}/content", file_id, version);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let bytes = self.read_content(response).await?;
+        Ok(bytes.to_vec())
+    }
+
+    async fn get_ocr_report(&self, file_id: Uuid) -> Result<OcrReport> {
+        let path = format!("/files/{}/ocr", file_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let report: OcrReport = self.read_json(response).await?;
+        Ok(report)
+    }
+
+    async fn reocr_file(&self, file_id: Uuid, options: OcrOptions) -> Result<Job> {
+        let path = format!("/files/{}/ocr", file_id);
+        let response = self.send_json(Method::POST, &path, &options).await?;
+        let response = response.error_for_status()?;
+        let job: Job = self.read_json(response).await?;
+        Ok(job)
+    }
+
+    async fn get_barcodes(&self, file_id: Uuid) -> Result<Barcodes> {
+        let path = format!("/files/{}/barcodes", file_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let barcodes: Barcodes = self.read_json(response).await?;
+        Ok(barcodes)
+    }
+
+    async fn upload_file_stream<R>(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        reader: R,
+    ) -> Result<File>
+    where
+        R: AsyncRead + Send + 'static,
+    {
+        let part = reader_part(reader, self.options().progress())
+            .await?
+            .file_name(file_name.to_string());
+        upload_part(self, workspace_id, part).await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn upload_file_from_path(
+        &self,
+        workspace_id: Uuid,
+        path: impl AsRef<Path>,
+    ) -> Result<File> {
+        let path = path.as_ref();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let file = tokio::fs::File::open(path).await?;
+        let len = file.metadata().await?.len();
+        self.check_upload_size(len)?;
+
+        let progress = self.options().progress().cloned();
+        let chunks = progress::track(progress, reader_stream(file), Some(len));
+        let part = Part::stream_with_length(Body::wrap_stream(chunks), len).file_name(file_name);
+        upload_part(self, workspace_id, part).await
+    }
+
+    async fn upload_file_with_options(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        file_data: Vec<u8>,
+        options: UploadFileOptions,
+    ) -> Result<File> {
+        let path = format!("/workspaces/{}/files/", workspace_id);
+
+        #[cfg(feature = "client-encryption")]
+        let (file_data, options) = encrypt_upload(file_data, options)?;
+
+        // Keep the content in a cheaply clonable buffer so the form can be
+        // rebuilt if the request has to be replayed.
+        let file_data = Bytes::from(file_data);
+        let file_len = file_data.len() as u64;
+        self.check_upload_size(file_len)?;
+        let progress = self.options().progress();
+        let form = || upload_form(bytes_part(&file_data, file_name, progress), &options);
+
+        let response = self.send_multipart(Method::POST, &path, form).await?;
+        let response = response.error_for_status()?;
+        let files: Vec<File> = self.read_json(response).await?;
+        first_uploaded(files)
+    }
+
+    async fn upload_file_multipart<R>(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        reader: R,
+        chunk_size: usize,
+    ) -> Result<File>
+    where
+        R: AsyncRead + Unpin,
+    {
+        self.upload_file_multipart_with_progress(
+            workspace_id,
+            file_name,
+            reader,
+            chunk_size,
+            |_| {},
+        )
+        .await
+    }
+
+    async fn upload_file_multipart_with_progress<R, F>(
+        &self,
+        workspace_id: Uuid,
+        file_name: &str,
+        mut reader: R,
+        chunk_size: usize,
+        mut on_progress: F,
+    ) -> Result<File>
+    where
+        R: AsyncRead + Unpin,
+        F: FnMut(&UploadProgress),
+    {
+        let path = format!("/workspaces/{}/uploads/", workspace_id);
+        let request = CreateMultipartUpload {
+            file_name: file_name.to_string(),
+        };
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let upload: MultipartUpload = self.read_json(response).await?;
+
+        // Later requests address the upload, so charge them to the
+        // workspace explicitly.
+        let scoped = self.with_options(RequestOptions::new().with_workspace(workspace_id));
+        let client: &NvisyClient = &scoped;
+        let mut parts = Vec::new();
+        let mut progress = UploadProgress::default();
+        let result = async {
+            loop {
+                let chunk = read_chunk(&mut reader, chunk_size.max(1)).await?;
+                // An empty file is still uploaded as a single empty part.
+                if chunk.is_empty() && !parts.is_empty() {
+                    break;
+                }
+                let last = chunk.len() < chunk_size.max(1);
+                self.check_upload_size(progress.bytes_uploaded + chunk.len() as u64)?;
+                let part_number = parts.len() as u32 + 1;
+                let part = upload_chunk(client, upload.upload_id, part_number, chunk).await?;
+
+                progress.parts_uploaded = part_number;
+                progress.bytes_uploaded += part.size;
+                on_progress(&progress);
+                if let Some(transfer) = self.options().progress() {
+                    transfer.report(progress.bytes_uploaded, None);
+                }
+                parts.push(part);
+                if last {
+                    break;
+                }
+            }
+            Ok(())
+        }
+        .await;
+
+        if let Err(err) = result {
+            // The upload expires on its own if aborting fails, so the
+            // original error is the one worth reporting.
+            let path = format!("/uploads/{}", upload.upload_id);
+            let _ = client.send(Method::DELETE, &path).await;
+            return Err(err);
+        }
+
+        let path = format!("/uploads/{}/complete", upload.upload_id);
+        let request = CompleteMultipartUpload { parts };
+        let response = client.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let file: File = client.read_json(response).await?;
+        Ok(file)
+    }
+
+    async fn upload_transaction(
+        &self,
+        workspace_id: Uuid,
+        items: Vec<UploadItem>,
+    ) -> Result<Vec<File>> {
+        let transaction = self.begin_upload_transaction(workspace_id).await?;
+        // Later requests address the transaction, so charge them to the
+        // workspace explicitly.
+        let scoped = self.with_options(RequestOptions::new().with_workspace(workspace_id));
+        for item in items {
+            if let Err(err) = scoped
+                .stage_transaction_file(transaction.transaction_id, item)
+                .await
+            {
+                // The transaction expires on its own if aborting fails, so
+                // the staging error is the one worth reporting.
+                let _ = scoped
+                    .abort_upload_transaction(transaction.transaction_id)
+                    .await;
+                return Err(err);
+            }
+        }
+        scoped
+            .commit_upload_transaction(transaction.transaction_id)
+            .await
+    }
+
+    async fn begin_upload_transaction(&self, workspace_id: Uuid) -> Result<UploadTransaction> {
+        let path = format!("/workspaces/{}/upload-transactions/", workspace_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let transaction: UploadTransaction = self.read_json(response).await?;
+        Ok(transaction)
+    }
+
+    async fn stage_transaction_file(
+        &self,
+        transaction_id: Uuid,
+        item: UploadItem,
+    ) -> Result<StagedFile> {
+        let path = format!("/upload-transactions/{}/files", transaction_id);
+
+        let file_data = Bytes::from(item.data);
+        self.check_upload_size(file_data.len() as u64)?;
+        let options = UploadFileOptions {
+            external_id: item.external_id,
+            conflict_strategy: item.conflict_strategy,
+            ..Default::default()
+        };
+        let progress = self.options().progress();
+        let form = || upload_form(bytes_part(&file_data, &item.file_name, progress), &options);
+
+        let response = self.send_multipart(Method::POST, &path, form).await?;
+        let response = response.error_for_status()?;
+        let staged: StagedFile = self.read_json(response).await?;
+        Ok(staged)
+    }
+
+    async fn commit_upload_transaction(&self, transaction_id: Uuid) -> Result<Vec<File>> {
+        let path = format!("/upload-transactions/{}/commit", transaction_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let files: Vec<File> = self.read_json(response).await?;
+        Ok(files)
+    }
+
+    async fn abort_upload_transaction(&self, transaction_id: Uuid) -> Result<()> {
+        let path = format!("/upload-transactions/{}", transaction_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+
+    async fn delete_files_batch(
+        &self,
+        workspace_id: Uuid,
+        file_ids: Vec<Uuid>,
+    ) -> Result<BulkResult<Uuid>> {
+        self.delete_files_batch_with_options(workspace_id, file_ids, DeleteFilesOptions::new())
+            .await
+    }
+
+    async fn delete_files_batch_with_options(
+        &self,
+        workspace_id: Uuid,
+        file_ids: Vec<Uuid>,
+        options: DeleteFilesOptions,
+    ) -> Result<BulkResult<Uuid>> {
+        let path = format!("/workspaces/{}/files/batch", workspace_id);
+        let body = DeleteFiles {
+            file_ids,
+            continue_on_error: options.continue_on_error.then_some(true),
+        };
+        let response = self.send_json(Method::DELETE, &path, &body).await?;
+        let response = response.error_for_status()?;
+
+        // Servers that predate per-file results reply with an empty body
+        // once every file has been deleted.
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(BulkResult {
+                succeeded: body.file_ids,
+                failed: Vec::new(),
+            });
+        }
+        let result: BulkResult<Uuid> = self.read_json(response).await?;
+        Ok(result)
+    }
+
+    async fn download_files_batch(
         &self,
         workspace_id: Uuid,
         file_ids: Vec<Uuid>,
@@ -249,7 +1540,1159 @@ impl FilesService for NvisyClient {
         let body = DownloadFiles { file_ids, format };
         let response = self.send_json(Method::GET, &path, &body).await?;
         let response = response.error_for_status()?;
-        let bytes = response.bytes().await?;
+        let bytes = self.read_bytes(response).await?;
         Ok(bytes.to_vec())
     }
+
+    #[cfg(feature = "zip")]
+    async fn download_files_zip(&self, file_ids: Vec<Uuid>) -> Result<Vec<u8>> {
+        use std::collections::HashMap;
+
+        use async_zip::base::write::ZipFileWriter;
+        use async_zip::{Compression, ZipEntryBuilder};
+        use futures_util::AsyncWriteExt as _;
+
+        let zip_error = |err: async_zip::error::ZipError| std::io::Error::other(err);
+
+        let mut archive = ZipFileWriter::new(Vec::new());
+        let mut names: HashMap<String, usize> = HashMap::new();
+
+        for file_id in file_ids {
+            let file = self.get_file(file_id).await?;
+            let name = unique_entry_name(&mut names, &file.display_name);
+
+            let entry = ZipEntryBuilder::new(name.into(), Compression::Deflate);
+            let mut writer = archive.write_entry_stream(entry).await.map_err(zip_error)?;
+            let mut chunks = std::pin::pin!(self.download_file_stream(file_id));
+            let mut received = 0;
+            while let Some(chunk) = chunks.next().await {
+                let chunk = chunk?;
+                received += chunk.len() as u64;
+                self.check_response_size(received)?;
+                writer.write_all(&chunk).await?;
+            }
+            writer.close().await.map_err(zip_error)?;
+        }
+
+        Ok(archive.close().await.map_err(zip_error)?)
+    }
+
+    async fn upload_files_concurrent(
+        &self,
+        workspace_id: Uuid,
+        items: Vec<UploadItem>,
+        max_in_flight: usize,
+    ) -> UploadReport {
+        let started = Instant::now();
+
+        let mut outcomes: Vec<(usize, UploadOutcome)> = stream::iter(items.into_iter().enumerate())
+            .map(|(index, item)| async move {
+                let bytes = item.data.len() as u64;
+                let item_started = Instant::now();
+                let options = UploadFileOptions {
+                    external_id: item.external_id,
+                    conflict_strategy: item.conflict_strategy,
+                    ..Default::default()
+                };
+                let result = self
+                    .upload_file_with_options(workspace_id, &item.file_name, item.data, options)
+                    .await;
+                let outcome = UploadOutcome {
+                    file_name: item.file_name,
+                    bytes,
+                    duration: item_started.elapsed(),
+                    result,
+                };
+                (index, outcome)
+            })
+            .buffer_unordered(max_in_flight.max(1))
+            .collect()
+            .await;
+        outcomes.sort_by_key(|(index, _)| *index);
+
+        let mut summary = UploadSummary::default();
+        for (_, outcome) in &outcomes {
+            summary.total_bytes += outcome.bytes;
+            if outcome.result.is_ok() {
+                summary.succeeded += 1;
+                summary.uploaded_bytes += outcome.bytes;
+            } else {
+                summary.failed += 1;
+            }
+        }
+        summary.elapsed = started.elapsed();
+
+        UploadReport {
+            outcomes: outcomes.into_iter().map(|(_, outcome)| outcome).collect(),
+            summary,
+        }
+    }
+}
+
+/// Returns a unique archive entry name, suffixing repeated names with a counter.
+#[cfg(feature = "zip")]
+fn unique_entry_name(names: &mut std::collections::HashMap<String, usize>, name: &str) -> String {
+    let count = names.entry(name.to_string()).or_default();
+    *count += 1;
+    if *count == 1 {
+        return name.to_string();
+    }
+
+    match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => format!("{stem} ({count}).{ext}"),
+        _ => format!("{name} ({count})"),
+    }
+}
+
+/// Downloads the content of a file whose metadata was already fetched,
+/// serving it from the download cache if one is configured.
+async fn download_content(client: &NvisyClient, file: &File) -> Result<Vec<u8>> {
+    let cache = client.config().download_cache();
+    let (Some(cache), Some(checksum)) = (cache, file.checksum.as_deref()) else {
+        return fetch_content(client, file.file_id).await;
+    };
+    if let Some(content) = cache.get(checksum).await {
+        if let Some(progress) = client.options().progress() {
+            let len = content.len() as u64;
+            progress.report(len, Some(len));
+        }
+        return Ok(content);
+    }
+
+    let content = fetch_content(client, file.file_id).await?;
+    if format!("{:x}", Sha256::digest(&content)).eq_ignore_ascii_case(checksum) {
+        cache.put(checksum, &content).await;
+    }
+    Ok(content)
+}
+
+/// Decrypts downloaded content if the request options carry an encryption
+/// key and the file was encrypted on the client.
+#[cfg(feature = "client-encryption")]
+fn decrypt_download(client: &NvisyClient, file: &File, content: Vec<u8>) -> Result<Vec<u8>> {
+    let Some(key) = client.options().encryption_key() else {
+        return Ok(content);
+    };
+    match file.encryption_envelope()? {
+        Some(envelope) => key.decrypt(&envelope, &content),
+        None => Ok(content),
+    }
+}
+
+/// Returns downloaded content as stored without client-side encryption.
+#[cfg(not(feature = "client-encryption"))]
+fn decrypt_download(_client: &NvisyClient, _file: &File, content: Vec<u8>) -> Result<Vec<u8>> {
+    Ok(content)
+}
+
+/// Downloads the content of a file, bypassing the download cache.
+async fn fetch_content(client: &NvisyClient, file_id: Uuid) -> Result<Vec<u8>> {
+    let path = format!("/files/{}/content", file_id);
+    let response = client.send(Method::GET, &path).await?;
+    let response = response.error_for_status()?;
+    let bytes = client.read_content(response).await?;
+    Ok(bytes.to_vec())
+}
+
+/// Completes partially downloaded content with a range request.
+///
+/// If the server ignores the range, the content is replaced by the full body.
+async fn resume_content(client: &NvisyClient, file_id: Uuid, content: &mut Vec<u8>) -> Result<()> {
+    let path = format!("/files/{}/content", file_id);
+    let request = client
+        .request_builder(Method::GET, &path)?
+        .header(RANGE, format!("bytes={}-", content.len()));
+    let response = client.execute(request).await?;
+    let response = response.error_for_status()?;
+    if response.status() != StatusCode::PARTIAL_CONTENT {
+        content.clear();
+    }
+    let bytes = client.read_content(response).await?;
+    content.extend_from_slice(&bytes);
+    Ok(())
+}
+
+/// Builds the multipart part for in-memory file content.
+///
+/// With a progress callback the content is sent in chunks so each one can
+/// be reported.
+#[cfg(not(target_arch = "wasm32"))]
+fn bytes_part(file_data: &Bytes, file_name: &str, progress: Option<&TransferProgress>) -> Part {
+    let len = file_data.len() as u64;
+    let part = match progress {
+        Some(progress) => {
+            let data = file_data.clone();
+            let chunks = stream::iter((0..data.len()).step_by(UPLOAD_CHUNK_SIZE).map(
+                move |start| {
+                    let end = (start + UPLOAD_CHUNK_SIZE).min(data.len());
+                    Ok::<_, std::io::Error>(data.slice(start..end))
+                },
+            ));
+            let chunks = progress::track(Some(progress.clone()), chunks, Some(len));
+            Part::stream_with_length(Body::wrap_stream(chunks), len)
+        }
+        None => Part::stream_with_length(file_data.clone(), len),
+    };
+    part.file_name(file_name.to_string())
+}
+
+/// Builds the multipart part for in-memory file content.
+///
+/// Browsers send request bodies in one piece, so progress is not reported.
+#[cfg(target_arch = "wasm32")]
+fn bytes_part(file_data: &Bytes, file_name: &str, _progress: Option<&TransferProgress>) -> Part {
+    Part::stream(file_data.clone()).file_name(file_name.to_string())
+}
+
+/// Builds the multipart part streaming a reader's content.
+#[cfg(not(target_arch = "wasm32"))]
+async fn reader_part<R>(reader: R, progress: Option<&TransferProgress>) -> Result<Part>
+where
+    R: AsyncRead + Send + 'static,
+{
+    let chunks = progress::track(progress.cloned(), reader_stream(reader), None);
+    Ok(Part::stream(Body::wrap_stream(chunks)))
+}
+
+/// Builds the multipart part holding a reader's content.
+///
+/// Browsers cannot stream request bodies, so the content is buffered and
+/// progress is not reported.
+#[cfg(target_arch = "wasm32")]
+async fn reader_part<R>(reader: R, _progress: Option<&TransferProgress>) -> Result<Part>
+where
+    R: AsyncRead + Send + 'static,
+{
+    let mut content = Vec::new();
+    std::pin::pin!(reader).read_to_end(&mut content).await?;
+    Ok(Part::stream(content))
+}
+
+/// Builds the multipart form for uploading a file.
+fn upload_form(file_part: Part, options: &UploadFileOptions) -> Form {
+    let mut form = Form::new().part("file", file_part);
+    if let Some(external_id) = &options.external_id {
+        form = form.text("externalId", external_id.clone());
+    }
+    if let Some(strategy) = options.conflict_strategy {
+        form = form.text("conflictStrategy", strategy.as_str());
+    }
+    if let Some(metadata) = &options.metadata {
+        form = form.text("metadata", metadata.to_string());
+    }
+    form
+}
+
+/// Encrypts the content if the options carry a key, recording the envelope
+/// in the file metadata.
+#[cfg(feature = "client-encryption")]
+fn encrypt_upload(
+    file_data: Vec<u8>,
+    mut options: UploadFileOptions,
+) -> Result<(Vec<u8>, UploadFileOptions)> {
+    use crate::model::ENCRYPTION_METADATA_KEY;
+
+    let Some(key) = options.encryption_key.take() else {
+        return Ok((file_data, options));
+    };
+
+    let mut metadata = match options.metadata.take() {
+        Some(serde_json::Value::Object(metadata)) => metadata,
+        Some(_) => {
+            return Err(Error::Encryption(
+                "metadata of encrypted files must be a JSON object".to_string(),
+            ));
+        }
+        None => serde_json::Map::new(),
+    };
+    let (ciphertext, envelope) = key.encrypt(&file_data)?;
+    metadata.insert(
+        ENCRYPTION_METADATA_KEY.to_string(),
+        serde_json::to_value(envelope)?,
+    );
+    options.metadata = Some(serde_json::Value::Object(metadata));
+
+    Ok((ciphertext, options))
+}
+
+/// Uploads a single streamed file part, which cannot be replayed.
+async fn upload_part(client: &NvisyClient, workspace_id: Uuid, part: Part) -> Result<File> {
+    let path = format!("/workspaces/{}/files/", workspace_id);
+    let form = upload_form(part, &UploadFileOptions::new());
+    let req = client.request_builder(Method::POST, &path)?.multipart(form);
+
+    let response = client.execute(req).await?;
+    let response = response.error_for_status()?;
+    let files: Vec<File> = client.read_json(response).await?;
+    first_uploaded(files)
+}
+
+/// Reads up to `size` bytes, stopping early only at the end of the reader.
+async fn read_chunk<R: AsyncRead + Unpin>(reader: &mut R, size: usize) -> Result<Bytes> {
+    let mut chunk = Vec::with_capacity(size);
+    while chunk.len() < size {
+        let read = (&mut *reader)
+            .take((size - chunk.len()) as u64)
+            .read_to_end(&mut chunk)
+            .await?;
+        if read == 0 {
+            break;
+        }
+    }
+    Ok(Bytes::from(chunk))
+}
+
+/// Uploads one part of a chunked upload, resending it with backoff while the
+/// checksum reported by the server does not match.
+async fn upload_chunk(
+    client: &NvisyClient,
+    upload_id: Uuid,
+    part_number: u32,
+    chunk: Bytes,
+) -> Result<UploadedPart> {
+    let path = format!("/uploads/{}/parts/{}", upload_id, part_number);
+    let expected = format!("{:x}", Sha256::digest(&chunk));
+
+    let policy = client.retry_policy();
+    let retries = policy.checksum_retries();
+    let mut attempt = 0;
+    loop {
+        let req = client
+            .request_builder(Method::PUT, &path)?
+            .header(CONTENT_TYPE, "application/octet-stream")
+            .body(chunk.clone());
+        let response = client.execute(req).await?;
+        let response = response.error_for_status()?;
+        let part: UploadedPart = client.read_json(response).await?;
+        if part.checksum.eq_ignore_ascii_case(&expected) {
+            return Ok(part);
+        }
+
+        let err = Error::ChecksumMismatch {
+            expected: expected.clone(),
+            actual: part.checksum,
+        };
+        if attempt == retries {
+            return Err(err);
+        }
+        attempt += 1;
+        client.notify_retry(attempt, &err);
+        runtime::sleep(policy.backoff(attempt)).await;
+    }
+}
+
+/// Returns the file from an upload response.
+///
+/// The API returns an array of uploaded files; a single file was uploaded.
+fn first_uploaded(files: Vec<File>) -> Result<File> {
+    files
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::Api("upload returned no files".into()))
+}
+
+/// Adapts a reader into a stream of chunks.
+#[cfg(not(target_arch = "wasm32"))]
+fn reader_stream<R>(reader: R) -> impl Stream<Item = std::io::Result<Bytes>> + Send + 'static
+where
+    R: AsyncRead + Send + 'static,
+{
+    stream::try_unfold(Box::pin(reader), |mut reader| async move {
+        let mut chunk = BytesMut::with_capacity(UPLOAD_CHUNK_SIZE);
+        let read = reader.read_buf(&mut chunk).await?;
+        Ok((read > 0).then(|| (chunk.freeze(), reader)))
+    })
+}
+
+/// Polls a file until processing finishes, failing unless it succeeds.
+async fn poll_until_processed(
+    client: &NvisyClient,
+    mut file: File,
+    options: &PollOptions,
+) -> Result<File> {
+    let started = Instant::now();
+    let mut delay = options.interval.max(PROCESSING_POLL_MIN);
+    let max_delay = PROCESSING_POLL_MAX.max(delay);
+    while !file.status.is_terminal() {
+        let elapsed = started.elapsed();
+        if elapsed >= options.timeout {
+            return Err(Error::Timeout {
+                phase: TimeoutPhase::Processing,
+                elapsed,
+                limit: options.timeout,
+            });
+        }
+        runtime::sleep(delay.min(options.timeout - elapsed)).await;
+        delay = (delay * 2).min(max_delay);
+        file = client.get_file(file.file_id).await?;
+    }
+
+    match file.status {
+        FileStatus::Failed => Err(Error::ProcessingFailed {
+            file_id: file.file_id,
+            error: file.processing_error,
+        }),
+        _ => Ok(file),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock;
+    use crate::{NvisyConfig, RetryPolicy};
+
+    /// Metadata for a file whose content is `hello`.
+    const FILE: &str = r#"{"fileId":"00000000-0000-0000-0000-000000000001","displayName":"hello.txt","fileSize":5,"checksum":"2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824","status":"completed","source":"uploaded","tags":[],"processingPriority":5,"fileKnowledge":{},"uploadedBy":"00000000-0000-0000-0000-000000000002","createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-01T00:00:00Z"}"#;
+
+    fn client(base_url: String, retries: u32) -> Result<NvisyClient> {
+        NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .with_retry_policy(RetryPolicy::default().with_checksum_retries(retries))
+            .build_client()
+    }
+
+    #[tokio::test]
+    async fn test_download_verified_retries_truncated_body() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], FILE),
+            mock::response(200, &[], "hel"),
+            mock::response(200, &[], "hello"),
+        ]);
+
+        let file_id = Uuid::from_u128(1);
+        let content = client(base_url, 1)?.download_file_verified(file_id).await?;
+        assert_eq!(content, b"hello");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].header("range"), Some("bytes=3-"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_verified_resumes_truncated_body() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], FILE),
+            mock::response(200, &[], "hel"),
+            mock::response(206, &[("Content-Range", "bytes 3-4/5")], "lo"),
+        ]);
+
+        let file_id = Uuid::from_u128(1);
+        let content = client(base_url, 1)?.download_file_verified(file_id).await?;
+        assert_eq!(content, b"hello");
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[2].request_line(),
+            "GET /files/00000000-0000-0000-0000-000000000001/content HTTP/1.1"
+        );
+        assert_eq!(requests[2].header("range"), Some("bytes=3-"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_verified_reports_mismatch() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], FILE),
+            mock::response(200, &[], "hel"),
+        ]);
+
+        let file_id = Uuid::from_u128(1);
+        let err = client(base_url, 0)?
+            .download_file_verified(file_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ChecksumMismatch { .. }));
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_find_file_by_external_id() -> Result<()> {
+        let page = format!(r#"{{"items":[{FILE}],"hasMore":false}}"#);
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], &page)]);
+
+        let file = client(base_url, 0)?
+            .find_file_by_external_id(Uuid::nil(), "doc-42")
+            .await?;
+        assert_eq!(file.map(|file| file.file_id), Some(Uuid::from_u128(1)));
+
+        let captured = server.join().unwrap();
+        assert!(captured[0].request_line().contains("externalId=doc-42"));
+        assert!(captured[0].request_line().contains("limit=1"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_upload_and_process_waits_for_completion() -> Result<()> {
+        let pending = format!("[{}]", FILE.replace("completed", "pending"));
+        let summary = r#"{"extraction":{"wordCount":1,"languages":["en"]},"classification":{"category":"note","tags":["greeting"]}}"#;
+        let (base_url, server) = mock::serve(vec![
+            mock::response(201, &[], &pending),
+            mock::response(200, &[], FILE),
+            mock::response(200, &[], summary),
+        ]);
+
+        let processed = client(base_url, 0)?
+            .upload_and_process(Uuid::nil(), "hello.txt", b"hello".to_vec())
+            .await?;
+        assert_eq!(processed.file.status, FileStatus::Completed);
+        assert_eq!(processed.extraction.word_count, 1);
+        assert_eq!(processed.classification.category.as_deref(), Some("note"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[2].request_line().contains("/summary"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_upload_and_process_reports_failure() -> Result<()> {
+        let failed = format!("[{}]", FILE.replace("completed", "failed"));
+        let (base_url, server) = mock::serve(vec![mock::response(201, &[], &failed)]);
+
+        let err = client(base_url, 0)?
+            .upload_and_process(Uuid::nil(), "hello.txt", b"hello".to_vec())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::ProcessingFailed { .. }));
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_upload_sends_conflict_strategy() -> Result<()> {
+        let renamed = FILE.replace(
+            r#""status":"completed""#,
+            r#""status":"completed","appliedConflictStrategy":"new_version""#,
+        );
+        let (base_url, server) =
+            mock::serve(vec![mock::response(201, &[], &format!("[{renamed}]"))]);
+
+        let options = UploadFileOptions::new().conflict_strategy(ConflictStrategy::NewVersion);
+        let file = client(base_url, 0)?
+            .upload_file_with_options(Uuid::nil(), "hello.txt", b"hello".to_vec(), options)
+            .await?;
+        assert_eq!(
+            file.applied_conflict_strategy,
+            Some(ConflictStrategy::NewVersion)
+        );
+
+        let requests = server.join().unwrap();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains("name=\"conflictStrategy\"\r\n\r\nnew_version"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_upload_transaction_aborts_on_failure() -> Result<()> {
+        let transaction = r#"{"transactionId":"00000000-0000-0000-0000-000000000009","workspaceId":"00000000-0000-0000-0000-000000000000","expiresAt":"2024-01-01T01:00:00Z","createdAt":"2024-01-01T00:00:00Z"}"#;
+        let staged = r#"{"stagedFileId":"00000000-0000-0000-0000-000000000003","fileName":"form.json","fileSize":2}"#;
+        let (base_url, server) = mock::serve(vec![
+            mock::response(201, &[], transaction),
+            mock::response(201, &[], staged),
+            mock::response(422, &[], ""),
+            mock::response(204, &[], ""),
+        ]);
+
+        let items = vec![
+            UploadItem::new("form.json", b"{}".to_vec()),
+            UploadItem::new("attachment.exe", b"MZ".to_vec()),
+        ];
+        let result = client(base_url, 0)?
+            .upload_transaction(Uuid::nil(), items)
+            .await;
+        assert!(result.is_err());
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[3].request_line(),
+            "DELETE /upload-transactions/00000000-0000-0000-0000-000000000009 HTTP/1.1"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "client-encryption")]
+    #[tokio::test]
+    async fn test_upload_encrypted_stores_envelope() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![mock::response(201, &[], &format!("[{FILE}]"))]);
+
+        let options = UploadFileOptions::new()
+            .metadata(serde_json::json!({ "matter": "42" }))
+            .encrypt_with(FileEncryptionKey::new("kek-1", [7; 32]));
+        client(base_url, 0)?
+            .upload_file_with_options(Uuid::nil(), "hello.txt", b"top secret".to_vec(), options)
+            .await?;
+
+        let requests = server.join().unwrap();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(!body.contains("top secret"));
+        assert!(body.contains(r#""matter":"42""#));
+        assert!(
+            body.contains(r#""clientEncryption":{"algorithm":"xsalsa20poly1305","keyId":"kek-1""#)
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "client-encryption")]
+    #[tokio::test]
+    async fn test_download_decrypts_with_scoped_key() -> Result<()> {
+        let encrypted = FILE.replace(
+            r#""fileKnowledge":{}"#,
+            r#""fileKnowledge":{},"metadata":{"clientEncryption":{"algorithm":"xsalsa20poly1305","keyId":"kek-2","wrappedKey":""}}"#,
+        );
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], &encrypted),
+            mock::response(200, &[], "hello"),
+        ]);
+
+        let scoped = client(base_url, 0)?.with_options(
+            crate::RequestOptions::new()
+                .with_encryption_key(FileEncryptionKey::new("kek-1", [7; 32])),
+        );
+        let err = scoped.download_file(Uuid::from_u128(1)).await.unwrap_err();
+        assert!(matches!(err, Error::Encryption(message) if message.contains("kek-2")));
+
+        assert_eq!(server.join().unwrap().len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_served_from_cache() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("nvisy-cache-{}", Uuid::new_v4()));
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], FILE),
+            mock::response(200, &[], "hello"),
+            mock::response(200, &[], FILE),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .with_download_cache(crate::DownloadCache::new(&dir, 1024))
+            .build_client()?;
+
+        let file_id = Uuid::from_u128(1);
+        assert_eq!(client.download_file(file_id).await?, b"hello");
+        assert_eq!(client.download_file(file_id).await?, b"hello");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        let _ = std::fs::remove_dir_all(dir);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_signed_url_refresh() -> Result<()> {
+        let signed = |url: &str, expires_at: &str| {
+            mock::response(
+                201,
+                &[],
+                &format!(
+                    r#"{{"fileId":"00000000-0000-0000-0000-000000000001","url":"{url}","expiresAt":"{expires_at}"}}"#
+                ),
+            )
+        };
+        let (base_url, server) = mock::serve(vec![
+            signed("https://cdn.example.com/a", "2000-01-01T00:00:00Z"),
+            signed("https://cdn.example.com/b", "2999-01-01T00:00:00Z"),
+        ]);
+        let client = client(base_url, 0)?;
+
+        let mut url = client
+            .create_signed_url(Uuid::from_u128(1), Some(Duration::from_secs(300)))
+            .await?;
+        assert!(url.is_expired());
+        url.refresh(&client, None).await?;
+        assert_eq!(url.url, "https://cdn.example.com/b");
+        assert!(!url.expires_within(Duration::from_secs(60)));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].body, br#"{"expiresInSecs":300}"#);
+        assert_eq!(
+            requests[1].request_line(),
+            "POST /files/00000000-0000-0000-0000-000000000001/signed-url HTTP/1.1"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_file_stream() -> Result<()> {
+        use futures_util::TryStreamExt;
+
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], "hello")]);
+        let client = client(base_url, 0)?;
+
+        let chunks: Vec<Bytes> = client
+            .download_file_stream(Uuid::from_u128(1))
+            .try_collect()
+            .await?;
+        assert_eq!(chunks.concat(), b"hello");
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0].request_line(),
+            "GET /files/00000000-0000-0000-0000-000000000001/content HTTP/1.1"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_from_path_streams_content() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("nvisy-upload-{}.txt", Uuid::new_v4()));
+        std::fs::write(&path, "hello from disk")?;
+        let (base_url, server) = mock::serve(vec![mock::response(201, &[], &format!("[{FILE}]"))]);
+
+        let file = client(base_url, 0)?
+            .upload_file_from_path(Uuid::nil(), &path)
+            .await?;
+        assert_eq!(file.display_name, "hello.txt");
+
+        let requests = server.join().unwrap();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        let file_name = path.file_name().unwrap().to_string_lossy();
+        assert!(body.contains(&format!("filename=\"{file_name}\"")));
+        assert!(body.contains("hello from disk"));
+        let _ = std::fs::remove_file(path);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_files_stream_resumes_after_cursor_expiry() -> Result<()> {
+        use futures_util::TryStreamExt;
+
+        let page = |next: &str| format!(r#"{{"items":[{FILE}],{next}"hasMore":false}}"#);
+        let expired = r#"{"code":"cursor_expired","message":"Cursor expired"}"#;
+        let responses = || {
+            vec![
+                mock::response(200, &[], &page(r#""nextCursor":"c1","#)),
+                mock::response(410, &[], expired),
+                mock::response(200, &[], &page("")),
+            ]
+        };
+
+        let (base_url, server) = mock::serve(responses());
+        let client = client(base_url, 0)?;
+        let files: Vec<File> = client
+            .list_files_stream(Uuid::nil(), None)
+            .try_collect()
+            .await?;
+        assert_eq!(files.len(), 2);
+
+        let requests = server.join().unwrap();
+        assert!(requests[1].request_line().contains("after=c1"));
+        assert!(
+            requests[2]
+                .request_line()
+                .contains("anchorId=00000000-0000-0000-0000-000000000001")
+        );
+
+        let (base_url, server) = mock::serve(responses()[..2].to_vec());
+        let client = self::client(base_url, 0)?;
+        let result = client
+            .paginate_files(Uuid::nil(), None)
+            .strict()
+            .collect_all(usize::MAX)
+            .await;
+        assert!(matches!(result, Err(Error::ApiResponse(body)) if body.is_cursor_expired()));
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_files_batch_falls_back_to_single_lookups() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(
+                404,
+                &[],
+                r#"{"code":"not_found","message":"no such route"}"#,
+            ),
+            mock::response(200, &[], FILE),
+        ]);
+
+        let batch = client(base_url, 0)?
+            .get_files_batch(&[Uuid::from_u128(1)])
+            .await?;
+        assert_eq!(batch.items.len(), 1);
+        assert!(batch.is_complete());
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0].request_line(),
+            "GET /files/batch?ids=00000000-0000-0000-0000-000000000001 HTTP/1.1"
+        );
+        assert!(
+            requests[1]
+                .request_line()
+                .starts_with("GET /files/00000000-0000-0000-0000-000000000001 ")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_multipart_resends_corrupted_part() -> Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        let part = |number: u32, content: &[u8]| {
+            let checksum = format!("{:x}", Sha256::digest(content));
+            let body = format!(
+                r#"{{"partNumber":{number},"size":{},"checksum":"{checksum}"}}"#,
+                content.len()
+            );
+            mock::response(200, &[], &body)
+        };
+        let (base_url, server) = mock::serve(vec![
+            mock::response(
+                200,
+                &[],
+                r#"{"uploadId":"00000000-0000-0000-0000-000000000009","workspaceId":"00000000-0000-0000-0000-000000000000","fileName":"hello.txt","expiresAt":"2024-01-02T00:00:00Z"}"#,
+            ),
+            part(1, b"hex"),
+            part(1, b"hel"),
+            part(2, b"lo"),
+            mock::response(200, &[], FILE),
+        ]);
+        let retries = Arc::new(Mutex::new(Vec::new()));
+        let sink = retries.clone();
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .with_retry_policy(
+                RetryPolicy::default()
+                    .with_checksum_retries(1)
+                    .with_base_backoff(Duration::ZERO),
+            )
+            .on_retry(move |info| {
+                let mismatch = matches!(info.error(), Error::ChecksumMismatch { .. });
+                sink.lock().unwrap().push((info.attempt(), mismatch));
+            })
+            .build_client()?;
+
+        let mut reported = Vec::new();
+        let file = client
+            .upload_file_multipart_with_progress(Uuid::nil(), "hello.txt", &b"hello"[..], 3, |p| {
+                reported.push(p.bytes_uploaded)
+            })
+            .await?;
+        assert_eq!(file.file_id, Uuid::from_u128(1));
+        assert_eq!(reported, [3, 5]);
+        assert_eq!(*retries.lock().unwrap(), [(1, true)]);
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 5);
+        assert_eq!(requests[1].body, b"hel");
+        assert_eq!(requests[2].body, b"hel");
+        assert!(
+            requests[3]
+                .request_line()
+                .starts_with("PUT /uploads/00000000-0000-0000-0000-000000000009/parts/2 ")
+        );
+        assert_eq!(requests[3].body, b"lo");
+        let complete = String::from_utf8_lossy(&requests[4].body).into_owned();
+        assert!(complete.contains(r#""partNumber":2"#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transfers_report_progress() -> Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        use crate::RequestOptions;
+
+        let (base_url, server) = mock::serve(vec![
+            mock::response(201, &[], &format!("[{FILE}]")),
+            mock::response(200, &[], "hello"),
+        ]);
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let options = RequestOptions::new().on_progress(move |done, total| {
+            sink.lock().unwrap().push((done, total));
+        });
+        let tracked = client(base_url, 0)?.with_options(options);
+
+        tracked
+            .upload_file(Uuid::nil(), "hello.txt", b"hello".to_vec())
+            .await?;
+        assert_eq!(reports.lock().unwrap().last(), Some(&(5, Some(5))));
+
+        reports.lock().unwrap().clear();
+        tracked.download_file(Uuid::from_u128(1)).await?;
+        assert_eq!(reports.lock().unwrap().last(), Some(&(5, Some(5))));
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_processing_times_out() -> Result<()> {
+        let pending = FILE.replace("completed", "processing");
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], &pending),
+            mock::response(200, &[], &pending),
+        ]);
+
+        let options = PollOptions::new()
+            .interval(Duration::from_secs(5))
+            .timeout(Duration::from_millis(500));
+        let err = client(base_url, 0)?
+            .wait_for_processing(Uuid::from_u128(1), options)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Timeout {
+                phase: TimeoutPhase::Processing,
+                ..
+            }
+        ));
+
+        assert_eq!(server.join().unwrap().len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_files_batch_reports_partial_failure() -> Result<()> {
+        let result = r#"{"succeeded":["00000000-0000-0000-0000-000000000001"],
+            "failed":[{"id":"00000000-0000-0000-0000-000000000002","code":"file_locked",
+            "message":"File is locked by an export"}]}"#;
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], result),
+            mock::response(204, &[], ""),
+        ]);
+        let client = client(base_url, 0)?;
+        let ids = vec![Uuid::from_u128(1), Uuid::from_u128(2)];
+
+        let result = client.delete_files_batch(Uuid::nil(), ids.clone()).await?;
+        assert!(!result.is_success());
+        assert_eq!(result.succeeded, vec![Uuid::from_u128(1)]);
+        assert_eq!(result.failed[0].id, Uuid::from_u128(2));
+        assert_eq!(result.failed[0].code, "file_locked");
+
+        let result = client.delete_files_batch(Uuid::nil(), ids.clone()).await?;
+        assert!(result.is_success());
+        assert_eq!(result.succeeded, ids);
+
+        server.join().unwrap();
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_files_batch_continues_on_error() -> Result<()> {
+        let result = r#"{"succeeded":["00000000-0000-0000-0000-000000000001"],
+            "failed":[{"id":"00000000-0000-0000-0000-000000000002","code":"not_found",
+            "message":"File not found"}]}"#;
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], result)]);
+
+        let ids = vec![Uuid::from_u128(1), Uuid::from_u128(2)];
+        let options = DeleteFilesOptions::new().continue_on_error();
+        let result = client(base_url, 0)?
+            .delete_files_batch_with_options(Uuid::nil(), ids, options)
+            .await?;
+        assert!(result.failed[0].is_not_found());
+
+        let requests = server.join().unwrap();
+        let body = String::from_utf8_lossy(&requests[0].body);
+        assert!(body.contains(r#""continueOnError":true"#));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_watch_file_status_yields_transitions() -> Result<()> {
+        let pending = FILE.replace("completed", "pending");
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], &pending),
+            mock::response(200, &[], FILE),
+        ]);
+
+        let client = client(base_url, 0)?;
+        let statuses: Vec<FileStatus> = client
+            .watch_file_status(Uuid::from_u128(1))
+            .try_collect()
+            .await?;
+        assert_eq!(statuses, vec![FileStatus::Pending, FileStatus::Completed]);
+
+        assert_eq!(server.join().unwrap().len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reocr_low_confidence_pages() -> Result<()> {
+        use crate::model::{OcrEngine, OcrWarning};
+
+        let report = r#"{"fileId":"00000000-0000-0000-0000-000000000001","engine":"standard",
+            "confidence":0.71,"pages":[{"pageNumber":1,"confidence":0.95,"dpi":300},
+            {"pageNumber":2,"confidence":0.42,"skewDegrees":4.5,"dpi":96,
+            "warnings":["low_dpi","skewed","rotated"]}]}"#;
+        let job = r#"{"jobId":"00000000-0000-0000-0000-000000000003",
+            "workspaceId":"00000000-0000-0000-0000-000000000002","fileId":"00000000-0000-0000-0000-000000000001",
+            "jobType":"ocr","state":"queued","progress":0,
+            "createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-01T00:00:00Z"}"#;
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], report),
+            mock::response(202, &[], job),
+        ]);
+        let client = client(base_url, 0)?;
+
+        let report = client.get_ocr_report(Uuid::from_u128(1)).await?;
+        let low: Vec<_> = report
+            .pages_below(0.8)
+            .map(|page| page.page_number)
+            .collect();
+        assert_eq!(low, [2]);
+        assert_eq!(
+            report.pages[1].warnings,
+            [OcrWarning::LowDpi, OcrWarning::Skewed, OcrWarning::Unknown]
+        );
+
+        let options = OcrOptions::new()
+            .with_language_hint("de")
+            .with_engine(OcrEngine::Enhanced)
+            .with_dpi(400);
+        let job = client.reocr_file(report.file_id, options).await?;
+        assert_eq!(job.job_type, "ocr");
+
+        let requests = server.join().unwrap();
+        assert!(requests[1].request_line().starts_with("POST /files/"));
+        let body: serde_json::Value = serde_json::from_slice(&requests[1].body)?;
+        assert_eq!(body["languageHints"][0], "de");
+        assert_eq!(body["engine"], "enhanced");
+        assert_eq!(body["dpi"], 400);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_barcodes_with_positions() -> Result<()> {
+        use crate::model::BarcodeFormat;
+
+        let barcodes = r#"{"fileId":"00000000-0000-0000-0000-000000000001","items":[
+            {"format":"qr_code","value":"CASE-2024-118","pageNumber":1,
+             "region":{"x":480.5,"y":36.0,"width":72.0,"height":72.0}},
+            {"format":"code128","value":"0012345","pageNumber":3,
+             "region":{"x":50.0,"y":700.0,"width":160.0,"height":40.0}}]}"#;
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], barcodes)]);
+
+        let barcodes = client(base_url, 0)?
+            .get_barcodes(Uuid::from_u128(1))
+            .await?;
+        let qr: Vec<_> = barcodes.of_format(BarcodeFormat::QrCode).collect();
+        assert_eq!(qr.len(), 1);
+        assert_eq!(qr[0].value, "CASE-2024-118");
+        assert_eq!(qr[0].region.x, 480.5);
+        assert_eq!(barcodes.items[1].page_number, 3);
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].request_line().contains("/barcodes"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_compare_versions_and_fetch_old_content() -> Result<()> {
+        let diff = r#"{"fileId":"00000000-0000-0000-0000-000000000001","fromVersion":1,
+            "toVersion":3,"sizeDelta":-2048,"changedPages":[2],"removedPages":[5],
+            "changedFields":[{"field":"displayName","before":"draft.pdf","after":"final.pdf"}]}"#;
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], diff),
+            mock::response(200, &[], "v1"),
+        ]);
+        let client = client(base_url, 0)?;
+
+        let diff = client.compare_versions(Uuid::from_u128(1), 1, 3).await?;
+        assert!(!diff.is_identical());
+        assert_eq!(diff.size_delta, -2048);
+        assert_eq!(diff.changed_fields[0].after, Some("final.pdf".into()));
+
+        let content = client.get_version_content(Uuid::from_u128(1), 1).await?;
+        assert_eq!(content, b"v1");
+
+        let requests = server.join().unwrap();
+        assert!(
+            requests[0]
+                .request_line()
+                .contains("/versions/compare?from=1&to=3")
+        );
+        assert!(requests[1].request_line().contains("/versions/1/content"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_processing_enforces_minimum_interval() -> Result<()> {
+        let pending = FILE.replace("completed", "processing");
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], &pending),
+            mock::response(200, &[], &pending),
+            mock::response(200, &[], &pending),
+        ]);
+
+        let mut options = PollOptions::new().timeout(Duration::from_millis(250));
+        options.interval = Duration::ZERO;
+        let err = client(base_url, 0)?
+            .wait_for_processing(Uuid::from_u128(1), options)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Timeout {
+                phase: TimeoutPhase::Processing,
+                ..
+            }
+        ));
+
+        assert_eq!(server.join().unwrap().len(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_download_charged_to_scoped_workspace() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], "hello"),
+            mock::response(200, &[], "hello"),
+        ]);
+        let workspace_id = Uuid::from_u128(9);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .with_rate_budget(workspace_id, 5)
+            .build_client()?;
+
+        let scoped = client.with_options(RequestOptions::new().with_workspace(workspace_id));
+        let started = std::time::Instant::now();
+        scoped.download_file(Uuid::from_u128(1)).await?;
+        scoped.download_file(Uuid::from_u128(1)).await?;
+        assert!(started.elapsed() >= Duration::from_millis(200));
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[1].request_line(),
+            "GET /files/00000000-0000-0000-0000-000000000001/content HTTP/1.1"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "zip")]
+    #[tokio::test]
+    async fn test_download_files_zip_limits_each_entry() -> Result<()> {
+        // Each body fits the limit, but the entries together exceed it.
+        let content = "a".repeat(FILE.len());
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], FILE),
+            mock::response(200, &[], &content),
+            mock::response(200, &[], FILE),
+            mock::response(200, &[], &content),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .with_max_response_size(FILE.len() as u64)
+            .build_client()?;
+
+        let file_id = Uuid::from_u128(1);
+        let archive = client.download_files_zip(vec![file_id, file_id]).await?;
+        assert!(archive.starts_with(b"PK"));
+
+        assert_eq!(server.join().unwrap().len(), 4);
+        Ok(())
+    }
 }
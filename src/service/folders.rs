@@ -0,0 +1,359 @@
+//! Folders API service.
+//!
+//! This module provides methods for managing folders and traversing folder
+//! trees in workspaces.
+
+use std::collections::VecDeque;
+use std::future::Future;
+
+use futures_util::Stream;
+use futures_util::stream;
+use reqwest::Method;
+use uuid::Uuid;
+
+use super::query::Query;
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{CreateFolder, Cursor, Folder, FolderContentsPage, FolderEntry, FolderItem};
+
+/// Trait for Folders API operations.
+pub trait FoldersService {
+    /// Gets a folder by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_id` - The folder identifier
+    fn get_folder(&self, folder_id: Uuid) -> impl Future<Output = Result<Folder>>;
+
+    /// Creates a folder in a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `request` - The folder creation request
+    fn create_folder(
+        &self,
+        workspace_id: Uuid,
+        request: CreateFolder,
+    ) -> impl Future<Output = Result<Folder>>;
+
+    /// Deletes a folder and everything inside it.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_id` - The folder identifier
+    fn delete_folder(&self, folder_id: Uuid) -> impl Future<Output = Result<()>>;
+
+    /// Lists the direct subfolders and files of a folder.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_id` - The folder identifier
+    /// * `options` - Optional listing options (pagination)
+    fn list_folder_contents(
+        &self,
+        folder_id: Uuid,
+        options: Option<ListFolderContentsOptions>,
+    ) -> impl Future<Output = Result<FolderContentsPage>>;
+
+    /// Walks the tree below a folder, loading it lazily.
+    ///
+    /// Contents are fetched one page at a time as the stream is polled, so
+    /// large trees are never held in memory at once. Within a folder,
+    /// subfolders are yielded before files. The walked folder itself is not
+    /// yielded.
+    ///
+    /// # Arguments
+    ///
+    /// * `folder_id` - The folder identifier
+    /// * `options` - Traversal order and depth limit
+    fn walk_folder(
+        &self,
+        folder_id: Uuid,
+        options: WalkFolderOptions,
+    ) -> impl Stream<Item = Result<FolderEntry>> + '_;
+}
+
+/// Options for listing folder contents.
+#[derive(Clone, Debug, Default)]
+pub struct ListFolderContentsOptions {
+    /// Pagination cursor.
+    pub after: Option<Cursor>,
+    /// Maximum number of results.
+    pub limit: Option<i32>,
+}
+
+impl ListFolderContentsOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pagination cursor.
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Sets the maximum number of results.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// Order in which a folder tree is walked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WalkOrder {
+    /// Descend into each subfolder as soon as it is yielded.
+    #[default]
+    DepthFirst,
+    /// Yield all entries at one depth before descending further.
+    BreadthFirst,
+}
+
+/// Options for walking a folder tree.
+#[derive(Clone, Debug, Default)]
+pub struct WalkFolderOptions {
+    /// Traversal order.
+    pub order: WalkOrder,
+    /// Maximum depth to yield, where 1 is the walked folder's direct
+    /// contents. Unlimited if not set.
+    pub max_depth: Option<usize>,
+}
+
+impl WalkFolderOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the traversal order.
+    pub fn order(mut self, order: WalkOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets the maximum depth.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+}
+
+/// Folder whose contents are being walked.
+struct WalkFrame {
+    folder_id: Uuid,
+    /// Depth of the folder's contents.
+    depth: usize,
+    /// Loaded entries not yet yielded.
+    buffered: VecDeque<FolderItem>,
+    /// Cursor of the next page, if any.
+    next: Option<Cursor>,
+    /// Whether the first page has been loaded.
+    loaded: bool,
+}
+
+impl WalkFrame {
+    fn new(folder_id: Uuid, depth: usize) -> Self {
+        Self {
+            folder_id,
+            depth,
+            buffered: VecDeque::new(),
+            next: None,
+            loaded: false,
+        }
+    }
+}
+
+impl FoldersService for NvisyClient {
+    async fn get_folder(&self, folder_id: Uuid) -> Result<Folder> {
+        let path = format!("/folders/{}", folder_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let folder: Folder = self.read_json(response).await?;
+        Ok(folder)
+    }
+
+    async fn create_folder(&self, workspace_id: Uuid, request: CreateFolder) -> Result<Folder> {
+        let path = format!("/workspaces/{}/folders/", workspace_id);
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let folder: Folder = self.read_json(response).await?;
+        Ok(folder)
+    }
+
+    async fn delete_folder(&self, folder_id: Uuid) -> Result<()> {
+        let path = format!("/folders/{}", folder_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+
+    async fn list_folder_contents(
+        &self,
+        folder_id: Uuid,
+        options: Option<ListFolderContentsOptions>,
+    ) -> Result<FolderContentsPage> {
+        let path = format!("/folders/{}/contents", folder_id);
+        let opts = options.unwrap_or_default();
+
+        let query = Query::new()
+            .opt("after", opts.after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let page: FolderContentsPage = self.read_json(response).await?;
+        Ok(page)
+    }
+
+    fn walk_folder(
+        &self,
+        folder_id: Uuid,
+        options: WalkFolderOptions,
+    ) -> impl Stream<Item = Result<FolderEntry>> + '_ {
+        // Frames are always pushed to the back. Depth-first walks continue
+        // with the newest frame, breadth-first walks with the oldest.
+        let frames = VecDeque::from([WalkFrame::new(folder_id, 1)]);
+
+        stream::try_unfold(frames, move |mut frames| {
+            let options = options.clone();
+            async move {
+                loop {
+                    let frame = match options.order {
+                        WalkOrder::DepthFirst => frames.back_mut(),
+                        WalkOrder::BreadthFirst => frames.front_mut(),
+                    };
+                    let Some(frame) = frame else {
+                        return Ok(None);
+                    };
+
+                    if let Some(item) = frame.buffered.pop_front() {
+                        let depth = frame.depth;
+                        if let FolderItem::Folder(folder) = &item
+                            && options.max_depth.is_none_or(|max| depth < max)
+                        {
+                            frames.push_back(WalkFrame::new(folder.folder_id, depth + 1));
+                        }
+                        return Ok(Some((FolderEntry { depth, item }, frames)));
+                    }
+
+                    if !frame.loaded || frame.next.is_some() {
+                        let options = ListFolderContentsOptions {
+                            after: frame.next.take(),
+                            limit: None,
+                        };
+                        let page = self
+                            .list_folder_contents(frame.folder_id, Some(options))
+                            .await?;
+                        frame.loaded = true;
+                        frame.next = page.next_cursor;
+                        frame
+                            .buffered
+                            .extend(page.folders.into_iter().map(FolderItem::Folder));
+                        frame.buffered.extend(
+                            page.files
+                                .into_iter()
+                                .map(|file| FolderItem::File(Box::new(file))),
+                        );
+                        continue;
+                    }
+
+                    match options.order {
+                        WalkOrder::DepthFirst => frames.pop_back(),
+                        WalkOrder::BreadthFirst => frames.pop_front(),
+                    };
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::TryStreamExt;
+
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+
+    fn folder(id: u128, name: &str) -> String {
+        format!(
+            r#"{{"folderId":"{}","workspaceId":"{}","displayName":"{name}","createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-01T00:00:00Z"}}"#,
+            Uuid::from_u128(id),
+            Uuid::nil()
+        )
+    }
+
+    fn file(name: &str) -> String {
+        format!(
+            r#"{{"fileId":"{}","displayName":"{name}","fileSize":1,"status":"completed","source":"uploaded","tags":[],"processingPriority":5,"fileKnowledge":{{}},"uploadedBy":"{}","createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-01T00:00:00Z"}}"#,
+            Uuid::nil(),
+            Uuid::nil()
+        )
+    }
+
+    fn page(folders: &[String], files: &[String]) -> String {
+        mock::response(
+            200,
+            &[],
+            &format!(
+                r#"{{"folders":[{}],"files":[{}],"hasMore":false}}"#,
+                folders.join(","),
+                files.join(",")
+            ),
+        )
+    }
+
+    /// Walks a tree where the root holds folder `a` and file `root.txt`,
+    /// and `a` holds folder `b` and file `a.txt`.
+    async fn walk(options: WalkFolderOptions, responses: Vec<String>) -> Result<Vec<String>> {
+        let (base_url, server) = mock::serve(responses);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let entries: Vec<FolderEntry> = client
+            .walk_folder(Uuid::nil(), options)
+            .try_collect()
+            .await?;
+        server.join().unwrap();
+
+        Ok(entries
+            .into_iter()
+            .map(|entry| match entry.item {
+                FolderItem::Folder(folder) => format!("{}:{}/", entry.depth, folder.display_name),
+                FolderItem::File(file) => format!("{}:{}", entry.depth, file.display_name),
+            })
+            .collect())
+    }
+
+    #[tokio::test]
+    async fn test_walk_depth_first() -> Result<()> {
+        let responses = vec![
+            page(&[folder(1, "a")], &[file("root.txt")]),
+            page(&[folder(2, "b")], &[file("a.txt")]),
+            page(&[], &[]),
+        ];
+        let entries = walk(WalkFolderOptions::new(), responses).await?;
+        assert_eq!(entries, ["1:a/", "2:b/", "2:a.txt", "1:root.txt"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_walk_breadth_first_with_max_depth() -> Result<()> {
+        let responses = vec![
+            page(&[folder(1, "a")], &[file("root.txt")]),
+            page(&[folder(2, "b")], &[file("a.txt")]),
+        ];
+        let options = WalkFolderOptions::new()
+            .order(WalkOrder::BreadthFirst)
+            .max_depth(2);
+        let entries = walk(options, responses).await?;
+        assert_eq!(entries, ["1:a/", "1:root.txt", "2:b/", "2:a.txt"]);
+        Ok(())
+    }
+}
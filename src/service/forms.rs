@@ -0,0 +1,105 @@
+//! Forms API service.
+//!
+//! This module provides methods for reading and filling the form fields of
+//! workspace files, such as intake paperwork.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{File, FillForm, FormFields};
+
+/// Trait for Forms API operations.
+pub trait FormsService {
+    /// Gets the form fields of a file.
+    ///
+    /// Interactive PDF forms report their defined fields; for scanned or
+    /// flat documents the fields are detected from the page layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    fn get_form_fields(&self, file_id: Uuid) -> impl Future<Output = Result<FormFields>>;
+
+    /// Fills in the form fields of a file.
+    ///
+    /// The original file is left unchanged; the completed copy is stored
+    /// as a new file next to it and returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_id` - The file identifier
+    /// * `values` - Field values and output settings
+    fn fill_form(&self, file_id: Uuid, values: FillForm) -> impl Future<Output = Result<File>>;
+}
+
+impl FormsService for NvisyClient {
+    async fn get_form_fields(&self, file_id: Uuid) -> Result<FormFields> {
+        let path = format!("/files/{}/form", file_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let fields: FormFields = self.read_json(response).await?;
+        Ok(fields)
+    }
+
+    async fn fill_form(&self, file_id: Uuid, values: FillForm) -> Result<File> {
+        let path = format!("/files/{}/form/fill", file_id);
+        let response = self.send_json(Method::POST, &path, &values).await?;
+        let response = response.error_for_status()?;
+        let file: File = self.read_json(response).await?;
+        Ok(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+    use crate::model::{FormSource, FormValue};
+
+    #[tokio::test]
+    async fn test_get_form_fields_reports_missing_required() -> Result<()> {
+        let fields = r#"{"fileId":"00000000-0000-0000-0000-000000000001","source":"acro_form",
+            "fields":[{"name":"full_name","kind":"text","pageNumber":1,"required":true},
+            {"name":"consent","kind":"checkbox","pageNumber":2,"value":true},
+            {"name":"stamp","kind":"barcode","pageNumber":2}]}"#;
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], fields)]);
+
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+        let form = client.get_form_fields(Uuid::from_u128(1)).await?;
+
+        assert_eq!(form.source, FormSource::AcroForm);
+        let missing: Vec<_> = form.missing_required().map(|f| f.name.as_str()).collect();
+        assert_eq!(missing, ["full_name"]);
+        assert_eq!(
+            form.field("consent").and_then(|f| f.value.clone()),
+            Some(FormValue::Checked(true))
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].request_line().contains("/form "));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_form_serializes_values() -> serde_json::Result<()> {
+        let fill = FillForm::new()
+            .with_value("full_name", "Ada Lovelace")
+            .with_value("consent", true)
+            .with_flatten(true);
+        let body = serde_json::to_value(&fill)?;
+
+        assert_eq!(body["values"]["full_name"], "Ada Lovelace");
+        assert_eq!(body["values"]["consent"], true);
+        assert_eq!(body["flatten"], true);
+        assert!(body.get("fileName").is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+//! Guests API service.
+//!
+//! This module provides methods for managing external guests with
+//! file- or folder-scoped access to a workspace.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use super::query::Query;
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{Cursor, Guest, GuestsPage, InviteGuest, UpdateGuest};
+
+/// Trait for Guests API operations.
+pub trait GuestsService {
+    /// Lists guests invited to resources in a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (pagination)
+    fn list_guests(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListGuestsOptions>,
+    ) -> impl Future<Output = Result<GuestsPage>>;
+
+    /// Gets a guest by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `guest_id` - The guest identifier
+    fn get_guest(&self, guest_id: Uuid) -> impl Future<Output = Result<Guest>>;
+
+    /// Invites an external guest to a file or folder.
+    ///
+    /// Guests are not workspace members and can only access the scoped resource.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `request` - The guest invitation request
+    fn invite_guest(
+        &self,
+        workspace_id: Uuid,
+        request: InviteGuest,
+    ) -> impl Future<Output = Result<Guest>>;
+
+    /// Updates a guest's permission or expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `guest_id` - The guest identifier
+    /// * `update` - The update request
+    fn update_guest(
+        &self,
+        guest_id: Uuid,
+        update: UpdateGuest,
+    ) -> impl Future<Output = Result<Guest>>;
+
+    /// Revokes a guest's access.
+    ///
+    /// # Arguments
+    ///
+    /// * `guest_id` - The guest identifier
+    fn revoke_guest(&self, guest_id: Uuid) -> impl Future<Output = Result<()>>;
+}
+
+/// Options for listing guests.
+#[derive(Clone, Debug, Default)]
+pub struct ListGuestsOptions {
+    /// Pagination cursor.
+    pub after: Option<Cursor>,
+    /// Maximum number of results.
+    pub limit: Option<i32>,
+}
+
+impl ListGuestsOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pagination cursor.
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Sets the maximum number of results.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl GuestsService for NvisyClient {
+    async fn list_guests(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListGuestsOptions>,
+    ) -> Result<GuestsPage> {
+        let path = format!("/workspaces/{}/guests/", workspace_id);
+        let opts = options.unwrap_or_default();
+
+        let query = Query::new()
+            .opt("after", opts.after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let page: GuestsPage = self.read_json(response).await?;
+        Ok(page)
+    }
+
+    async fn get_guest(&self, guest_id: Uuid) -> Result<Guest> {
+        let path = format!("/guests/{}/", guest_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let guest: Guest = self.read_json(response).await?;
+        Ok(guest)
+    }
+
+    async fn invite_guest(&self, workspace_id: Uuid, request: InviteGuest) -> Result<Guest> {
+        let path = format!("/workspaces/{}/guests/", workspace_id);
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let guest: Guest = self.read_json(response).await?;
+        Ok(guest)
+    }
+
+    async fn update_guest(&self, guest_id: Uuid, update: UpdateGuest) -> Result<Guest> {
+        let path = format!("/guests/{}/", guest_id);
+        let response = self.send_json(Method::PATCH, &path, &update).await?;
+        let response = response.error_for_status()?;
+        let guest: Guest = self.read_json(response).await?;
+        Ok(guest)
+    }
+
+    async fn revoke_guest(&self, guest_id: Uuid) -> Result<()> {
+        let path = format!("/guests/{}/", guest_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+}
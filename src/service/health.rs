@@ -7,6 +7,7 @@ use std::future::Future;
 use reqwest::Method;
 
 use crate::client::NvisyClient;
+use crate::client::runtime::Instant;
 use crate::error::Result;
 use crate::model::{CheckHealth, MonitorStatus};
 
@@ -14,6 +15,10 @@ use crate::model::{CheckHealth, MonitorStatus};
 pub trait HealthService {
     /// Gets the current system health status.
     ///
+    /// When [`NvisyConfig::health_cache_ttl`](crate::NvisyConfig::health_cache_ttl)
+    /// is set, calls without options return a cached status until the TTL
+    /// elapses, so frequent readiness probes do not add API load.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -32,12 +37,80 @@ pub trait HealthService {
 
 impl HealthService for NvisyClient {
     async fn health(&self, options: Option<CheckHealth>) -> Result<MonitorStatus> {
+        let ttl = self
+            .config()
+            .health_cache_ttl()
+            .filter(|_| options.is_none());
+        if let Some(ttl) = ttl {
+            let cache = self.inner.health_cache.lock().unwrap();
+            if let Some((fetched_at, status)) = cache.as_ref()
+                && fetched_at.elapsed() < ttl
+            {
+                return Ok(status.clone());
+            }
+        }
+
         let response = match options {
             Some(opts) => self.send_json(Method::POST, "/health/", &opts).await?,
             None => self.send(Method::GET, "/health/").await?,
         };
         let response = response.error_for_status()?;
-        let status: MonitorStatus = response.json().await?;
+        let status: MonitorStatus = self.read_json(response).await?;
+        self.inner.telemetry.set_api_version(&status.version);
+
+        if ttl.is_some() {
+            *self.inner.health_cache.lock().unwrap() = Some((Instant::now(), status.clone()));
+        }
         Ok(status)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+    use crate::model::{DegradedReason, ServiceStatus};
+
+    const STATUS: &str = r#"{"checkedAt":"2024-01-01T00:00:00Z","status":"degraded","version":"1.0.0","components":[{"name":"storage","status":"unhealthy"}],"degradedReasons":["storage_unavailable","solar_flare"]}"#;
+
+    #[tokio::test]
+    async fn test_health_is_cached_within_ttl() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], STATUS)]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .with_health_cache_ttl(Duration::from_secs(60))
+            .build_client()?;
+
+        let first = client.health(None).await?;
+        let second = client.health(None).await?;
+        assert_eq!(first.checked_at, second.checked_at);
+        assert_eq!(first.components[0].status, ServiceStatus::Unhealthy);
+        assert_eq!(
+            first.degraded_reasons,
+            [DegradedReason::StorageUnavailable, DegradedReason::Unknown]
+        );
+
+        assert_eq!(server.join().unwrap().len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_health_with_options_allowed_in_read_only_mode() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], STATUS)]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .with_read_only(true)
+            .build_client()?;
+
+        client.health(Some(CheckHealth::new())).await?;
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].request_line(), "POST /health/ HTTP/1.1");
+        Ok(())
+    }
+}
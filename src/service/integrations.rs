@@ -3,13 +3,23 @@
 //! This module provides methods for managing workspace integrations.
 
 use std::future::Future;
+use std::time::Duration;
 
+use futures_util::Stream;
 use reqwest::Method;
 use uuid::Uuid;
 
+use super::pagination::Paginator;
+use super::query::Query;
+#[cfg(feature = "tracing")]
+use crate::TRACING_TARGET_CLIENT;
 use crate::client::NvisyClient;
+use crate::client::runtime;
 use crate::error::Result;
-use crate::model::{CreateIntegration, Integration, IntegrationsPage, UpdateIntegration};
+use crate::model::{
+    CreateIntegration, CredentialsPublicKey, Cursor, Integration, IntegrationHealth,
+    IntegrationSyncRun, IntegrationsPage, PageAnchor, UpdateIntegration,
+};
 
 /// Trait for Integrations API operations.
 pub trait IntegrationsService {
@@ -25,6 +35,50 @@ pub trait IntegrationsService {
         options: Option<ListIntegrationsOptions>,
     ) -> impl Future<Output = Result<IntegrationsPage>>;
 
+    /// Lists all integrations in a workspace, following pagination cursors.
+    ///
+    /// Pages are fetched lazily as the stream is polled. The `after` option
+    /// sets where the listing starts and `limit` sets the page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (page size)
+    fn list_integrations_stream(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListIntegrationsOptions>,
+    ) -> impl Stream<Item = Result<Integration>> + '_;
+
+    /// Returns a paginator over all integrations in a workspace.
+    ///
+    /// Pages are fetched as they are requested. The `after` option sets
+    /// where the listing starts and `limit` sets the page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (page size)
+    fn paginate_integrations(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListIntegrationsOptions>,
+    ) -> Paginator<'_, Integration>;
+
+    /// Finds an integration in a workspace by its external identifier.
+    ///
+    /// Returns `None` if no integration in the workspace has the given identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `external_id` - The external identifier
+    fn find_integration_by_external_id(
+        &self,
+        workspace_id: Uuid,
+        external_id: &str,
+    ) -> impl Future<Output = Result<Option<Integration>>>;
+
     /// Gets an integration by ID.
     ///
     /// # Arguments
@@ -69,13 +123,75 @@ pub trait IntegrationsService {
     ///
     /// * `integration_id` - The integration identifier
     fn sync_integration(&self, integration_id: Uuid) -> impl Future<Output = Result<Integration>>;
+
+    /// Lists recent sync runs for an integration, newest first.
+    ///
+    /// Each run reports the conflicts it encountered and how they were resolved.
+    ///
+    /// # Arguments
+    ///
+    /// * `integration_id` - The integration identifier
+    fn list_sync_runs(
+        &self,
+        integration_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<IntegrationSyncRun>>>;
+
+    /// Gets a sync run by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `integration_id` - The integration identifier
+    /// * `run_id` - The sync run identifier
+    fn get_sync_run(
+        &self,
+        integration_id: Uuid,
+        run_id: Uuid,
+    ) -> impl Future<Output = Result<IntegrationSyncRun>>;
+
+    /// Refreshes the OAuth grant or access token of an integration.
+    ///
+    /// The returned integration reports the new
+    /// [`credentials_expires_at`](Integration::credentials_expires_at). Use
+    /// [`CredentialsRefresher`] to refresh credentials automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `integration_id` - The integration identifier
+    fn refresh_integration_credentials(
+        &self,
+        integration_id: Uuid,
+    ) -> impl Future<Output = Result<Integration>>;
+
+    /// Gets a health summary of an integration.
+    ///
+    /// Reports connectivity, when the access token expires, the last
+    /// successful sync, and the number of queued items, so that monitoring
+    /// can catch an expiring OAuth grant before syncs stop.
+    ///
+    /// # Arguments
+    ///
+    /// * `integration_id` - The integration identifier
+    fn get_integration_health(
+        &self,
+        integration_id: Uuid,
+    ) -> impl Future<Output = Result<IntegrationHealth>>;
+
+    /// Gets the tenant's public key for encrypting integration credentials.
+    ///
+    /// Pass the key to [`CreateIntegration::credentials_encrypted`] so that
+    /// credentials are encrypted before leaving the client.
+    fn get_credentials_public_key(&self) -> impl Future<Output = Result<CredentialsPublicKey>>;
 }
 
 /// Options for listing integrations.
 #[derive(Clone, Debug, Default)]
 pub struct ListIntegrationsOptions {
+    /// Filter by external identifier.
+    pub external_id: Option<String>,
     /// Pagination cursor.
-    pub after: Option<String>,
+    pub after: Option<Cursor>,
+    /// Resume after the given item instead of at a cursor.
+    pub starting_after: Option<PageAnchor>,
     /// Maximum number of results.
     pub limit: Option<i32>,
 }
@@ -86,9 +202,21 @@ impl ListIntegrationsOptions {
         Self::default()
     }
 
+    /// Sets the external identifier filter.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
     /// Sets the pagination cursor.
-    pub fn after(mut self, cursor: impl Into<String>) -> Self {
-        self.after = Some(cursor.into());
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Resumes the listing after the given item.
+    pub fn starting_after(mut self, anchor: PageAnchor) -> Self {
+        self.starting_after = Some(anchor);
         self
     }
 
@@ -99,6 +227,120 @@ impl ListIntegrationsOptions {
     }
 }
 
+/// Default time before expiry at which credentials are refreshed.
+const DEFAULT_REFRESH_BEFORE: Duration = Duration::from_secs(10 * 60);
+
+/// Default interval between credential expiry checks.
+const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Background refresher that keeps integration credentials from expiring.
+///
+/// Intended for long-running daemons that own integration lifecycles. The
+/// refresher periodically checks the tracked integrations and refreshes
+/// those whose credentials expire soon. Spawn [`run`](Self::run) as a
+/// task; on native targets it must run inside a Tokio runtime, since it
+/// sleeps with Tokio timers.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use nvisy_sdk::{NvisyClient, Result};
+/// # use nvisy_sdk::service::CredentialsRefresher;
+/// # use uuid::Uuid;
+/// # async fn example(client: NvisyClient, integration_id: Uuid) -> Result<()> {
+/// let refresher = CredentialsRefresher::new(client, [integration_id]);
+/// tokio::spawn(refresher.run());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CredentialsRefresher {
+    client: NvisyClient,
+    integration_ids: Vec<Uuid>,
+    refresh_before: Duration,
+    check_interval: Duration,
+}
+
+impl CredentialsRefresher {
+    /// Creates a refresher for the given integrations.
+    pub fn new(client: NvisyClient, integration_ids: impl IntoIterator<Item = Uuid>) -> Self {
+        Self {
+            client,
+            integration_ids: integration_ids.into_iter().collect(),
+            refresh_before: DEFAULT_REFRESH_BEFORE,
+            check_interval: DEFAULT_CHECK_INTERVAL,
+        }
+    }
+
+    /// Sets how long before expiry credentials are refreshed. Defaults to
+    /// 10 minutes.
+    pub fn with_refresh_before(mut self, refresh_before: Duration) -> Self {
+        self.refresh_before = refresh_before;
+        self
+    }
+
+    /// Sets the interval between expiry checks. Defaults to 1 minute.
+    pub fn with_check_interval(mut self, check_interval: Duration) -> Self {
+        self.check_interval = check_interval;
+        self
+    }
+
+    /// Checks every tracked integration once, refreshing the credentials of
+    /// those that expire soon.
+    ///
+    /// A failure for one integration does not stop the others from being
+    /// checked. Returns the outcome for every integration that was refreshed
+    /// or could not be checked; integrations that are not yet due are
+    /// omitted.
+    pub async fn refresh_due(&self) -> Vec<(Uuid, Result<Integration>)> {
+        let mut outcomes = Vec::new();
+        for &integration_id in &self.integration_ids {
+            match self.refresh_if_due(integration_id).await {
+                Ok(None) => {}
+                Ok(Some(integration)) => outcomes.push((integration_id, Ok(integration))),
+                Err(err) => outcomes.push((integration_id, Err(err))),
+            }
+        }
+        outcomes
+    }
+
+    /// Refreshes the credentials of a single integration if they expire soon.
+    async fn refresh_if_due(&self, integration_id: Uuid) -> Result<Option<Integration>> {
+        let integration = self.client.get_integration(integration_id).await?;
+        if !integration.credentials_expire_within(self.refresh_before) {
+            return Ok(None);
+        }
+
+        let integration = self
+            .client
+            .refresh_integration_credentials(integration_id)
+            .await?;
+        Ok(Some(integration))
+    }
+
+    /// Checks the tracked integrations at every interval until dropped.
+    ///
+    /// Failed checks are retried at the next interval.
+    pub async fn run(self) {
+        loop {
+            for (integration_id, outcome) in self.refresh_due().await {
+                if let Err(err) = outcome {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!(
+                        target: TRACING_TARGET_CLIENT,
+                        integration_id = %integration_id,
+                        error = %err,
+                        "Failed to refresh integration credentials"
+                    );
+                    #[cfg(not(feature = "tracing"))]
+                    let _ = (integration_id, err);
+                }
+            }
+            runtime::sleep(self.check_interval).await;
+        }
+    }
+}
+
 impl IntegrationsService for NvisyClient {
     async fn list_integrations(
         &self,
@@ -108,26 +350,70 @@ impl IntegrationsService for NvisyClient {
         let path = format!("/workspaces/{}/integrations/", workspace_id);
         let opts = options.unwrap_or_default();
 
-        let mut req = self.request_builder(Method::GET, &path)?;
-
-        if let Some(after) = &opts.after {
-            req = req.query(&[("after", after)]);
-        }
-        if let Some(limit) = opts.limit {
-            req = req.query(&[("limit", limit)]);
-        }
+        let query = Query::new()
+            .opt("externalId", opts.external_id.as_ref())
+            .opt("after", opts.after.as_ref())
+            .anchor(opts.starting_after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
 
-        let response = req.send().await?;
+        let response = self.execute(req).await?;
         let response = response.error_for_status()?;
-        let page: IntegrationsPage = response.json().await?;
+        let page: IntegrationsPage = self.read_json(response).await?;
         Ok(page)
     }
 
+    fn list_integrations_stream(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListIntegrationsOptions>,
+    ) -> impl Stream<Item = Result<Integration>> + '_ {
+        self.paginate_integrations(workspace_id, options)
+            .into_stream()
+    }
+
+    fn paginate_integrations(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListIntegrationsOptions>,
+    ) -> Paginator<'_, Integration> {
+        let mut options = options.unwrap_or_default();
+        let first = options.after.take();
+        let resume_options = options.clone();
+        Paginator::new(first, move |after| {
+            let options = ListIntegrationsOptions {
+                after,
+                ..options.clone()
+            };
+            self.list_integrations(workspace_id, Some(options))
+        })
+        .with_resume(move |anchor| {
+            let options = ListIntegrationsOptions {
+                after: None,
+                starting_after: Some(anchor),
+                ..resume_options.clone()
+            };
+            self.list_integrations(workspace_id, Some(options))
+        })
+    }
+
+    async fn find_integration_by_external_id(
+        &self,
+        workspace_id: Uuid,
+        external_id: &str,
+    ) -> Result<Option<Integration>> {
+        let options = ListIntegrationsOptions::new()
+            .external_id(external_id)
+            .limit(1);
+        let page = self.list_integrations(workspace_id, Some(options)).await?;
+        Ok(page.items.into_iter().next())
+    }
+
     async fn get_integration(&self, integration_id: Uuid) -> Result<Integration> {
         let path = format!("/integrations/{}/", integration_id);
         let response = self.send(Method::GET, &path).await?;
         let response = response.error_for_status()?;
-        let integration: Integration = response.json().await?;
+        let integration: Integration = self.read_json(response).await?;
         Ok(integration)
     }
 
@@ -139,7 +425,7 @@ impl IntegrationsService for NvisyClient {
         let path = format!("/workspaces/{}/integrations/", workspace_id);
         let response = self.send_json(Method::POST, &path, &request).await?;
         let response = response.error_for_status()?;
-        let integration: Integration = response.json().await?;
+        let integration: Integration = self.read_json(response).await?;
         Ok(integration)
     }
 
@@ -151,7 +437,7 @@ impl IntegrationsService for NvisyClient {
         let path = format!("/integrations/{}/", integration_id);
         let response = self.send_json(Method::PATCH, &path, &update).await?;
         let response = response.error_for_status()?;
-        let integration: Integration = response.json().await?;
+        let integration: Integration = self.read_json(response).await?;
         Ok(integration)
     }
 
@@ -166,7 +452,121 @@ impl IntegrationsService for NvisyClient {
         let path = format!("/integrations/{}/sync", integration_id);
         let response = self.send(Method::POST, &path).await?;
         let response = response.error_for_status()?;
-        let integration: Integration = response.json().await?;
+        let integration: Integration = self.read_json(response).await?;
+        Ok(integration)
+    }
+
+    async fn list_sync_runs(&self, integration_id: Uuid) -> Result<Vec<IntegrationSyncRun>> {
+        let path = format!("/integrations/{}/sync/runs/", integration_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let runs: Vec<IntegrationSyncRun> = self.read_json(response).await?;
+        Ok(runs)
+    }
+
+    async fn get_sync_run(&self, integration_id: Uuid, run_id: Uuid) -> Result<IntegrationSyncRun> {
+        let path = format!("/integrations/{}/sync/runs/{}", integration_id, run_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let run: IntegrationSyncRun = self.read_json(response).await?;
+        Ok(run)
+    }
+
+    async fn refresh_integration_credentials(&self, integration_id: Uuid) -> Result<Integration> {
+        let path = format!("/integrations/{}/credentials/refresh", integration_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let integration: Integration = self.read_json(response).await?;
         Ok(integration)
     }
+
+    async fn get_integration_health(&self, integration_id: Uuid) -> Result<IntegrationHealth> {
+        let path = format!("/integrations/{}/health", integration_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let health: IntegrationHealth = self.read_json(response).await?;
+        Ok(health)
+    }
+
+    async fn get_credentials_public_key(&self) -> Result<CredentialsPublicKey> {
+        let response = self
+            .send(Method::GET, "/integrations/credentials-key")
+            .await?;
+        let response = response.error_for_status()?;
+        let key: CredentialsPublicKey = self.read_json(response).await?;
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+
+    fn integration(integration_id: u8, credentials_expires_at: &str) -> String {
+        format!(
+            r#"{{"integrationId":"00000000-0000-0000-0000-0000000000{integration_id:02}","workspaceId":"00000000-0000-0000-0000-000000000000","integrationName":"Drive","description":"Shared drive","integrationType":"storage","isActive":true,"credentialsExpiresAt":"{credentials_expires_at}","createdBy":"00000000-0000-0000-0000-000000000000","createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-01T00:00:00Z"}}"#
+        )
+    }
+
+    #[tokio::test]
+    async fn test_refresher_refreshes_expiring_credentials() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(200, &[], &integration(1, "2024-01-01T00:00:00Z")),
+            mock::response(200, &[], &integration(1, "2999-01-01T00:00:00Z")),
+            mock::response(200, &[], &integration(2, "2999-01-01T00:00:00Z")),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let ids = [Uuid::from_u128(1), Uuid::from_u128(2)];
+        let outcomes = CredentialsRefresher::new(client, ids).refresh_due().await;
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].0, ids[0]);
+        let refreshed = outcomes[0].1.as_ref().unwrap();
+        assert_eq!(refreshed.integration_id, ids[0]);
+        assert!(!refreshed.credentials_expire_within(DEFAULT_REFRESH_BEFORE));
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[1].request_line(),
+            "POST /integrations/00000000-0000-0000-0000-000000000001/credentials/refresh HTTP/1.1"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_refresher_continues_after_failure() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![
+            mock::response(
+                404,
+                &[],
+                r#"{"name":"not_found","message":"Integration not found"}"#,
+            ),
+            mock::response(200, &[], &integration(2, "2024-01-01T00:00:00Z")),
+            mock::response(200, &[], &integration(2, "2999-01-01T00:00:00Z")),
+        ]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let ids = [Uuid::from_u128(1), Uuid::from_u128(2)];
+        let outcomes = CredentialsRefresher::new(client, ids).refresh_due().await;
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].0, ids[0]);
+        assert!(outcomes[0].1.is_err());
+        assert_eq!(outcomes[1].0, ids[1]);
+        assert_eq!(outcomes[1].1.as_ref().unwrap().integration_id, ids[1]);
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[2].request_line(),
+            "POST /integrations/00000000-0000-0000-0000-000000000002/credentials/refresh HTTP/1.1"
+        );
+        Ok(())
+    }
 }
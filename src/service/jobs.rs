@@ -0,0 +1,179 @@
+//! Jobs API service.
+//!
+//! This module provides methods for inspecting and controlling the
+//! asynchronous jobs that process uploaded files.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use super::query::Query;
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{Cursor, Job, JobState, JobsPage};
+
+/// Trait for Jobs API operations.
+pub trait JobsService {
+    /// Lists processing jobs in a workspace, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (filters, pagination)
+    fn list_jobs(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListJobsOptions>,
+    ) -> impl Future<Output = Result<JobsPage>>;
+
+    /// Gets a job by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The job identifier
+    fn get_job(&self, job_id: Uuid) -> impl Future<Output = Result<Job>>;
+
+    /// Cancels a queued or running job.
+    ///
+    /// Returns the job in its updated state.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The job identifier
+    fn cancel_job(&self, job_id: Uuid) -> impl Future<Output = Result<Job>>;
+
+    /// Requeues a failed or cancelled job.
+    ///
+    /// Returns the job in its updated state.
+    ///
+    /// # Arguments
+    ///
+    /// * `job_id` - The job identifier
+    fn retry_job(&self, job_id: Uuid) -> impl Future<Output = Result<Job>>;
+}
+
+/// Options for listing jobs.
+#[derive(Clone, Debug, Default)]
+pub struct ListJobsOptions {
+    /// Only include jobs in this state.
+    pub state: Option<JobState>,
+    /// Only include jobs processing this file.
+    pub file_id: Option<Uuid>,
+    /// Pagination cursor.
+    pub after: Option<Cursor>,
+    /// Maximum number of results.
+    pub limit: Option<i32>,
+}
+
+impl ListJobsOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only includes jobs in the given state.
+    pub fn state(mut self, state: JobState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Only includes jobs processing the given file.
+    pub fn file_id(mut self, file_id: Uuid) -> Self {
+        self.file_id = Some(file_id);
+        self
+    }
+
+    /// Sets the pagination cursor.
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Sets the maximum number of results.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl JobsService for NvisyClient {
+    async fn list_jobs(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListJobsOptions>,
+    ) -> Result<JobsPage> {
+        let path = format!("/workspaces/{}/jobs/", workspace_id);
+        let opts = options.unwrap_or_default();
+
+        let query = Query::new()
+            .opt("state", opts.state.as_ref())
+            .opt("fileId", opts.file_id.as_ref())
+            .opt("after", opts.after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let page: JobsPage = self.read_json(response).await?;
+        Ok(page)
+    }
+
+    async fn get_job(&self, job_id: Uuid) -> Result<Job> {
+        let path = format!("/jobs/{}", job_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let job: Job = self.read_json(response).await?;
+        Ok(job)
+    }
+
+    async fn cancel_job(&self, job_id: Uuid) -> Result<Job> {
+        let path = format!("/jobs/{}/cancel", job_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let job: Job = self.read_json(response).await?;
+        Ok(job)
+    }
+
+    async fn retry_job(&self, job_id: Uuid) -> Result<Job> {
+        let path = format!("/jobs/{}/retry", job_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let job: Job = self.read_json(response).await?;
+        Ok(job)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+
+    #[tokio::test]
+    async fn test_list_jobs_filters_by_state() -> Result<()> {
+        let page = r#"{"items":[{"jobId":"00000000-0000-0000-0000-000000000003",
+            "workspaceId":"00000000-0000-0000-0000-000000000001","jobType":"extraction",
+            "state":"failed","progress":40,"error":{"code":"ocr_timeout",
+            "message":"OCR timed out","retryable":true},
+            "createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-01T00:05:00Z"}],
+            "hasMore":false}"#;
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], page)]);
+
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+        let options = ListJobsOptions::new().state(JobState::Failed);
+        let page = client.list_jobs(Uuid::from_u128(1), Some(options)).await?;
+
+        let job = &page.items[0];
+        assert_eq!(job.state, JobState::Failed);
+        assert!(job.state.is_terminal());
+        assert!(job.error.as_ref().is_some_and(|err| err.retryable));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].request_line().contains("?state=failed"));
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+//! Members API service.
+//!
+//! This module provides methods for managing workspace membership, listing
+//! recent member activity, and managing shareable invite links.
+
+use std::future::Future;
+
+use jiff::Timestamp;
+use reqwest::Method;
+use uuid::Uuid;
+
+use super::query::Query;
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{
+    CreateInviteLink, Cursor, InviteLink, InviteMember, Member, MembersPage, StatsWindow,
+    UpdateMember, WorkspaceRole,
+};
+
+/// Trait for Members API operations.
+pub trait MembersService {
+    /// Lists the members of a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (pagination)
+    fn list_members(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListMembersOptions>,
+    ) -> impl Future<Output = Result<MembersPage>>;
+
+    /// Gets a member of a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `account_id` - The member's account identifier
+    fn get_member(
+        &self,
+        workspace_id: Uuid,
+        account_id: Uuid,
+    ) -> impl Future<Output = Result<Member>>;
+
+    /// Invites an account to a workspace by email.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `request` - The invitation request
+    fn invite_member(
+        &self,
+        workspace_id: Uuid,
+        request: InviteMember,
+    ) -> impl Future<Output = Result<Member>>;
+
+    /// Changes the role of a workspace member.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `account_id` - The member's account identifier
+    /// * `role` - The new role
+    fn update_member_role(
+        &self,
+        workspace_id: Uuid,
+        account_id: Uuid,
+        role: WorkspaceRole,
+    ) -> impl Future<Output = Result<Member>>;
+
+    /// Removes a member from a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `account_id` - The member's account identifier
+    fn remove_member(
+        &self,
+        workspace_id: Uuid,
+        account_id: Uuid,
+    ) -> impl Future<Output = Result<()>>;
+
+    /// Lists the members of a workspace who were active within a window.
+    ///
+    /// Members missing from the result have been dormant for at least the
+    /// window, making them candidates for deprovisioning.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `window` - How far back to look for activity
+    fn list_active_members(
+        &self,
+        workspace_id: Uuid,
+        window: StatsWindow,
+    ) -> impl Future<Output = Result<Vec<Member>>>;
+
+    /// Creates a shareable link for joining a workspace.
+    ///
+    /// Anyone with the link can join with the given role, subject to the
+    /// restrictions in `options`. This is a lighter-weight alternative to
+    /// inviting each account by email.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `role` - Role granted to accounts joining through the link
+    /// * `options` - Expiry, allowed email domains, and usage limit
+    fn create_invite_link(
+        &self,
+        workspace_id: Uuid,
+        role: WorkspaceRole,
+        options: InviteLinkOptions,
+    ) -> impl Future<Output = Result<InviteLink>>;
+
+    /// Lists the invite links of a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn list_invite_links(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<InviteLink>>>;
+
+    /// Revokes an invite link. Accounts that already joined keep their
+    /// access.
+    ///
+    /// # Arguments
+    ///
+    /// * `invite_link_id` - The invite link identifier
+    fn revoke_invite_link(&self, invite_link_id: Uuid) -> impl Future<Output = Result<()>>;
+}
+
+/// Restrictions on an invite link.
+#[derive(Clone, Debug, Default)]
+pub struct InviteLinkOptions {
+    /// Time after which the link stops working.
+    pub expires_at: Option<Timestamp>,
+    /// Email domains allowed to join. Any domain may join if empty.
+    pub allowed_domains: Vec<String>,
+    /// Maximum number of accounts that can join.
+    pub max_uses: Option<u32>,
+}
+
+impl InviteLinkOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the expiry time.
+    pub fn expires_at(mut self, expires_at: Timestamp) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    /// Restricts joining to accounts with an email address in one of the
+    /// given domains.
+    pub fn allowed_domains<I, S>(mut self, domains: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_domains = domains.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the maximum number of accounts that can join.
+    pub fn max_uses(mut self, max_uses: u32) -> Self {
+        self.max_uses = Some(max_uses);
+        self
+    }
+}
+
+/// Options for listing members.
+#[derive(Clone, Debug, Default)]
+pub struct ListMembersOptions {
+    /// Pagination cursor.
+    pub after: Option<Cursor>,
+    /// Maximum number of results.
+    pub limit: Option<i32>,
+}
+
+impl ListMembersOptions {
+    /// Creates a new options builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the pagination cursor.
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Sets the maximum number of results.
+    pub fn limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl MembersService for NvisyClient {
+    async fn list_members(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListMembersOptions>,
+    ) -> Result<MembersPage> {
+        let path = format!("/workspaces/{}/members/", workspace_id);
+        let opts = options.unwrap_or_default();
+
+        let query = Query::new()
+            .opt("after", opts.after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let page: MembersPage = self.read_json(response).await?;
+        Ok(page)
+    }
+
+    async fn get_member(&self, workspace_id: Uuid, account_id: Uuid) -> Result<Member> {
+        let path = format!("/workspaces/{}/members/{}", workspace_id, account_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let member: Member = self.read_json(response).await?;
+        Ok(member)
+    }
+
+    async fn invite_member(&self, workspace_id: Uuid, request: InviteMember) -> Result<Member> {
+        let path = format!("/workspaces/{}/members/", workspace_id);
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let member: Member = self.read_json(response).await?;
+        Ok(member)
+    }
+
+    async fn update_member_role(
+        &self,
+        workspace_id: Uuid,
+        account_id: Uuid,
+        role: WorkspaceRole,
+    ) -> Result<Member> {
+        let path = format!("/workspaces/{}/members/{}", workspace_id, account_id);
+        let update = UpdateMember { role: Some(role) };
+        let response = self.send_json(Method::PATCH, &path, &update).await?;
+        let response = response.error_for_status()?;
+        let member: Member = self.read_json(response).await?;
+        Ok(member)
+    }
+
+    async fn remove_member(&self, workspace_id: Uuid, account_id: Uuid) -> Result<()> {
+        let path = format!("/workspaces/{}/members/{}", workspace_id, account_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+
+    async fn list_active_members(
+        &self,
+        workspace_id: Uuid,
+        window: StatsWindow,
+    ) -> Result<Vec<Member>> {
+        let path = format!("/workspaces/{}/members/active", workspace_id);
+        let req = Query::new()
+            .push("window", &window)
+            .apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let members: Vec<Member> = self.read_json(response).await?;
+        Ok(members)
+    }
+
+    async fn create_invite_link(
+        &self,
+        workspace_id: Uuid,
+        role: WorkspaceRole,
+        options: InviteLinkOptions,
+    ) -> Result<InviteLink> {
+        let path = format!("/workspaces/{}/invite-links/", workspace_id);
+        let request = CreateInviteLink {
+            role,
+            expires_at: options.expires_at,
+            allowed_domains: options.allowed_domains,
+            max_uses: options.max_uses,
+        };
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let link: InviteLink = self.read_json(response).await?;
+        Ok(link)
+    }
+
+    async fn list_invite_links(&self, workspace_id: Uuid) -> Result<Vec<InviteLink>> {
+        let path = format!("/workspaces/{}/invite-links/", workspace_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let links: Vec<InviteLink> = self.read_json(response).await?;
+        Ok(links)
+    }
+
+    async fn revoke_invite_link(&self, invite_link_id: Uuid) -> Result<()> {
+        let path = format!("/invite-links/{}", invite_link_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+
+    #[tokio::test]
+    async fn test_create_invite_link_sends_restrictions() -> Result<()> {
+        let link = r#"{"inviteLinkId":"00000000-0000-0000-0000-000000000001","workspaceId":"00000000-0000-0000-0000-000000000000","url":"https://app.nvisy.com/join/abc","role":"viewer","allowedDomains":["example.com"],"maxUses":5,"useCount":5,"createdBy":"00000000-0000-0000-0000-000000000000","createdAt":"2024-01-01T00:00:00Z"}"#;
+        let (base_url, server) = mock::serve(vec![mock::response(201, &[], link)]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let options = InviteLinkOptions::new()
+            .allowed_domains(["example.com"])
+            .max_uses(5);
+        let link = client
+            .create_invite_link(Uuid::nil(), WorkspaceRole::Viewer, options)
+            .await?;
+        assert!(link.is_exhausted());
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0].body,
+            br#"{"role":"viewer","allowedDomains":["example.com"],"maxUses":5}"#
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_member_role() -> Result<()> {
+        let member = r#"{"accountId":"00000000-0000-0000-0000-000000000001","workspaceId":"00000000-0000-0000-0000-000000000000","role":"admin","joinedAt":"2024-01-01T00:00:00Z"}"#;
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], member)]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let member = client
+            .update_member_role(Uuid::nil(), Uuid::from_u128(1), WorkspaceRole::Admin)
+            .await?;
+        assert_eq!(member.role, WorkspaceRole::Admin);
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0].request_line(),
+            "PATCH /workspaces/00000000-0000-0000-0000-000000000000/members/00000000-0000-0000-0000-000000000001 HTTP/1.1"
+        );
+        assert_eq!(requests[0].body, br#"{"role":"admin"}"#);
+        Ok(())
+    }
+}
@@ -6,19 +6,74 @@
 //! Import the service traits you need and call methods directly on the client:
 //!
 //! - [`FilesService`] - File upload, download, and management
+//! - [`JobsService`] - Asynchronous file processing jobs
+//! - [`FoldersService`] - Folder management and lazy tree traversal
+//! - [`FormsService`] - Form field detection and filling
 //! - [`WorkspacesService`] - Workspace CRUD and notifications
+//! - [`MembersService`] - Workspace membership, activity, and invite links
+//! - [`CommentsService`] - File discussions
+//! - [`GuestsService`] - External guest access to files and folders
 //! - [`IntegrationsService`] - Third-party integrations
 //! - [`WebhooksService`] - Webhook management
+//! - [`SecurityService`] - Organization security policies
+//! - [`ExportsService`] - Anonymized dataset exports
+//! - [`BundlesService`] - Merged PDF packets of workspace files
+//! - [`AlertsService`] - Usage-based threshold alerts
+//! - [`EventsService`] - Workspace event log for webhook backfill
+//! - [`NotificationRoutesService`] - Chat channel routing for workspace events
+//! - [`AutomationService`] - Trigger-action automation rules
+//! - [`CapabilitiesService`] - Server-advertised limits and tenant features
+//! - [`CaptureService`] - Mobile photo capture into workspaces
+//! - [`ProvisioningService`] - Idempotent `ensure_*` helpers for provisioning tools
 //! - [`HealthService`] - System health checks
 
+mod alerts;
+mod automations;
+mod batch;
+mod bundles;
+mod capabilities;
+mod capture;
+mod comments;
+mod events;
+mod exports;
 mod files;
+mod folders;
+mod forms;
+mod guests;
 mod health;
 mod integrations;
+mod jobs;
+mod members;
+mod notification_routes;
+mod pagination;
+mod provisioning;
+mod query;
+mod security;
 mod webhooks;
 mod workspaces;
 
-pub use files::{FilesService, ListFilesOptions};
+pub use alerts::AlertsService;
+pub use automations::AutomationService;
+pub use bundles::BundlesService;
+pub use capabilities::CapabilitiesService;
+pub use capture::CaptureService;
+pub use comments::{CommentsService, ListCommentsOptions};
+pub use events::{EventsService, ListEventsOptions};
+pub use exports::ExportsService;
+pub use files::{
+    DeleteFilesOptions, FilesService, ListFilesOptions, PollOptions, UploadFileOptions, UploadItem,
+    UploadOutcome, UploadReport, UploadSummary,
+};
+pub use folders::{FoldersService, ListFolderContentsOptions, WalkFolderOptions, WalkOrder};
+pub use forms::FormsService;
+pub use guests::{GuestsService, ListGuestsOptions};
 pub use health::HealthService;
-pub use integrations::{IntegrationsService, ListIntegrationsOptions};
+pub use integrations::{CredentialsRefresher, IntegrationsService, ListIntegrationsOptions};
+pub use jobs::{JobsService, ListJobsOptions};
+pub use members::{InviteLinkOptions, ListMembersOptions, MembersService};
+pub use notification_routes::NotificationRoutesService;
+pub use pagination::{Anchored, Page, Paginator};
+pub use provisioning::{EnsureOutcome, Ensured, ProvisioningService};
+pub use security::SecurityService;
 pub use webhooks::{ListWebhooksOptions, WebhooksService};
 pub use workspaces::{ListWorkspacesOptions, WorkspacesService};
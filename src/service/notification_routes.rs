@@ -0,0 +1,119 @@
+//! Notification routes API service.
+//!
+//! This module provides methods for routing workspace events to chat
+//! channels through communication integrations.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{CreateNotificationRoute, NotificationRoute, UpdateNotificationRoute};
+
+/// Trait for Notification Routes API operations.
+pub trait NotificationRoutesService {
+    /// Lists notification routes in a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn list_notification_routes(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<NotificationRoute>>>;
+
+    /// Gets a notification route by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `route_id` - The route identifier
+    fn get_notification_route(
+        &self,
+        route_id: Uuid,
+    ) -> impl Future<Output = Result<NotificationRoute>>;
+
+    /// Creates a notification route.
+    ///
+    /// The referenced integration must be of type
+    /// [`IntegrationType::Communication`](crate::model::IntegrationType::Communication).
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `request` - The route creation request
+    fn create_notification_route(
+        &self,
+        workspace_id: Uuid,
+        request: CreateNotificationRoute,
+    ) -> impl Future<Output = Result<NotificationRoute>>;
+
+    /// Updates a notification route.
+    ///
+    /// # Arguments
+    ///
+    /// * `route_id` - The route identifier
+    /// * `update` - The update request
+    fn update_notification_route(
+        &self,
+        route_id: Uuid,
+        update: UpdateNotificationRoute,
+    ) -> impl Future<Output = Result<NotificationRoute>>;
+
+    /// Deletes a notification route.
+    ///
+    /// # Arguments
+    ///
+    /// * `route_id` - The route identifier
+    fn delete_notification_route(&self, route_id: Uuid) -> impl Future<Output = Result<()>>;
+}
+
+impl NotificationRoutesService for NvisyClient {
+    async fn list_notification_routes(&self, workspace_id: Uuid) -> Result<Vec<NotificationRoute>> {
+        let path = format!("/workspaces/{}/notification-routes/", workspace_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let routes: Vec<NotificationRoute> = self.read_json(response).await?;
+        Ok(routes)
+    }
+
+    async fn get_notification_route(&self, route_id: Uuid) -> Result<NotificationRoute> {
+        let path = format!("/notification-routes/{}", route_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let route: NotificationRoute = self.read_json(response).await?;
+        Ok(route)
+    }
+
+    async fn create_notification_route(
+        &self,
+        workspace_id: Uuid,
+        request: CreateNotificationRoute,
+    ) -> Result<NotificationRoute> {
+        let path = format!("/workspaces/{}/notification-routes/", workspace_id);
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let route: NotificationRoute = self.read_json(response).await?;
+        Ok(route)
+    }
+
+    async fn update_notification_route(
+        &self,
+        route_id: Uuid,
+        update: UpdateNotificationRoute,
+    ) -> Result<NotificationRoute> {
+        let path = format!("/notification-routes/{}", route_id);
+        let response = self.send_json(Method::PATCH, &path, &update).await?;
+        let response = response.error_for_status()?;
+        let route: NotificationRoute = self.read_json(response).await?;
+        Ok(route)
+    }
+
+    async fn delete_notification_route(&self, route_id: Uuid) -> Result<()> {
+        let path = format!("/notification-routes/{}", route_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,384 @@
+//! Cursor pagination shared by the list endpoints.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures_util::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures_util::future::LocalBoxFuture;
+use futures_util::{FutureExt, Stream, TryFutureExt, stream};
+
+use crate::error::{Error, Result};
+use crate::model::{
+    Cursor, File, FilesPage, Integration, IntegrationsPage, PageAnchor, Webhook, WebhooksPage,
+    Workspace, WorkspacesPage,
+};
+
+/// Page of a cursor-paginated listing.
+pub trait Page {
+    /// Type of the listed items.
+    type Item;
+
+    /// Splits the page into its items and the cursor of the next page.
+    fn into_parts(self) -> (Vec<Self::Item>, Option<Cursor>);
+}
+
+macro_rules! impl_page {
+    ($($page:ty => $item:ty),* $(,)?) => {
+        $(
+            impl Page for $page {
+                type Item = $item;
+
+                fn into_parts(self) -> (Vec<Self::Item>, Option<Cursor>) {
+                    (self.items, self.next_cursor)
+                }
+            }
+        )*
+    };
+}
+
+impl_page! {
+    FilesPage => File,
+    WorkspacesPage => Workspace,
+    WebhooksPage => Webhook,
+    IntegrationsPage => Integration,
+}
+
+/// Item whose position in a listing can be used to resume it.
+pub trait Anchored {
+    /// Returns the anchor positioned at this item.
+    fn anchor(&self) -> PageAnchor;
+}
+
+macro_rules! impl_anchored {
+    ($($item:ty => $id:ident),* $(,)?) => {
+        $(
+            impl Anchored for $item {
+                fn anchor(&self) -> PageAnchor {
+                    PageAnchor::new(self.created_at, self.$id)
+                }
+            }
+        )*
+    };
+}
+
+impl_anchored! {
+    File => file_id,
+    Workspace => workspace_id,
+    Webhook => webhook_id,
+    Integration => integration_id,
+}
+
+/// Future resolving to the items of a page and the cursor of the next one.
+#[cfg(not(target_arch = "wasm32"))]
+type PageFuture<'a, T> = BoxFuture<'a, Result<(Vec<T>, Option<Cursor>)>>;
+
+/// Future resolving to the items of a page and the cursor of the next one.
+#[cfg(target_arch = "wasm32")]
+type PageFuture<'a, T> = LocalBoxFuture<'a, Result<(Vec<T>, Option<Cursor>)>>;
+
+/// Loads the page at a position.
+#[cfg(not(target_arch = "wasm32"))]
+type FetchPage<'a, P, T> = Box<dyn Fn(P) -> PageFuture<'a, T> + Send + Sync + 'a>;
+
+/// Loads the page at a position.
+#[cfg(target_arch = "wasm32")]
+type FetchPage<'a, P, T> = Box<dyn Fn(P) -> PageFuture<'a, T> + 'a>;
+
+/// Reissues a listing after the last item seen.
+struct Resume<'a, T> {
+    fetch: FetchPage<'a, PageAnchor, T>,
+    anchor: fn(&T) -> PageAnchor,
+}
+
+/// Walks a cursor-paginated listing one page at a time.
+///
+/// Paginators are returned by the `paginate_*` methods of the services, such
+/// as [`FilesService::paginate_files`](super::FilesService::paginate_files).
+/// Pages are only requested as they are consumed.
+///
+/// If a cursor expires mid-listing, paginators returned by the services
+/// transparently reissue the listing after the last item seen. Items
+/// created or deleted meanwhile may then be missed or repeated; use
+/// [`strict`](Self::strict) to fail instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use nvisy_sdk::{NvisyClient, Result};
+/// # use nvisy_sdk::service::FilesService;
+/// # use uuid::Uuid;
+/// # async fn example(client: NvisyClient, workspace_id: Uuid) -> Result<()> {
+/// let mut pages = client.paginate_files(workspace_id, None);
+/// while let Some(files) = pages.next_page().await? {
+///     println!("{} files", files.len());
+/// }
+///
+/// // Or load at most 500 files at once.
+/// let files = client.paginate_files(workspace_id, None).collect_all(500).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<'a, T> {
+    fetch: FetchPage<'a, Option<Cursor>, T>,
+    resume: Option<Resume<'a, T>>,
+    /// Cursor of the next page, or `None` once the last page was loaded.
+    next: Option<Option<Cursor>>,
+    /// Position of the last item loaded.
+    last: Option<PageAnchor>,
+}
+
+impl<'a, T: 'a> Paginator<'a, T> {
+    /// Creates a paginator starting at `first`.
+    ///
+    /// `fetch` is called with the cursor of each page to load.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new<P, F, Fut>(first: Option<Cursor>, fetch: F) -> Self
+    where
+        P: Page<Item = T> + 'a,
+        F: Fn(Option<Cursor>) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = Result<P>> + Send + 'a,
+    {
+        Self::with_fetch(
+            first,
+            Box::new(move |after| fetch(after).map_ok(Page::into_parts).boxed()),
+        )
+    }
+
+    /// Creates a paginator starting at `first`.
+    ///
+    /// `fetch` is called with the cursor of each page to load.
+    #[cfg(target_arch = "wasm32")]
+    pub fn new<P, F, Fut>(first: Option<Cursor>, fetch: F) -> Self
+    where
+        P: Page<Item = T> + 'a,
+        F: Fn(Option<Cursor>) -> Fut + 'a,
+        Fut: Future<Output = Result<P>> + 'a,
+    {
+        Self::with_fetch(
+            first,
+            Box::new(move |after| fetch(after).map_ok(Page::into_parts).boxed_local()),
+        )
+    }
+
+    /// Creates a paginator from a boxed page loader.
+    fn with_fetch(first: Option<Cursor>, fetch: FetchPage<'a, Option<Cursor>, T>) -> Self {
+        Self {
+            fetch,
+            resume: None,
+            next: Some(first),
+            last: None,
+        }
+    }
+
+    /// Sets how the listing is reissued when a cursor expires.
+    ///
+    /// `resume` is called with the position of the last item seen and
+    /// loads the page that follows it.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_resume<P, F, Fut>(self, resume: F) -> Self
+    where
+        T: Anchored,
+        P: Page<Item = T> + 'a,
+        F: Fn(PageAnchor) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = Result<P>> + Send + 'a,
+    {
+        self.with_resume_fetch(Box::new(move |anchor| {
+            resume(anchor).map_ok(Page::into_parts).boxed()
+        }))
+    }
+
+    /// Sets how the listing is reissued when a cursor expires.
+    ///
+    /// `resume` is called with the position of the last item seen and
+    /// loads the page that follows it.
+    #[cfg(target_arch = "wasm32")]
+    pub fn with_resume<P, F, Fut>(self, resume: F) -> Self
+    where
+        T: Anchored,
+        P: Page<Item = T> + 'a,
+        F: Fn(PageAnchor) -> Fut + 'a,
+        Fut: Future<Output = Result<P>> + 'a,
+    {
+        self.with_resume_fetch(Box::new(move |anchor| {
+            resume(anchor).map_ok(Page::into_parts).boxed_local()
+        }))
+    }
+
+    /// Sets the boxed loader used to reissue the listing.
+    fn with_resume_fetch(mut self, fetch: FetchPage<'a, PageAnchor, T>) -> Self
+    where
+        T: Anchored,
+    {
+        self.resume = Some(Resume {
+            fetch,
+            anchor: T::anchor,
+        });
+        self
+    }
+
+    /// Fails with the API error when a cursor expires, instead of
+    /// reissuing the listing.
+    ///
+    /// Use this when missing or repeating items is not acceptable.
+    pub fn strict(mut self) -> Self {
+        self.resume = None;
+        self
+    }
+
+    /// Returns `true` if more pages may be loaded.
+    pub fn has_more(&self) -> bool {
+        self.next.is_some()
+    }
+
+    /// Loads the next page, or returns `None` once every page was loaded.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<T>>> {
+        let Some(after) = self.next.take() else {
+            return Ok(None);
+        };
+        let result = match (self.fetch)(after.clone()).await {
+            Err(err) if is_cursor_expired(&err) => match (&self.resume, &self.last) {
+                (Some(resume), Some(last)) => (resume.fetch)(last.clone()).await,
+                _ => Err(err),
+            },
+            result => result,
+        };
+        let (items, next) = match result {
+            Ok(page) => page,
+            Err(err) => {
+                // Allow the failed page to be requested again.
+                self.next = Some(after);
+                return Err(err);
+            }
+        };
+        if let (Some(resume), Some(item)) = (&self.resume, items.last()) {
+            self.last = Some((resume.anchor)(item));
+        }
+        self.next = next.map(Some);
+        Ok(Some(items))
+    }
+
+    /// Loads pages until the listing ends or `max_items` items were loaded.
+    ///
+    /// Returns at most `max_items` items.
+    pub async fn collect_all(mut self, max_items: usize) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        while items.len() < max_items {
+            let Some(page) = self.next_page().await? else {
+                break;
+            };
+            items.extend(page);
+        }
+        items.truncate(max_items);
+        Ok(items)
+    }
+
+    /// Calls `f` with every item, loading pages as needed.
+    ///
+    /// Stops at the first error returned by `f` or by a page request.
+    pub async fn try_for_each<F, Fut>(mut self, mut f: F) -> Result<()>
+    where
+        F: FnMut(T) -> Fut,
+        Fut: Future<Output = Result<()>>,
+    {
+        while let Some(page) = self.next_page().await? {
+            for item in page {
+                f(item).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Converts the paginator into a stream of items.
+    pub fn into_stream(self) -> impl Stream<Item = Result<T>> + 'a {
+        stream::try_unfold(
+            (self, VecDeque::new()),
+            |(mut pages, mut items)| async move {
+                loop {
+                    if let Some(item) = items.pop_front() {
+                        return Ok(Some((item, (pages, items))));
+                    }
+                    match pages.next_page().await? {
+                        Some(page) => items.extend(page),
+                        None => return Ok(None),
+                    }
+                }
+            },
+        )
+    }
+}
+
+impl<T> fmt::Debug for Paginator<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Paginator")
+            .field("next", &self.next)
+            .field("resumable", &self.resume.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Returns `true` if the error reports an expired pagination cursor.
+fn is_cursor_expired(err: &Error) -> bool {
+    matches!(err, Error::ApiResponse(body) if body.is_cursor_expired())
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::TryStreamExt;
+
+    use super::*;
+
+    struct NumbersPage(Vec<u32>, Option<Cursor>);
+
+    impl Page for NumbersPage {
+        type Item = u32;
+
+        fn into_parts(self) -> (Vec<u32>, Option<Cursor>) {
+            (self.0, self.1)
+        }
+    }
+
+    fn numbers() -> Paginator<'static, u32> {
+        Paginator::new(None, |after: Option<Cursor>| async move {
+            Ok(match after.as_ref().map(Cursor::as_str) {
+                None => NumbersPage(vec![1, 2], "a".parse().ok()),
+                Some("a") => NumbersPage(vec![], "b".parse().ok()),
+                _ => NumbersPage(vec![3], None),
+            })
+        })
+    }
+
+    #[tokio::test]
+    async fn test_paginator_follows_cursors() -> Result<()> {
+        let mut pages = numbers();
+        assert_eq!(pages.next_page().await?, Some(vec![1, 2]));
+        assert_eq!(pages.next_page().await?, Some(vec![]));
+        assert_eq!(pages.next_page().await?, Some(vec![3]));
+        assert_eq!(pages.next_page().await?, None);
+        assert!(!pages.has_more());
+
+        let items: Vec<u32> = numbers().into_stream().try_collect().await?;
+        assert_eq!(items, [1, 2, 3]);
+        assert_eq!(numbers().collect_all(1).await?, [1]);
+        Ok(())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_paginator_streams_are_send() -> Result<()> {
+        use uuid::Uuid;
+
+        use crate::NvisyClient;
+        use crate::service::FilesService;
+
+        fn assert_send<S: Send>(_: &S) {}
+
+        let client = NvisyClient::with_api_key("test-key")?;
+        assert_send(&numbers().into_stream());
+        assert_send(&client.paginate_files(Uuid::nil(), None));
+        assert_send(&client.list_files_stream(Uuid::nil(), None));
+        Ok(())
+    }
+}
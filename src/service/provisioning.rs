@@ -0,0 +1,407 @@
+//! Idempotent provisioning helpers.
+//!
+//! This module provides `ensure_*` methods that converge a resource to a
+//! desired specification, creating or updating it only when needed. They
+//! are intended for provisioning tools that run repeatedly against the
+//! same workspace.
+
+use std::future::Future;
+
+use uuid::Uuid;
+
+use super::{
+    IntegrationsService, ListIntegrationsOptions, ListWebhooksOptions, ListWorkspacesOptions,
+    WebhooksService, WorkspacesService,
+};
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{
+    CreateIntegration, CreateWebhook, CreateWorkspace, Integration, UpdateIntegration,
+    UpdateWebhook, UpdateWorkspace, Webhook, Workspace,
+};
+
+/// Trait for idempotent provisioning operations.
+pub trait ProvisioningService {
+    /// Ensures a webhook matching the specification exists in a workspace.
+    ///
+    /// The webhook is looked up by external identifier when the
+    /// specification has one, and by display name otherwise. If none exists
+    /// it is created; otherwise any fields that differ from the specification
+    /// are updated. Unset optional fields in the specification are left as-is.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `spec` - The desired webhook
+    fn ensure_webhook(
+        &self,
+        workspace_id: Uuid,
+        spec: CreateWebhook,
+    ) -> impl Future<Output = Result<Ensured<Webhook>>>;
+
+    /// Ensures an integration matching the specification exists in a workspace.
+    ///
+    /// The integration is looked up by external identifier when the
+    /// specification has one, and by name otherwise. Credentials and metadata are
+    /// only applied when the integration is created, since they cannot be
+    /// compared with the stored values.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `spec` - The desired integration
+    fn ensure_integration(
+        &self,
+        workspace_id: Uuid,
+        spec: CreateIntegration,
+    ) -> impl Future<Output = Result<Ensured<Integration>>>;
+
+    /// Ensures a workspace matching the specification exists.
+    ///
+    /// The workspace is looked up by external identifier when the
+    /// specification has one, and by display name otherwise, among the
+    /// workspaces accessible to the API key.
+    ///
+    /// # Arguments
+    ///
+    /// * `spec` - The desired workspace
+    fn ensure_workspace(
+        &self,
+        spec: CreateWorkspace,
+    ) -> impl Future<Output = Result<Ensured<Workspace>>>;
+}
+
+/// What an `ensure_*` call did to converge a resource.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EnsureOutcome {
+    /// The resource did not exist and was created.
+    Created,
+    /// The resource existed and was updated to match the specification.
+    Updated,
+    /// The resource already matched the specification.
+    Unchanged,
+}
+
+/// Resource returned by an `ensure_*` call, with what was done to it.
+#[derive(Clone, Debug)]
+pub struct Ensured<T> {
+    /// The resource in its current state.
+    pub resource: T,
+    /// What was done to converge the resource.
+    pub outcome: EnsureOutcome,
+}
+
+impl<T> Ensured<T> {
+    /// Returns `true` if the resource was created or updated.
+    pub fn changed(&self) -> bool {
+        self.outcome != EnsureOutcome::Unchanged
+    }
+}
+
+impl ProvisioningService for NvisyClient {
+    async fn ensure_webhook(
+        &self,
+        workspace_id: Uuid,
+        spec: CreateWebhook,
+    ) -> Result<Ensured<Webhook>> {
+        let existing = match &spec.external_id {
+            Some(external_id) => {
+                self.find_webhook_by_external_id(workspace_id, external_id)
+                    .await?
+            }
+            None => {
+                let mut options = ListWebhooksOptions::new();
+                loop {
+                    let page = self.list_webhooks(workspace_id, Some(options)).await?;
+                    let found = page
+                        .items
+                        .into_iter()
+                        .find(|webhook| webhook.display_name == spec.display_name);
+                    match (found, page.next_cursor) {
+                        (Some(webhook), _) => break Some(webhook),
+                        (None, Some(cursor)) => options = ListWebhooksOptions::new().after(cursor),
+                        (None, None) => break None,
+                    }
+                }
+            }
+        };
+
+        let Some(existing) = existing else {
+            let webhook = self.create_webhook(workspace_id, spec).await?;
+            return Ok(Ensured {
+                resource: webhook,
+                outcome: EnsureOutcome::Created,
+            });
+        };
+
+        match webhook_update(&existing, spec) {
+            Some(update) => Ok(Ensured {
+                resource: self.update_webhook(existing.webhook_id, update).await?,
+                outcome: EnsureOutcome::Updated,
+            }),
+            None => Ok(Ensured {
+                resource: existing,
+                outcome: EnsureOutcome::Unchanged,
+            }),
+        }
+    }
+
+    async fn ensure_integration(
+        &self,
+        workspace_id: Uuid,
+        spec: CreateIntegration,
+    ) -> Result<Ensured<Integration>> {
+        let existing = match &spec.external_id {
+            Some(external_id) => {
+                self.find_integration_by_external_id(workspace_id, external_id)
+                    .await?
+            }
+            None => {
+                let mut options = ListIntegrationsOptions::new();
+                loop {
+                    let page = self.list_integrations(workspace_id, Some(options)).await?;
+                    let found = page
+                        .items
+                        .into_iter()
+                        .find(|integration| integration.integration_name == spec.integration_name);
+                    match (found, page.next_cursor) {
+                        (Some(integration), _) => break Some(integration),
+                        (None, Some(cursor)) => {
+                            options = ListIntegrationsOptions::new().after(cursor)
+                        }
+                        (None, None) => break None,
+                    }
+                }
+            }
+        };
+
+        let Some(existing) = existing else {
+            let integration = self.create_integration(workspace_id, spec).await?;
+            return Ok(Ensured {
+                resource: integration,
+                outcome: EnsureOutcome::Created,
+            });
+        };
+
+        match integration_update(&existing, spec) {
+            Some(update) => Ok(Ensured {
+                resource: self
+                    .update_integration(existing.integration_id, update)
+                    .await?,
+                outcome: EnsureOutcome::Updated,
+            }),
+            None => Ok(Ensured {
+                resource: existing,
+                outcome: EnsureOutcome::Unchanged,
+            }),
+        }
+    }
+
+    async fn ensure_workspace(&self, spec: CreateWorkspace) -> Result<Ensured<Workspace>> {
+        let existing = match &spec.external_id {
+            Some(external_id) => self.find_workspace_by_external_id(external_id).await?,
+            None => {
+                let mut options = ListWorkspacesOptions::new();
+                loop {
+                    let page = self.list_workspaces(Some(options)).await?;
+                    let found = page
+                        .items
+                        .into_iter()
+                        .find(|workspace| workspace.display_name == spec.display_name);
+                    match (found, page.next_cursor) {
+                        (Some(workspace), _) => break Some(workspace),
+                        (None, Some(cursor)) => {
+                            options = ListWorkspacesOptions::new().after(cursor)
+                        }
+                        (None, None) => break None,
+                    }
+                }
+            }
+        };
+
+        let Some(existing) = existing else {
+            let workspace = self.create_workspace(spec).await?;
+            return Ok(Ensured {
+                resource: workspace,
+                outcome: EnsureOutcome::Created,
+            });
+        };
+
+        match workspace_update(&existing, spec) {
+            Some(update) => Ok(Ensured {
+                resource: self.update_workspace(existing.workspace_id, update).await?,
+                outcome: EnsureOutcome::Updated,
+            }),
+            None => Ok(Ensured {
+                resource: existing,
+                outcome: EnsureOutcome::Unchanged,
+            }),
+        }
+    }
+}
+
+/// Returns `true` if both lists contain the same elements the same number of
+/// times, ignoring order.
+fn same_elements<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+    let count = |list: &[T], item: &T| list.iter().filter(|other| *other == item).count();
+    a.len() == b.len() && a.iter().all(|item| count(a, item) == count(b, item))
+}
+
+/// Builds the update needed to make a webhook match the specification.
+fn webhook_update(existing: &Webhook, spec: CreateWebhook) -> Option<UpdateWebhook> {
+    let mut update = UpdateWebhook::default();
+    let mut changed = false;
+
+    if existing.description != spec.description {
+        update.description = Some(spec.description);
+        changed = true;
+    }
+    if existing.url != spec.url {
+        update.url = Some(spec.url);
+        changed = true;
+    }
+    if !same_elements(&existing.events, &spec.events) {
+        update.events = Some(spec.events);
+        changed = true;
+    }
+    if let Some(headers) = spec.headers
+        && existing.headers != headers
+    {
+        update.headers = Some(headers);
+        changed = true;
+    }
+    if let Some(status) = spec.status
+        && existing.status != status
+    {
+        update.status = Some(status);
+        changed = true;
+    }
+    if let Some(payload_format) = spec.payload_format
+        && existing.payload_format != payload_format
+    {
+        update.payload_format = Some(payload_format);
+        changed = true;
+    }
+    if let Some(batching) = spec.batching
+        && existing.batching.as_ref() != Some(&batching)
+    {
+        update.batching = Some(batching);
+        changed = true;
+    }
+
+    changed.then_some(update)
+}
+
+/// Builds the update needed to make an integration match the specification.
+fn integration_update(
+    existing: &Integration,
+    spec: CreateIntegration,
+) -> Option<UpdateIntegration> {
+    let mut update = UpdateIntegration::default();
+    let mut changed = false;
+
+    if existing.description != spec.description {
+        update.description = Some(spec.description);
+        changed = true;
+    }
+    if existing.integration_type != spec.integration_type {
+        update.integration_type = Some(spec.integration_type);
+        changed = true;
+    }
+    if let Some(conflict_policy) = spec.conflict_policy
+        && existing.conflict_policy.as_ref() != Some(&conflict_policy)
+    {
+        update.conflict_policy = Some(conflict_policy);
+        changed = true;
+    }
+    if let Some(is_active) = spec.is_active
+        && existing.is_active != is_active
+    {
+        update.is_active = Some(is_active);
+        changed = true;
+    }
+
+    changed.then_some(update)
+}
+
+/// Builds the update needed to make a workspace match the specification.
+fn workspace_update(existing: &Workspace, spec: CreateWorkspace) -> Option<UpdateWorkspace> {
+    let mut update = UpdateWorkspace::default();
+    let mut changed = false;
+
+    if let Some(description) = spec.description
+        && existing.description.as_ref() != Some(&description)
+    {
+        update.description = Some(description);
+        changed = true;
+    }
+    if !same_elements(&existing.tags, &spec.tags) {
+        update.tags = Some(spec.tags);
+        changed = true;
+    }
+    if existing.enable_comments != spec.enable_comments {
+        update.enable_comments = Some(spec.enable_comments);
+        changed = true;
+    }
+    if existing.require_approval != spec.require_approval {
+        update.require_approval = Some(spec.require_approval);
+        changed = true;
+    }
+
+    changed.then_some(update)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{WebhookEvent, WorkspaceRole};
+
+    fn workspace() -> Workspace {
+        Workspace {
+            workspace_id: Uuid::nil(),
+            external_id: None,
+            display_name: "Legal".to_string(),
+            description: Some("Contracts".to_string()),
+            tags: vec!["a".to_string(), "b".to_string()],
+            enable_comments: true,
+            require_approval: false,
+            member_role: WorkspaceRole::Owner,
+            created_by: Uuid::nil(),
+            created_at: jiff::Timestamp::UNIX_EPOCH,
+            updated_at: jiff::Timestamp::UNIX_EPOCH,
+            counts: None,
+        }
+    }
+
+    #[test]
+    fn test_workspace_update_unchanged() {
+        let spec = CreateWorkspace::new("Legal")
+            .with_description("Contracts")
+            .with_tags(vec!["b".to_string(), "a".to_string()]);
+        assert!(workspace_update(&workspace(), spec).is_none());
+    }
+
+    #[test]
+    fn test_workspace_update_changed_fields_only() {
+        let spec = CreateWorkspace::new("Legal")
+            .with_tags(vec!["a".to_string(), "b".to_string()])
+            .with_approval(true);
+        let update = workspace_update(&workspace(), spec).unwrap();
+        assert_eq!(update.require_approval, Some(true));
+        assert_eq!(update.description, None);
+        assert_eq!(update.tags, None);
+        assert_eq!(update.enable_comments, None);
+    }
+
+    #[test]
+    fn test_same_elements_ignores_order() {
+        let a = [WebhookEvent::FileCreated, WebhookEvent::FileDeleted];
+        let b = [WebhookEvent::FileDeleted, WebhookEvent::FileCreated];
+        assert!(same_elements(&a, &b));
+        assert!(!same_elements(&a, &b[..1]));
+
+        let duplicated = [WebhookEvent::FileCreated, WebhookEvent::FileCreated];
+        assert!(!same_elements(&a, &duplicated));
+        assert!(!same_elements(&duplicated, &a));
+    }
+}
@@ -0,0 +1,152 @@
+//! Query string encoding shared by the service endpoints.
+//!
+//! Values are encoded through their `Serialize` implementation, so enums and
+//! timestamps are sent in the same form the API uses in JSON bodies. List
+//! parameters are encoded according to the [`ListStyle`] each endpoint
+//! declares, since the API does not accept both forms everywhere.
+
+use reqwest::RequestBuilder;
+use serde::Serialize;
+
+use crate::model::PageAnchor;
+
+/// Encoding of a list-valued query parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ListStyle {
+    /// Repeats the key for every value: `formats=pdf&formats=png`.
+    Repeat,
+    /// Joins the values with commas under one key: `formats=pdf,png`.
+    Comma,
+}
+
+/// Query parameters of a request, in insertion order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Query {
+    pairs: Vec<(&'static str, String)>,
+}
+
+impl Query {
+    /// Creates an empty query.
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a parameter.
+    pub(crate) fn push(mut self, key: &'static str, value: &impl Serialize) -> Self {
+        self.pairs.push((key, encode(value)));
+        self
+    }
+
+    /// Adds a parameter if the value is present.
+    pub(crate) fn opt(self, key: &'static str, value: Option<&impl Serialize>) -> Self {
+        match value {
+            Some(value) => self.push(key, value),
+            None => self,
+        }
+    }
+
+    /// Adds a list parameter in the given style. Empty lists are omitted.
+    pub(crate) fn list<'a, T: Serialize + 'a>(
+        mut self,
+        key: &'static str,
+        values: impl IntoIterator<Item = &'a T>,
+        style: ListStyle,
+    ) -> Self {
+        let values = values.into_iter().map(encode);
+        match style {
+            ListStyle::Repeat => self.pairs.extend(values.map(|value| (key, value))),
+            ListStyle::Comma => {
+                let joined = values.collect::<Vec<_>>().join(",");
+                if !joined.is_empty() {
+                    self.pairs.push((key, joined));
+                }
+            }
+        }
+        self
+    }
+
+    /// Adds the parameters selecting items after an anchor, if present.
+    pub(crate) fn anchor(mut self, anchor: Option<&PageAnchor>) -> Self {
+        if let Some(anchor) = anchor {
+            self.pairs.extend(anchor.query());
+        }
+        self
+    }
+
+    /// Returns the encoded parameters.
+    #[cfg(test)]
+    pub(crate) fn pairs(&self) -> &[(&'static str, String)] {
+        &self.pairs
+    }
+
+    /// Appends the parameters to a request.
+    pub(crate) fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        if self.pairs.is_empty() {
+            return request;
+        }
+        request.query(&self.pairs)
+    }
+}
+
+/// Encodes a value as a query parameter value.
+///
+/// Strings are sent unquoted; other values use their JSON representation.
+fn encode(value: &impl Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(value)) => value,
+        Ok(value) => value.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::Timestamp;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::model::{FileFormat, WebhookEvent};
+
+    fn encoded(query: &Query) -> Vec<(&str, &str)> {
+        query
+            .pairs()
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_list_styles() {
+        let formats = [FileFormat::Pdf, FileFormat::Docx];
+        let repeat = Query::new().list("formats", &formats, ListStyle::Repeat);
+        assert_eq!(encoded(&repeat), [("formats", "pdf"), ("formats", "docx")]);
+
+        let comma = Query::new().list("formats", &formats, ListStyle::Comma);
+        assert_eq!(encoded(&comma), [("formats", "pdf,docx")]);
+
+        let empty = Query::new().list::<FileFormat>("formats", &[], ListStyle::Comma);
+        assert!(empty.pairs().is_empty());
+    }
+
+    #[test]
+    fn test_values_use_wire_format() {
+        let since: Timestamp = "2024-01-01T00:00:00Z".parse().unwrap();
+        let query = Query::new()
+            .push("since", &since)
+            .push("type", &WebhookEvent::MemberAdded)
+            .opt("limit", Some(&10))
+            .opt("search", None::<&String>)
+            .anchor(Some(&PageAnchor::new(since, Uuid::nil())));
+
+        assert_eq!(
+            encoded(&query),
+            [
+                ("since", "2024-01-01T00:00:00Z"),
+                ("type", "member_added"),
+                ("limit", "10"),
+                ("anchorCreatedAt", "2024-01-01T00:00:00Z"),
+                ("anchorId", "00000000-0000-0000-0000-000000000000"),
+            ]
+        );
+    }
+}
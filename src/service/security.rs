@@ -0,0 +1,97 @@
+//! Security API service.
+//!
+//! This module provides methods for managing organization-level security
+//! policies such as IP allowlists, token lifetimes, and download restrictions.
+
+use std::future::Future;
+
+use reqwest::Method;
+use uuid::Uuid;
+
+use crate::client::NvisyClient;
+use crate::error::Result;
+use crate::model::{
+    CreateIpAllowlistEntry, IpAllowlistEntry, SecurityPolicy, UpdateSecurityPolicy,
+};
+
+/// Trait for Security API operations.
+pub trait SecurityService {
+    /// Gets the organization security policy.
+    fn get_security_policy(&self) -> impl Future<Output = Result<SecurityPolicy>>;
+
+    /// Updates the organization security policy.
+    ///
+    /// Only provided fields are updated.
+    ///
+    /// # Arguments
+    ///
+    /// * `update` - The update request
+    fn update_security_policy(
+        &self,
+        update: UpdateSecurityPolicy,
+    ) -> impl Future<Output = Result<SecurityPolicy>>;
+
+    /// Lists the entries in the IP allowlist.
+    fn list_ip_allowlist(&self) -> impl Future<Output = Result<Vec<IpAllowlistEntry>>>;
+
+    /// Adds an entry to the IP allowlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The allowlist entry to add
+    fn add_ip_allowlist_entry(
+        &self,
+        request: CreateIpAllowlistEntry,
+    ) -> impl Future<Output = Result<IpAllowlistEntry>>;
+
+    /// Removes an entry from the IP allowlist.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry_id` - The allowlist entry identifier
+    fn remove_ip_allowlist_entry(&self, entry_id: Uuid) -> impl Future<Output = Result<()>>;
+}
+
+impl SecurityService for NvisyClient {
+    async fn get_security_policy(&self) -> Result<SecurityPolicy> {
+        let response = self.send(Method::GET, "/security/policy").await?;
+        let response = response.error_for_status()?;
+        let policy: SecurityPolicy = self.read_json(response).await?;
+        Ok(policy)
+    }
+
+    async fn update_security_policy(&self, update: UpdateSecurityPolicy) -> Result<SecurityPolicy> {
+        let response = self
+            .send_json(Method::PATCH, "/security/policy", &update)
+            .await?;
+        let response = response.error_for_status()?;
+        let policy: SecurityPolicy = self.read_json(response).await?;
+        Ok(policy)
+    }
+
+    async fn list_ip_allowlist(&self) -> Result<Vec<IpAllowlistEntry>> {
+        let response = self.send(Method::GET, "/security/ip-allowlist/").await?;
+        let response = response.error_for_status()?;
+        let entries: Vec<IpAllowlistEntry> = self.read_json(response).await?;
+        Ok(entries)
+    }
+
+    async fn add_ip_allowlist_entry(
+        &self,
+        request: CreateIpAllowlistEntry,
+    ) -> Result<IpAllowlistEntry> {
+        let response = self
+            .send_json(Method::POST, "/security/ip-allowlist/", &request)
+            .await?;
+        let response = response.error_for_status()?;
+        let entry: IpAllowlistEntry = self.read_json(response).await?;
+        Ok(entry)
+    }
+
+    async fn remove_ip_allowlist_entry(&self, entry_id: Uuid) -> Result<()> {
+        let path = format!("/security/ip-allowlist/{}", entry_id);
+        let response = self.send(Method::DELETE, &path).await?;
+        response.error_for_status()?;
+        Ok(())
+    }
+}
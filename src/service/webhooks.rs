@@ -4,13 +4,17 @@
 
 use std::future::Future;
 
+use futures_util::Stream;
 use reqwest::Method;
 use uuid::Uuid;
 
+use super::pagination::Paginator;
+use super::query::Query;
 use crate::client::NvisyClient;
 use crate::error::Result;
 use crate::model::{
-    CreateWebhook, TestWebhook, UpdateWebhook, Webhook, WebhookResult, WebhooksPage,
+    CreateWebhook, Cursor, PageAnchor, TestWebhook, UpdateWebhook, Webhook, WebhookResult,
+    WebhooksPage,
 };
 
 /// Trait for Webhooks API operations.
@@ -27,6 +31,50 @@ pub trait WebhooksService {
         options: Option<ListWebhooksOptions>,
     ) -> impl Future<Output = Result<WebhooksPage>>;
 
+    /// Lists all webhooks in a workspace, following pagination cursors.
+    ///
+    /// Pages are fetched lazily as the stream is polled. The `after` option
+    /// sets where the listing starts and `limit` sets the page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (page size)
+    fn list_webhooks_stream(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListWebhooksOptions>,
+    ) -> impl Stream<Item = Result<Webhook>> + '_;
+
+    /// Returns a paginator over all webhooks in a workspace.
+    ///
+    /// Pages are fetched as they are requested. The `after` option sets
+    /// where the listing starts and `limit` sets the page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `options` - Optional listing options (page size)
+    fn paginate_webhooks(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListWebhooksOptions>,
+    ) -> Paginator<'_, Webhook>;
+
+    /// Finds a webhook in a workspace by its external identifier.
+    ///
+    /// Returns `None` if no webhook in the workspace has the given identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `external_id` - The external identifier
+    fn find_webhook_by_external_id(
+        &self,
+        workspace_id: Uuid,
+        external_id: &str,
+    ) -> impl Future<Output = Result<Option<Webhook>>>;
+
     /// Gets a webhook by ID.
     ///
     /// # Arguments
@@ -81,8 +129,12 @@ pub trait WebhooksService {
 /// Options for listing webhooks.
 #[derive(Clone, Debug, Default)]
 pub struct ListWebhooksOptions {
+    /// Filter by external identifier.
+    pub external_id: Option<String>,
     /// Pagination cursor.
-    pub after: Option<String>,
+    pub after: Option<Cursor>,
+    /// Resume after the given item instead of at a cursor.
+    pub starting_after: Option<PageAnchor>,
     /// Maximum number of results.
     pub limit: Option<i32>,
 }
@@ -93,9 +145,21 @@ impl ListWebhooksOptions {
         Self::default()
     }
 
+    /// Sets the external identifier filter.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
     /// Sets the pagination cursor.
-    pub fn after(mut self, cursor: impl Into<String>) -> Self {
-        self.after = Some(cursor.into());
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Resumes the listing after the given item.
+    pub fn starting_after(mut self, anchor: PageAnchor) -> Self {
+        self.starting_after = Some(anchor);
         self
     }
 
@@ -115,26 +179,67 @@ impl WebhooksService for NvisyClient {
         let path = format!("/workspaces/{}/webhooks/", workspace_id);
         let opts = options.unwrap_or_default();
 
-        let mut req = self.request_builder(Method::GET, &path)?;
-
-        if let Some(after) = &opts.after {
-            req = req.query(&[("after", after)]);
-        }
-        if let Some(limit) = opts.limit {
-            req = req.query(&[("limit", limit)]);
-        }
+        let query = Query::new()
+            .opt("externalId", opts.external_id.as_ref())
+            .opt("after", opts.after.as_ref())
+            .anchor(opts.starting_after.as_ref())
+            .opt("limit", opts.limit.as_ref());
+        let req = query.apply(self.request_builder(Method::GET, &path)?);
 
-        let response = req.send().await?;
+        let response = self.execute(req).await?;
         let response = response.error_for_status()?;
-        let page: WebhooksPage = response.json().await?;
+        let page: WebhooksPage = self.read_json(response).await?;
         Ok(page)
     }
 
+    fn list_webhooks_stream(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListWebhooksOptions>,
+    ) -> impl Stream<Item = Result<Webhook>> + '_ {
+        self.paginate_webhooks(workspace_id, options).into_stream()
+    }
+
+    fn paginate_webhooks(
+        &self,
+        workspace_id: Uuid,
+        options: Option<ListWebhooksOptions>,
+    ) -> Paginator<'_, Webhook> {
+        let mut options = options.unwrap_or_default();
+        let first = options.after.take();
+        let resume_options = options.clone();
+        Paginator::new(first, move |after| {
+            let options = ListWebhooksOptions {
+                after,
+                ..options.clone()
+            };
+            self.list_webhooks(workspace_id, Some(options))
+        })
+        .with_resume(move |anchor| {
+            let options = ListWebhooksOptions {
+                after: None,
+                starting_after: Some(anchor),
+                ..resume_options.clone()
+            };
+            self.list_webhooks(workspace_id, Some(options))
+        })
+    }
+
+    async fn find_webhook_by_external_id(
+        &self,
+        workspace_id: Uuid,
+        external_id: &str,
+    ) -> Result<Option<Webhook>> {
+        let options = ListWebhooksOptions::new().external_id(external_id).limit(1);
+        let page = self.list_webhooks(workspace_id, Some(options)).await?;
+        Ok(page.items.into_iter().next())
+    }
+
     async fn get_webhook(&self, webhook_id: Uuid) -> Result<Webhook> {
         let path = format!("/webhooks/{}/", webhook_id);
         let response = self.send(Method::GET, &path).await?;
         let response = response.error_for_status()?;
-        let webhook: Webhook = response.json().await?;
+        let webhook: Webhook = self.read_json(response).await?;
         Ok(webhook)
     }
 
@@ -142,7 +247,7 @@ impl WebhooksService for NvisyClient {
         let path = format!("/workspaces/{}/webhooks/", workspace_id);
         let response = self.send_json(Method::POST, &path, &request).await?;
         let response = response.error_for_status()?;
-        let webhook: Webhook = response.json().await?;
+        let webhook: Webhook = self.read_json(response).await?;
         Ok(webhook)
     }
 
@@ -150,7 +255,7 @@ impl WebhooksService for NvisyClient {
         let path = format!("/webhooks/{}/", webhook_id);
         let response = self.send_json(Method::PATCH, &path, &update).await?;
         let response = response.error_for_status()?;
-        let webhook: Webhook = response.json().await?;
+        let webhook: Webhook = self.read_json(response).await?;
         Ok(webhook)
     }
 
@@ -172,7 +277,7 @@ impl WebhooksService for NvisyClient {
             None => self.send(Method::POST, &path).await?,
         };
         let response = response.error_for_status()?;
-        let result: WebhookResult = response.json().await?;
+        let result: WebhookResult = self.read_json(response).await?;
         Ok(result)
     }
 }
@@ -4,14 +4,22 @@
 
 use std::future::Future;
 
+use futures_util::{Stream, TryStreamExt};
 use reqwest::Method;
 use uuid::Uuid;
 
+use super::FilesService;
+use super::batch;
+use super::pagination::Paginator;
+use super::query::Query;
 use crate::client::NvisyClient;
 use crate::error::Result;
 use crate::model::{
-    CreateWorkspace, NotificationSettings, UpdateNotificationSettings, UpdateWorkspace, Workspace,
-    WorkspacesPage,
+    Batch, CreateWorkspace, CreateWorkspaceMerge, Cursor, EncryptionInfo, File, IngestEmail,
+    MergeOptions, NotificationDigest, NotificationSettings, PageAnchor, SnapshotRestore,
+    StatsWindow, UpdateIngestEmail, UpdateNotificationSettings, UpdateWorkspace, Workspace,
+    WorkspaceComparison, WorkspaceConfig, WorkspaceConfigApplied, WorkspaceDataInfo,
+    WorkspaceMerge, WorkspaceSnapshot, WorkspaceStats, WorkspacesPage,
 };
 
 /// Trait for Workspaces API operations.
@@ -26,6 +34,44 @@ pub trait WorkspacesService {
         options: Option<ListWorkspacesOptions>,
     ) -> impl Future<Output = Result<WorkspacesPage>>;
 
+    /// Lists all workspaces the authenticated user is a member of, following pagination cursors.
+    ///
+    /// Pages are fetched lazily as the stream is polled. The `after` option
+    /// sets where the listing starts and `limit` sets the page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional listing options (page size)
+    fn list_workspaces_stream(
+        &self,
+        options: Option<ListWorkspacesOptions>,
+    ) -> impl Stream<Item = Result<Workspace>> + '_;
+
+    /// Returns a paginator over all workspaces the authenticated user is a member of.
+    ///
+    /// Pages are fetched as they are requested. The `after` option sets
+    /// where the listing starts and `limit` sets the page size.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional listing options (page size)
+    fn paginate_workspaces(
+        &self,
+        options: Option<ListWorkspacesOptions>,
+    ) -> Paginator<'_, Workspace>;
+
+    /// Finds a workspace by its external identifier.
+    ///
+    /// Returns `None` if no accessible workspace has the given identifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `external_id` - The external identifier
+    fn find_workspace_by_external_id(
+        &self,
+        external_id: &str,
+    ) -> impl Future<Output = Result<Option<Workspace>>>;
+
     /// Gets a workspace by ID.
     ///
     /// # Arguments
@@ -33,6 +79,21 @@ pub trait WorkspacesService {
     /// * `workspace_id` - The workspace identifier
     fn get_workspace(&self, workspace_id: Uuid) -> impl Future<Output = Result<Workspace>>;
 
+    /// Gets many workspaces by ID with as few requests as possible.
+    ///
+    /// Workspaces that do not exist are listed in [`Batch::not_found`]
+    /// instead of failing the call. Against servers without the batch
+    /// endpoint, the workspaces are fetched individually with bounded
+    /// concurrency.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_ids` - The workspace identifiers
+    fn get_workspaces_batch(
+        &self,
+        workspace_ids: &[Uuid],
+    ) -> impl Future<Output = Result<Batch<Workspace>>>;
+
     /// Creates a new workspace.
     ///
     /// The creator is automatically added as an owner.
@@ -89,15 +150,248 @@ pub trait WorkspacesService {
         workspace_id: Uuid,
         update: UpdateNotificationSettings,
     ) -> impl Future<Output = Result<NotificationSettings>>;
+
+    /// Previews the next notification digest for a workspace.
+    ///
+    /// Returns what the next digest email would contain given the current
+    /// notification settings, without sending anything.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn preview_notification_digest(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<NotificationDigest>>;
+
+    /// Gets data residency and encryption information for a workspace.
+    ///
+    /// Returns the storage region, encryption-at-rest key type, and retention
+    /// guarantees, suitable for compliance reporting.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn get_workspace_data_info(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<WorkspaceDataInfo>>;
+
+    /// Gets time-bucketed activity statistics for a workspace.
+    ///
+    /// Returns counts of uploads, downloads, comments, webhook deliveries,
+    /// and processing failures per bucket, suitable for charting.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `window` - Time range to cover
+    fn get_workspace_stats(
+        &self,
+        workspace_id: Uuid,
+        window: StatsWindow,
+    ) -> impl Future<Output = Result<WorkspaceStats>>;
+
+    /// Rotates the customer-managed encryption key for a workspace.
+    ///
+    /// Only applicable to workspaces using a customer-managed key.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn rotate_workspace_encryption_key(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<EncryptionInfo>>;
+
+    /// Gets the inbound email address of a workspace.
+    ///
+    /// Attachments of messages sent to the address are uploaded as files,
+    /// and an [`EmailIngested`](crate::model::WebhookEvent::EmailIngested)
+    /// event is delivered for each message.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn get_ingest_email(&self, workspace_id: Uuid) -> impl Future<Output = Result<IngestEmail>>;
+
+    /// Replaces the inbound email address of a workspace with a new one.
+    ///
+    /// The previous address stops accepting messages immediately. Settings
+    /// such as allowed senders are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn rotate_ingest_email(&self, workspace_id: Uuid) -> impl Future<Output = Result<IngestEmail>>;
+
+    /// Updates the inbound email settings of a workspace.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `update` - The update request
+    fn update_ingest_email(
+        &self,
+        workspace_id: Uuid,
+        update: UpdateIngestEmail,
+    ) -> impl Future<Output = Result<IngestEmail>>;
+
+    /// Starts a snapshot of a workspace's files, metadata, and settings.
+    ///
+    /// Snapshots are taken asynchronously; poll
+    /// [`get_workspace_snapshot`](Self::get_workspace_snapshot) until the
+    /// status is terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn create_workspace_snapshot(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<WorkspaceSnapshot>>;
+
+    /// Lists snapshots of a workspace, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn list_workspace_snapshots(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<WorkspaceSnapshot>>>;
+
+    /// Gets a workspace snapshot by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `snapshot_id` - The snapshot identifier
+    fn get_workspace_snapshot(
+        &self,
+        workspace_id: Uuid,
+        snapshot_id: Uuid,
+    ) -> impl Future<Output = Result<WorkspaceSnapshot>>;
+
+    /// Starts restoring a workspace to a snapshot.
+    ///
+    /// Changes made since the snapshot was taken are rolled back. Restores
+    /// run asynchronously; poll
+    /// [`get_snapshot_restore`](Self::get_snapshot_restore) until the status
+    /// is terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `snapshot_id` - The snapshot to restore
+    fn restore_workspace_snapshot(
+        &self,
+        workspace_id: Uuid,
+        snapshot_id: Uuid,
+    ) -> impl Future<Output = Result<SnapshotRestore>>;
+
+    /// Gets a snapshot restore job by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `restore_id` - The restore job identifier
+    fn get_snapshot_restore(
+        &self,
+        workspace_id: Uuid,
+        restore_id: Uuid,
+    ) -> impl Future<Output = Result<SnapshotRestore>>;
+
+    /// Exports a workspace's setup as a declarative configuration document.
+    ///
+    /// The document covers settings, notifications, webhooks, integrations,
+    /// member roles, and folders. Secrets are never included.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    fn export_workspace_config(
+        &self,
+        workspace_id: Uuid,
+    ) -> impl Future<Output = Result<WorkspaceConfig>>;
+
+    /// Applies a declarative configuration document to a workspace.
+    ///
+    /// Resources are matched by name; missing ones are created, differing
+    /// ones are updated, and ones absent from the document are removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_id` - The workspace identifier
+    /// * `config` - The desired configuration
+    fn apply_workspace_config(
+        &self,
+        workspace_id: Uuid,
+        config: WorkspaceConfig,
+    ) -> impl Future<Output = Result<WorkspaceConfigApplied>>;
+
+    /// Compares the files, settings, and members of two workspaces.
+    ///
+    /// Files are matched by checksum, or by display name when a checksum is
+    /// unavailable. Useful when consolidating duplicate workspaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `workspace_a` - The first workspace identifier
+    /// * `workspace_b` - The second workspace identifier
+    fn compare_workspaces(
+        &self,
+        workspace_a: Uuid,
+        workspace_b: Uuid,
+    ) -> impl Future<Output = Result<WorkspaceComparison>>;
+
+    /// Starts merging one workspace into another.
+    ///
+    /// Moves files, members, webhooks, and settings from the source to the
+    /// target, resolving conflicts according to `options`. Set
+    /// [`MergeOptions::dry_run`] to preview the changes and conflicts
+    /// without applying them. Merges run asynchronously; poll
+    /// [`get_workspace_merge`](Self::get_workspace_merge) until the status
+    /// is terminal.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_id` - The workspace to merge from
+    /// * `target_id` - The workspace to merge into
+    /// * `options` - What to merge and how to resolve conflicts
+    fn merge_workspaces(
+        &self,
+        source_id: Uuid,
+        target_id: Uuid,
+        options: MergeOptions,
+    ) -> impl Future<Output = Result<WorkspaceMerge>>;
+
+    /// Gets a workspace merge job by ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_id` - The workspace being merged into
+    /// * `merge_id` - The merge job identifier
+    fn get_workspace_merge(
+        &self,
+        target_id: Uuid,
+        merge_id: Uuid,
+    ) -> impl Future<Output = Result<WorkspaceMerge>>;
 }
 
 /// Options for listing workspaces.
 #[derive(Clone, Debug, Default)]
 pub struct ListWorkspacesOptions {
+    /// Filter by external identifier.
+    pub external_id: Option<String>,
     /// Pagination cursor.
-    pub after: Option<String>,
+    pub after: Option<Cursor>,
+    /// Resume after the given item instead of at a cursor.
+    pub starting_after: Option<PageAnchor>,
     /// Maximum number of results.
     pub limit: Option<i32>,
+    /// Whether to include resource counts in each workspace.
+    pub include_counts: bool,
 }
 
 impl ListWorkspacesOptions {
@@ -106,9 +400,21 @@ impl ListWorkspacesOptions {
         Self::default()
     }
 
+    /// Sets the external identifier filter.
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
     /// Sets the pagination cursor.
-    pub fn after(mut self, cursor: impl Into<String>) -> Self {
-        self.after = Some(cursor.into());
+    pub fn after(mut self, cursor: Cursor) -> Self {
+        self.after = Some(cursor);
+        self
+    }
+
+    /// Resumes the listing after the given item.
+    pub fn starting_after(mut self, anchor: PageAnchor) -> Self {
+        self.starting_after = Some(anchor);
         self
     }
 
@@ -117,6 +423,13 @@ impl ListWorkspacesOptions {
         self.limit = Some(limit);
         self
     }
+
+    /// Includes resource counts in each workspace, populating
+    /// [`Workspace::counts`].
+    pub fn include_counts(mut self) -> Self {
+        self.include_counts = true;
+        self
+    }
 }
 
 impl WorkspacesService for NvisyClient {
@@ -125,39 +438,80 @@ impl WorkspacesService for NvisyClient {
         options: Option<ListWorkspacesOptions>,
     ) -> Result<WorkspacesPage> {
         let opts = options.unwrap_or_default();
-        let mut params: Vec<(&str, String)> = Vec::new();
+        let query = Query::new()
+            .opt("externalId", opts.external_id.as_ref())
+            .opt("after", opts.after.as_ref())
+            .anchor(opts.starting_after.as_ref())
+            .opt("limit", opts.limit.as_ref())
+            .opt("expand", opts.include_counts.then_some(&"counts"));
+        let req = query.apply(self.request_builder(Method::GET, "/workspaces/")?);
 
-        if let Some(after) = &opts.after {
-            params.push(("after", after.clone()));
-        }
-        if let Some(limit) = opts.limit {
-            params.push(("limit", limit.to_string()));
-        }
-
-        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-
-        let response = self
-            .send_with_params(Method::GET, "/workspaces/", &params_ref)
-            .await?;
+        let response = self.execute(req).await?;
         let response = response.error_for_status()?;
-        let page: WorkspacesPage = response.json().await?;
+        let page: WorkspacesPage = self.read_json(response).await?;
         Ok(page)
     }
 
+    fn list_workspaces_stream(
+        &self,
+        options: Option<ListWorkspacesOptions>,
+    ) -> impl Stream<Item = Result<Workspace>> + '_ {
+        self.paginate_workspaces(options).into_stream()
+    }
+
+    fn paginate_workspaces(
+        &self,
+        options: Option<ListWorkspacesOptions>,
+    ) -> Paginator<'_, Workspace> {
+        let mut options = options.unwrap_or_default();
+        let first = options.after.take();
+        let resume_options = options.clone();
+        Paginator::new(first, move |after| {
+            let options = ListWorkspacesOptions {
+                after,
+                ..options.clone()
+            };
+            self.list_workspaces(Some(options))
+        })
+        .with_resume(move |anchor| {
+            let options = ListWorkspacesOptions {
+                after: None,
+                starting_after: Some(anchor),
+                ..resume_options.clone()
+            };
+            self.list_workspaces(Some(options))
+        })
+    }
+
+    async fn find_workspace_by_external_id(&self, external_id: &str) -> Result<Option<Workspace>> {
+        let options = ListWorkspacesOptions::new()
+            .external_id(external_id)
+            .limit(1);
+        let page = self.list_workspaces(Some(options)).await?;
+        Ok(page.items.into_iter().next())
+    }
+
     async fn get_workspace(&self, workspace_id: Uuid) -> Result<Workspace> {
         let path = format!("/workspaces/{}/", workspace_id);
         let response = self.send(Method::GET, &path).await?;
         let response = response.error_for_status()?;
-        let workspace: Workspace = response.json().await?;
+        let workspace: Workspace = self.read_json(response).await?;
         Ok(workspace)
     }
 
+    async fn get_workspaces_batch(&self, workspace_ids: &[Uuid]) -> Result<Batch<Workspace>> {
+        batch::get_batch(self, "/workspaces/batch", workspace_ids, |workspace_id| {
+            self.get_workspace(workspace_id)
+        })
+        .await
+    }
+
     async fn create_workspace(&self, request: CreateWorkspace) -> Result<Workspace> {
         let response = self
             .send_json(Method::POST, "/workspaces/", &request)
             .await?;
         let response = response.error_for_status()?;
-        let workspace: Workspace = response.json().await?;
+        let workspace: Workspace = self.read_json(response).await?;
         Ok(workspace)
     }
 
@@ -169,7 +523,7 @@ impl WorkspacesService for NvisyClient {
         let path = format!("/workspaces/{}/", workspace_id);
         let response = self.send_json(Method::PATCH, &path, &update).await?;
         let response = response.error_for_status()?;
-        let workspace: Workspace = response.json().await?;
+        let workspace: Workspace = self.read_json(response).await?;
         Ok(workspace)
     }
 
@@ -187,7 +541,7 @@ impl WorkspacesService for NvisyClient {
         let path = format!("/workspaces/{}/notifications", workspace_id);
         let response = self.send(Method::GET, &path).await?;
         let response = response.error_for_status()?;
-        let settings: NotificationSettings = response.json().await?;
+        let settings: NotificationSettings = self.read_json(response).await?;
         Ok(settings)
     }
 
@@ -199,7 +553,260 @@ impl WorkspacesService for NvisyClient {
         let path = format!("/workspaces/{}/notifications", workspace_id);
         let response = self.send_json(Method::PATCH, &path, &update).await?;
         let response = response.error_for_status()?;
-        let settings: NotificationSettings = response.json().await?;
+        let settings: NotificationSettings = self.read_json(response).await?;
         Ok(settings)
     }
+
+    async fn preview_notification_digest(&self, workspace_id: Uuid) -> Result<NotificationDigest> {
+        let path = format!("/workspaces/{}/notifications/digest-preview", workspace_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let digest: NotificationDigest = self.read_json(response).await?;
+        Ok(digest)
+    }
+
+    async fn get_workspace_data_info(&self, workspace_id: Uuid) -> Result<WorkspaceDataInfo> {
+        let path = format!("/workspaces/{}/data-info", workspace_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let info: WorkspaceDataInfo = self.read_json(response).await?;
+        Ok(info)
+    }
+
+    async fn get_workspace_stats(
+        &self,
+        workspace_id: Uuid,
+        window: StatsWindow,
+    ) -> Result<WorkspaceStats> {
+        let path = format!("/workspaces/{}/stats", workspace_id);
+        let req = Query::new()
+            .push("window", &window)
+            .apply(self.request_builder(Method::GET, &path)?);
+
+        let response = self.execute(req).await?;
+        let response = response.error_for_status()?;
+        let stats: WorkspaceStats = self.read_json(response).await?;
+        Ok(stats)
+    }
+
+    async fn rotate_workspace_encryption_key(&self, workspace_id: Uuid) -> Result<EncryptionInfo> {
+        let path = format!("/workspaces/{}/encryption/rotate", workspace_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let info: EncryptionInfo = self.read_json(response).await?;
+        Ok(info)
+    }
+
+    async fn get_ingest_email(&self, workspace_id: Uuid) -> Result<IngestEmail> {
+        let path = format!("/workspaces/{}/ingest-email", workspace_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let email: IngestEmail = self.read_json(response).await?;
+        Ok(email)
+    }
+
+    async fn rotate_ingest_email(&self, workspace_id: Uuid) -> Result<IngestEmail> {
+        let path = format!("/workspaces/{}/ingest-email/rotate", workspace_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let email: IngestEmail = self.read_json(response).await?;
+        Ok(email)
+    }
+
+    async fn update_ingest_email(
+        &self,
+        workspace_id: Uuid,
+        update: UpdateIngestEmail,
+    ) -> Result<IngestEmail> {
+        let path = format!("/workspaces/{}/ingest-email", workspace_id);
+        let response = self.send_json(Method::PATCH, &path, &update).await?;
+        let response = response.error_for_status()?;
+        let email: IngestEmail = self.read_json(response).await?;
+        Ok(email)
+    }
+
+    async fn create_workspace_snapshot(&self, workspace_id: Uuid) -> Result<WorkspaceSnapshot> {
+        let path = format!("/workspaces/{}/snapshots/", workspace_id);
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let snapshot: WorkspaceSnapshot = self.read_json(response).await?;
+        Ok(snapshot)
+    }
+
+    async fn list_workspace_snapshots(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceSnapshot>> {
+        let path = format!("/workspaces/{}/snapshots/", workspace_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let snapshots: Vec<WorkspaceSnapshot> = self.read_json(response).await?;
+        Ok(snapshots)
+    }
+
+    async fn get_workspace_snapshot(
+        &self,
+        workspace_id: Uuid,
+        snapshot_id: Uuid,
+    ) -> Result<WorkspaceSnapshot> {
+        let path = format!("/workspaces/{}/snapshots/{}", workspace_id, snapshot_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let snapshot: WorkspaceSnapshot = self.read_json(response).await?;
+        Ok(snapshot)
+    }
+
+    async fn restore_workspace_snapshot(
+        &self,
+        workspace_id: Uuid,
+        snapshot_id: Uuid,
+    ) -> Result<SnapshotRestore> {
+        let path = format!(
+            "/workspaces/{}/snapshots/{}/restore",
+            workspace_id, snapshot_id
+        );
+        let response = self.send(Method::POST, &path).await?;
+        let response = response.error_for_status()?;
+        let restore: SnapshotRestore = self.read_json(response).await?;
+        Ok(restore)
+    }
+
+    async fn get_snapshot_restore(
+        &self,
+        workspace_id: Uuid,
+        restore_id: Uuid,
+    ) -> Result<SnapshotRestore> {
+        let path = format!("/workspaces/{}/restores/{}", workspace_id, restore_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let restore: SnapshotRestore = self.read_json(response).await?;
+        Ok(restore)
+    }
+
+    async fn export_workspace_config(&self, workspace_id: Uuid) -> Result<WorkspaceConfig> {
+        let path = format!("/workspaces/{}/config", workspace_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let config: WorkspaceConfig = self.read_json(response).await?;
+        Ok(config)
+    }
+
+    async fn apply_workspace_config(
+        &self,
+        workspace_id: Uuid,
+        config: WorkspaceConfig,
+    ) -> Result<WorkspaceConfigApplied> {
+        let path = format!("/workspaces/{}/config", workspace_id);
+        let response = self.send_json(Method::PUT, &path, &config).await?;
+        let response = response.error_for_status()?;
+        let applied: WorkspaceConfigApplied = self.read_json(response).await?;
+        Ok(applied)
+    }
+
+    async fn compare_workspaces(
+        &self,
+        workspace_a: Uuid,
+        workspace_b: Uuid,
+    ) -> Result<WorkspaceComparison> {
+        let files_a = list_all_files(self, workspace_a).await?;
+        let config_a = self.export_workspace_config(workspace_a).await?;
+        let files_b = list_all_files(self, workspace_b).await?;
+        let config_b = self.export_workspace_config(workspace_b).await?;
+        Ok(WorkspaceComparison::new(
+            (workspace_a, files_a, config_a),
+            (workspace_b, files_b, config_b),
+        ))
+    }
+
+    async fn merge_workspaces(
+        &self,
+        source_id: Uuid,
+        target_id: Uuid,
+        options: MergeOptions,
+    ) -> Result<WorkspaceMerge> {
+        let path = format!("/workspaces/{}/merges/", target_id);
+        let request = CreateWorkspaceMerge {
+            source_workspace_id: source_id,
+            options,
+        };
+        let response = self.send_json(Method::POST, &path, &request).await?;
+        let response = response.error_for_status()?;
+        let merge: WorkspaceMerge = self.read_json(response).await?;
+        Ok(merge)
+    }
+
+    async fn get_workspace_merge(&self, target_id: Uuid, merge_id: Uuid) -> Result<WorkspaceMerge> {
+        let path = format!("/workspaces/{}/merges/{}", target_id, merge_id);
+        let response = self.send(Method::GET, &path).await?;
+        let response = response.error_for_status()?;
+        let merge: WorkspaceMerge = self.read_json(response).await?;
+        Ok(merge)
+    }
+}
+
+/// Lists every file in a workspace, following pagination.
+async fn list_all_files(client: &NvisyClient, workspace_id: Uuid) -> Result<Vec<File>> {
+    client
+        .list_files_stream(workspace_id, None)
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::NvisyConfig;
+    use crate::client::mock;
+
+    #[tokio::test]
+    async fn test_list_workspaces_with_counts() -> Result<()> {
+        let (base_url, server) = mock::serve(vec![mock::response(
+            200,
+            &[],
+            r#"{"items":[{"workspaceId":"00000000-0000-0000-0000-000000000001","displayName":"Legal","tags":[],"enableComments":true,"requireApproval":false,"memberRole":"owner","createdBy":"00000000-0000-0000-0000-000000000002","createdAt":"2024-01-01T00:00:00Z","updatedAt":"2024-01-01T00:00:00Z","counts":{"files":12,"members":3}}],"hasMore":false}"#,
+        )]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let page = client
+            .list_workspaces(Some(ListWorkspacesOptions::new().include_counts()))
+            .await?;
+        let counts = page.items[0].counts.unwrap();
+        assert_eq!((counts.files, counts.members, counts.webhooks), (12, 3, 0));
+
+        let requests = server.join().unwrap();
+        assert_eq!(
+            requests[0].request_line(),
+            "GET /workspaces/?expand=counts HTTP/1.1"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_ingest_email_allowed_senders() -> Result<()> {
+        let email = r#"{"workspaceId":"00000000-0000-0000-0000-000000000001",
+            "address":"legal-7f3k@in.nvisy.com","enabled":true,
+            "allowedSenders":["mailroom@firm.com","@courts.gov"],
+            "createdAt":"2024-01-01T00:00:00Z"}"#;
+        let (base_url, server) = mock::serve(vec![mock::response(200, &[], email)]);
+        let client = NvisyConfig::builder()
+            .with_api_key("test-key")
+            .with_base_url(base_url)
+            .build_client()?;
+
+        let update =
+            UpdateIngestEmail::new().with_allowed_senders(["mailroom@firm.com", "@courts.gov"]);
+        let email = client
+            .update_ingest_email(Uuid::from_u128(1), update)
+            .await?;
+        assert!(email.allows_sender("Clerk@Courts.gov"));
+        assert!(email.allows_sender("mailroom@firm.com"));
+        assert!(!email.allows_sender("someone@example.com"));
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].request_line().starts_with("PATCH /workspaces/"));
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body)?;
+        assert_eq!(body["allowedSenders"][1], "@courts.gov");
+        assert!(body.get("enabled").is_none());
+        Ok(())
+    }
 }
@@ -0,0 +1,9 @@
+//! Utilities for receiving webhook deliveries.
+//!
+//! Use [`WebhookVerifier`] to check that an incoming request was sent by
+//! Nvisy and to decode its body into a [`WebhookPayload`](crate::model::WebhookPayload).
+//! [`sign_payload`] produces matching signatures for testing receivers.
+
+pub mod verify;
+
+pub use verify::{DEFAULT_TOLERANCE, SIGNATURE_HEADER, WebhookVerifier, sign_payload};